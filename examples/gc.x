@@ -0,0 +1,49 @@
+// Coletor de ciclos: listas duplamente encadeadas, objetos que apontam para si
+// mesmos e closures que capturam o próprio dono só são liberados por `gc()`
+class No {
+    valor = 0;
+    proximo = null;
+    anterior = null;
+    constructor(self, valor) {
+        self.valor = valor;
+    }
+}
+
+fn lista(n) {
+    let primeiro = new No(0);
+    let atual = primeiro;
+    for (let i = 1; i < n; i++) {
+        let no = new No(i);
+        atual.proximo = no;
+        no.anterior = atual;
+        atual = no;
+    }
+    atual.proximo = primeiro;
+    primeiro.anterior = atual;
+    return primeiro;
+}
+
+let base = gcStats().tracked;
+
+let l = lista(1000);
+l = null;
+Io.println("antes do gc: " + (gcStats().tracked - base)); // 1000
+Io.println("liberados: " + gc()); // 1000
+Io.println("depois do gc: " + (gcStats().tracked - base)); // 0
+
+class Caixa {
+    callback = null;
+}
+fn criar() {
+    let caixa = new Caixa();
+    fn dono() {
+        return caixa;
+    }
+    caixa.callback = dono;
+}
+criar();
+Io.println("closure: " + gc()); // 1
+
+let vivo = lista(3);
+Io.println("vivos continuam: " + gc() + " " + vivo.proximo.valor); // 0 1
+Io.println(gcStats());
@@ -0,0 +1,27 @@
+// Referências fracas: um cache em WeakMap não impede que as chaves sejam liberadas
+class Usuario {
+    nome = "";
+    constructor(self, nome) {
+        self.nome = nome;
+    }
+}
+
+let perfis = WeakMap();
+let ana = new Usuario("Ana");
+let ref = WeakRef(ana);
+
+perfis.set(ana, { acessos: 3 });
+Io.println(ref.deref().nome); // Ana
+Io.println(perfis.has(ana)); // true
+
+ana = null;
+Io.println(ref.deref()); // null
+
+// Ciclos só morrem com o coletor
+let bob = new Usuario("Bob");
+bob.nome = bob;
+let refBob = WeakRef(bob);
+bob = null;
+Io.println(refBob.deref() == null); // false
+gc();
+Io.println(refBob.deref()); // null
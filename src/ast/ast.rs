@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use yansi::Color;
 use yansi::Paint;
 
+use crate::environment::stdlib::number::NumberValue;
 use crate::environment::values::Value;
 use crate::environment::Environment;
 
@@ -31,17 +32,44 @@ pub enum Stmt {
     ExportDefault(Box<Stmt>), // novo!
     Let {
         name: String,
+        ty: Option<TypeAnnotation>, // `let x: number = 1`
         value: Expr,
+        location: SourceLocation,
     },
     FuncDecl(FunctionStmt),
+    /// `@memoize fn f() {}` ou `@deprecated("msg") class Foo {}`: `target` é um
+    /// `FuncDecl` ou `ClassDecl`, substituído pelo valor que os decoradores retornam.
+    Decorated {
+        decorators: Vec<Decorator>,
+        target: Box<Stmt>,
+    },
     ClassDecl {
         name: String,
         superclass: Option<Expr>, // para herança, se suportar
         methods: Vec<MethodDecl>, // (Nome, estatico)
         static_fields: HashMap<String, Expr>,
         instance_fields: HashMap<String, Expr>, // FuncDecl ou algo similar
+        field_types: HashMap<String, TypeAnnotation>, // campos anotados, estáticos ou não
+        interfaces: Vec<Expr>,                  // class Foo implements A, B
+        modifiers: Vec<Modifiers>,              // abstract class Foo
+    },
+    /// `interface Nome extends A { metodo(self); padrao(self) { ... } }`; métodos sem
+    /// corpo têm o modificador `Abstract` e precisam ser implementados pela classe.
+    InterfaceDecl {
+        name: String,
+        extends: Vec<Expr>,
+        methods: Vec<MethodDecl>,
     },
     Method(MethodDecl),
+    EnumDecl {
+        name: String,
+        variants: Vec<EnumVariant>,
+    },
+    Match {
+        subject: Expr,
+        arms: Vec<MatchArm>,
+        location: SourceLocation, // identifica o match nos avisos de exaustividade
+    },
     If {
         condition: Expr,
         then_branch: Vec<Stmt>,
@@ -70,21 +98,136 @@ pub enum Stmt {
     },
     TryCatchFinally {
         try_block: Vec<Stmt>,
-        catch_block: Option<(String, Vec<Stmt>)>,
+        catch_clauses: Vec<CatchClause>, // testadas em ordem; a primeira compatível trata o erro
         finally_block: Option<Vec<Stmt>>,
     },
-    Throw(Expr),
-    ExprStmt(Expr),
-    Return(Option<Expr>),
+    Throw(Expr, SourceLocation),
+    /// `defer expr;`: avalia `expr` ao sair do bloco atual, em ordem inversa de registro.
+    Defer(Expr),
+    /// `using (let nome = valor) { ... }`: chama `close()` ou `dispose()` em `valor`
+    /// ao sair do corpo, mesmo com erro.
+    Using {
+        name: String,
+        value: Expr,
+        body: Vec<Stmt>,
+    },
+    // A posição atualiza o frame em execução, para erros que não vêm de uma chamada
+    ExprStmt(Expr, SourceLocation),
+    Return(Option<Expr>, SourceLocation),
     Break,
     Continue,
 }
+/// Variante de `enum Cor { Vermelho, Rgb(r, g, b) }`; `fields` vazio para variantes unitárias.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct EnumVariant {
+    pub name: String,
+    pub fields: Vec<String>,
+}
+
+/// `@nome` ou `@nome(args)`: chama `nome(alvo, ...args)` ao declarar o alvo.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Decorator {
+    pub name: String, // sem o `@`, como aparece em `decorators(alvo)`
+    pub callee: Expr,
+    pub args: Vec<Expr>,
+    pub location: SourceLocation,
+}
+
+/// Braço de `match (valor) { padrão => corpo }`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MatchArm {
+    pub pattern: MatchPattern,
+    pub body: Vec<Stmt>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum MatchPattern {
+    Wildcard, // _
+    // `Cor.Rgb(r, g, b)`: testa a variante e liga os campos por posição
+    Variant { path: Expr, bindings: Vec<String> },
+    // `Cor.Vermelho`, `Cor.Rgb` ou qualquer valor: classe usa instanceof, o resto `==`
+    Value(Expr),
+}
+
+/// Posição de uma chamada no código-fonte. Vazia para AST gerada em Rust.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct SourceLocation {
+    pub file: String,
+    pub line: usize,
+}
+
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.file.is_empty() {
+            return write!(f, "<native>");
+        }
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
+
+/// Anotação de tipo opcional: `number`, `Foo`, `Array<Foo>`. Só é verificada pelo
+/// comando `check` ou em modo estrito.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TypeAnnotation {
+    pub name: String,
+    pub args: Vec<TypeAnnotation>,
+    pub location: SourceLocation, // onde a anotação foi escrita
+}
+
+impl TypeAnnotation {
+    pub fn named(name: &str) -> Self {
+        TypeAnnotation {
+            name: name.to_string(),
+            args: vec![],
+            location: SourceLocation::default(),
+        }
+    }
+}
+
+impl std::fmt::Display for TypeAnnotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.args.is_empty() {
+            let args = self.args.iter().map(|arg| arg.to_string());
+            write!(f, "<{}>", args.collect::<Vec<_>>().join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Tipos dos parâmetros (na ordem de `params`) e do retorno; `None` quando não anotado.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct Signature {
+    pub params: Vec<Option<TypeAnnotation>>,
+    pub returns: Option<TypeAnnotation>,
+}
+
+impl Signature {
+    pub fn is_empty(&self) -> bool {
+        self.returns.is_none() && self.params.iter().all(Option::is_none)
+    }
+}
+
+/// `catch (e: TypeError) if (cond) { ... }`; binding, tipo e guarda são opcionais.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CatchClause {
+    pub binding: Option<CatchBinding>,
+    pub error_type: Option<Expr>,
+    pub guard: Option<Expr>,
+    pub body: Vec<Stmt>,
+}
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum CatchBinding {
+    Identifier(String),
+    Object(Vec<(String, String)>), // catch ({ name, message: msg }) -> (propriedade, variável local)
+}
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct FunctionStmt {
     pub name: String,
     pub params: Vec<String>,
     pub vararg: Option<String>,
     pub body: Vec<Stmt>,
+    pub signature: Signature,
 }
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum Expr {
@@ -121,6 +264,7 @@ pub enum Expr {
     Call {
         callee: Box<Expr>,
         args: Vec<Expr>,
+        location: SourceLocation, // usado nos frames do stack trace
     },
     New {
         class_expr: Box<Expr>,
@@ -130,6 +274,33 @@ pub enum Expr {
     This,
     Block(Vec<Stmt>),
     Spread(Box<Expr>),
+    /// `expr?`: o valor de `Some`/`Ok`, ou retorna `None`/`Err` da função atual
+    Propagate(Box<Expr>),
+    /// `[x * 2 for x of xs if x > 0]`
+    ArrayComprehension {
+        element: Box<Expr>,
+        clauses: Vec<ComprehensionClause>,
+    },
+    /// `{ k: v for [k, v] of entries }`; a chave é avaliada, não literal
+    ObjectComprehension {
+        key: Box<Expr>,
+        value: Box<Expr>,
+        clauses: Vec<ComprehensionClause>,
+    },
+    /// `valor |> alvo`: `alvo(valor)`, ou `f(valor, ...)` quando o alvo é a chamada
+    /// `f(...)`; um `_` entre os argumentos marca onde o valor entra.
+    Pipe {
+        value: Box<Expr>,
+        target: Box<Expr>,
+        location: SourceLocation,
+    },
+    /// Argumento nomeado `nome: valor`; só aparece na lista de argumentos de uma chamada
+    NamedArg {
+        name: String,
+        value: Box<Expr>,
+    },
+    /// `delete obj.key` / `delete obj[key]`
+    Delete(Box<Expr>),
 }
 
 impl std::fmt::Display for Expr {
@@ -146,6 +317,40 @@ pub enum ControlFlow<T: std::fmt::Debug> {
     Error(T),
 }
 
+/// Categoria de uma falha em tempo de execução. Cada variante corresponde a
+/// uma classe nativa (subclasse de `Error`) definida antes do código do usuário.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Error,
+    TypeError,
+    ReferenceError,
+    RangeError,
+    SyntaxError,
+    IoError,
+}
+
+impl ErrorKind {
+    /// Classes nativas derivadas de `Error`, na ordem em que são declaradas.
+    pub const NATIVE: [ErrorKind; 5] = [
+        ErrorKind::TypeError,
+        ErrorKind::ReferenceError,
+        ErrorKind::RangeError,
+        ErrorKind::SyntaxError,
+        ErrorKind::IoError,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ErrorKind::Error => "Error",
+            ErrorKind::TypeError => "TypeError",
+            ErrorKind::ReferenceError => "ReferenceError",
+            ErrorKind::RangeError => "RangeError",
+            ErrorKind::SyntaxError => "SyntaxError",
+            ErrorKind::IoError => "IoError",
+        }
+    }
+}
+
 pub fn debug_stmts(stmts: &[Stmt], indent: usize) {
     for stmt in stmts {
         debug_stmt(stmt, indent);
@@ -192,7 +397,7 @@ pub fn debug_stmt(stmt: &Stmt, indent: usize) {
             debug_stmt(inner, indent + 2);
         }
 
-        Stmt::Let { name, value } => {
+        Stmt::Let { name, value, .. } => {
             println!(
                 "{pad}{} {} = {}",
                 "Stmt::Let".paint(color_let),
@@ -201,7 +406,7 @@ pub fn debug_stmt(stmt: &Stmt, indent: usize) {
             );
         }
 
-        Stmt::Return(expr_opt) => {
+        Stmt::Return(expr_opt, _) => {
             if let Some(expr) = expr_opt {
                 println!(
                     "{pad}{} {}",
@@ -213,7 +418,7 @@ pub fn debug_stmt(stmt: &Stmt, indent: usize) {
             }
         }
 
-        Stmt::ExprStmt(expr) => {
+        Stmt::ExprStmt(expr, _) => {
             println!(
                 "{pad}{} {}",
                 "Stmt::ExprStmt".paint(color_expr),
@@ -225,6 +430,16 @@ pub fn debug_stmt(stmt: &Stmt, indent: usize) {
             "Stmt::FuncDecl".paint(color_func),
             func.name.paint(color_name)
         ),
+        Stmt::Decorated { decorators, target } => {
+            for decorator in decorators {
+                println!(
+                    "{pad}{} @{}",
+                    "Stmt::Decorated".paint(color_func),
+                    decorator.name.paint(color_name)
+                );
+            }
+            debug_stmt(target, indent);
+        }
 
         Stmt::ClassDecl {
             name: class_name,
@@ -246,6 +461,22 @@ pub fn debug_stmt(stmt: &Stmt, indent: usize) {
             }
         }
 
+        Stmt::InterfaceDecl { name, methods, .. } => {
+            println!(
+                "{pad}{} ({})",
+                "Stmt::InterfaceDecl".paint(color_class),
+                name.paint(color_name)
+            );
+            for m in methods {
+                println!(
+                    "{pad}  {} {} ({})",
+                    "└──".paint(color_symbol),
+                    "Stmt::InterfaceDecl::Method".paint(color_method),
+                    m.name.paint(color_name)
+                );
+            }
+        }
+
         Stmt::Method(m) => println!(
             "{pad}{} ({})",
             "Stmt::Method".paint(color_method),
@@ -308,7 +539,7 @@ pub fn debug_stmt(stmt: &Stmt, indent: usize) {
 
         Stmt::TryCatchFinally {
             try_block,
-            catch_block,
+            catch_clauses,
             finally_block,
         } => {
             println!("{pad}{}", "Stmt::Try".paint(color_try));
@@ -330,7 +561,9 @@ pub fn debug_stmt(stmt: &Stmt, indent: usize) {
 
             let has_finally = finally_block.is_some();
 
-            if let Some((_name, catch_block)) = catch_block {
+            for (clause_idx, clause) in catch_clauses.iter().enumerate() {
+                let catch_block = &clause.body;
+                let has_finally = has_finally || clause_idx + 1 < catch_clauses.len();
                 let catch_indent = indent + 2;
                 let catch_pad = " ".repeat(catch_indent);
                 let symbol = if has_finally {
@@ -382,7 +615,36 @@ pub fn debug_stmt(stmt: &Stmt, indent: usize) {
                 }
             }
         }
-        Stmt::Throw(_) => println!("{pad}{}", "Stmt::Throw".paint(color_throw)),
+        Stmt::Throw(_, _) => println!("{pad}{}", "Stmt::Throw".paint(color_throw)),
+        Stmt::Defer(_) => println!("{pad}{}", "Stmt::Defer".paint(color_other)),
+        Stmt::Using { name, body, .. } => {
+            println!(
+                "{pad}{} {}",
+                "Stmt::Using".paint(color_control),
+                name.paint(color_name)
+            );
+            debug_stmts(body, indent + 2);
+        }
+        Stmt::EnumDecl { name, variants } => {
+            println!(
+                "{pad}{} {}",
+                "Stmt::EnumDecl".paint(color_class),
+                name.paint(color_name)
+            );
+            for variant in variants {
+                println!(
+                    "{pad}  {}({})",
+                    variant.name.paint(color_method),
+                    variant.fields.join(", ")
+                );
+            }
+        }
+        Stmt::Match { arms, .. } => {
+            println!("{pad}{}", "Stmt::Match".paint(color_control));
+            for arm in arms {
+                debug_stmts(&arm.body, indent + 2);
+            }
+        }
         Stmt::Break => println!("{pad}{}", "Stmt::Break".paint(color_other)),
         Stmt::Continue => println!("{pad}{}", "Stmt::Continue".paint(color_other)),
     }
@@ -390,37 +652,20 @@ pub fn debug_stmt(stmt: &Stmt, indent: usize) {
 impl<T: std::fmt::Debug + std::convert::From<std::string::String> + From<Value> + Clone>
     ControlFlow<T>
 {
-    #[track_caller]
     pub fn new_error(env: &mut Rc<RefCell<Environment>>, msg: String) -> ControlFlow<T> {
-        let location = std::panic::Location::caller().to_string()
-            + " "
-            + std::file!()
-            + ":"
-            + &std::line!().to_string();
-        let env = env.borrow();
-        let error_class = env.get("Error");
-        if error_class.is_none() {
-            panic!("Error class not found {msg} {location}")
-        }
-        let error_class = error_class.unwrap().to_class();
-        if error_class.is_none() {
-            panic!("Class 'Error' not found");
-        }
-        let error_class = error_class.unwrap();
-
-        let throw_method = error_class.find_static_method("throw");
-
-        if throw_method.is_none() {
-            panic!("throw method not found");
-        }
-        let throw_method = throw_method.unwrap();
-
-        let error = throw_method.call(vec![Value::Null, Value::String(msg.into())]);
+        Self::new_typed_error(env, ErrorKind::Error, msg)
+    }
 
-        if error.is_err() {
-            panic!("Error creating error object {:?}", error.to_string());
+    /// Cria uma instância da classe de erro correspondente a `kind`.
+    pub fn new_typed_error(
+        env: &mut Rc<RefCell<Environment>>,
+        kind: ErrorKind,
+        msg: String,
+    ) -> ControlFlow<T> {
+        match Value::new_typed_error(env, kind, msg) {
+            Value::Error(error) => ControlFlow::Error(error.borrow().clone().into()),
+            error => ControlFlow::Error(error.into()),
         }
-        ControlFlow::Error(error.into())
     }
     pub fn is_none(&self) -> bool {
         match self {
@@ -537,6 +782,7 @@ impl Expr {
     pub fn to_number(&self) -> Option<f64> {
         match self {
             Expr::Literal(Literal::Number(n)) => Some(*n),
+            Expr::Literal(Literal::Int(n)) => Some(*n as f64),
             _ => None,
         }
     }
@@ -558,23 +804,19 @@ impl Expr {
             }
             Expr::BinaryOp { op, left, right } => {
                 let op_str = match op {
-                    Operator::Binary(b) => match b {
-                        BinaryOperator::Add => "+",
-                        BinaryOperator::Subtract => "-",
-                        BinaryOperator::Multiply => "*",
-                        BinaryOperator::Divide => "/",
-                        BinaryOperator::Modulo => "%",
-                        BinaryOperator::Exponentiate => "**",
-                    },
+                    Operator::Binary(b) => b.symbol(),
                     Operator::Compare(c) => match c {
                         CompareOperator::Eq => "==",
                         CompareOperator::Ne => "!=",
+                        CompareOperator::StrictEq => "===",
+                        CompareOperator::StrictNe => "!==",
                         CompareOperator::Gt => ">",
                         CompareOperator::Ge => ">=",
                         CompareOperator::Lt => "<",
                         CompareOperator::Le => "<=",
                         CompareOperator::In => "in",
                         CompareOperator::InstanceOf => "instanceof",
+                        CompareOperator::Implements => "implements",
                     },
                     Operator::Logical(l) => match l {
                         LogicalOperator::And => "&&",
@@ -617,7 +859,7 @@ impl Expr {
             Expr::BracketAccess { object, property } => {
                 format!("{}[{}]", object.to_string(), property.to_string())
             }
-            Expr::Call { callee, args } => {
+            Expr::Call { callee, args, .. } => {
                 let args_str = args
                     .iter()
                     .map(|a| a.to_string())
@@ -646,6 +888,22 @@ impl Expr {
             Expr::Spread(expr) => {
                 format!("...{}", expr.to_string())
             }
+            Expr::NamedArg { name, value } => format!("{name}: {value}"),
+            Expr::Pipe { value, target, .. } => format!("{value} |> {target}"),
+            Expr::Propagate(expr) => format!("{expr}?"),
+            Expr::ArrayComprehension { element, clauses } => {
+                let clauses: Vec<String> = clauses.iter().map(|c| c.to_string()).collect();
+                format!("[{element} {}]", clauses.join(" "))
+            }
+            Expr::ObjectComprehension {
+                key,
+                value,
+                clauses,
+            } => {
+                let clauses: Vec<String> = clauses.iter().map(|c| c.to_string()).collect();
+                format!("{{ {key}: {value} {} }}", clauses.join(" "))
+            }
+            Expr::Delete(target) => format!("delete {}", target),
         }
     }
 }
@@ -680,6 +938,19 @@ impl AssignOperator {
             _ => None,
         }
     }
+
+    /// Operação aritmética equivalente de uma atribuição composta (`+=` -> `+`).
+    pub fn binary_operator(&self) -> Option<BinaryOperator> {
+        match self {
+            AssignOperator::Assign => None,
+            AssignOperator::AddAssign => Some(BinaryOperator::Add),
+            AssignOperator::SubAssign => Some(BinaryOperator::Subtract),
+            AssignOperator::MulAssign => Some(BinaryOperator::Multiply),
+            AssignOperator::DivAssign => Some(BinaryOperator::Divide),
+            AssignOperator::ModAssign => Some(BinaryOperator::Modulo),
+            AssignOperator::PowAssign => Some(BinaryOperator::Exponentiate),
+        }
+    }
 }
 
 impl std::fmt::Display for AssignOperator {
@@ -710,6 +981,7 @@ pub enum BinaryOperator {
     Subtract,
     Multiply,
     Divide,
+    FloorDivide,
     Modulo,
     Exponentiate,
 }
@@ -721,22 +993,38 @@ impl BinaryOperator {
             BinaryOperator::Subtract => "sub",
             BinaryOperator::Multiply => "mul",
             BinaryOperator::Divide => "div",
+            BinaryOperator::FloorDivide => "floordiv",
             BinaryOperator::Modulo => "mod",
             BinaryOperator::Exponentiate => "exp",
         }
         .to_string()
     }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            BinaryOperator::Add => "+",
+            BinaryOperator::Subtract => "-",
+            BinaryOperator::Multiply => "*",
+            BinaryOperator::Divide => "/",
+            BinaryOperator::FloorDivide => "~/",
+            BinaryOperator::Modulo => "%",
+            BinaryOperator::Exponentiate => "**",
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub enum CompareOperator {
     Eq,         // ==
     Ne,         // !=
+    StrictEq,   // ===
+    StrictNe,   // !==
     Gt,         // >
     Ge,         // >=
     Lt,         // <
     Le,         // <=
     InstanceOf, // instanceof
+    Implements, // implements
     In,         // in
 }
 
@@ -765,6 +1053,12 @@ pub enum Literal {
     Bool(bool),
     /// Any floating point number.
     Number(f64),
+    /// Any integer number.
+    Int(i64),
+    /// Arbitrary precision integer (`10n`), stored as its digits.
+    BigInt(String),
+    /// Exact decimal number (`10.25m`), stored as its digits.
+    Decimal(String),
     /// Any quoted string.
     String(String),
     /// An array of values
@@ -773,6 +1067,25 @@ pub enum Literal {
     Object(Vec<ObjectEntry>),
 }
 
+/// Cláusula de uma compreensão, aplicada da esquerda para a direita: cada `for`
+/// repete as seguintes para cada item; `if` descarta os que não passam.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum ComprehensionClause {
+    For { target: Expr, iterable: Expr },
+    If(Expr),
+}
+
+impl std::fmt::Display for ComprehensionClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ComprehensionClause::For { target, iterable } => {
+                write!(f, "for {target} of {iterable}")
+            }
+            ComprehensionClause::If(condition) => write!(f, "if {condition}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum ObjectEntry {
     Property { key: String, value: Expr },
@@ -786,6 +1099,9 @@ impl Literal {
             Literal::Null => "null".to_string(),
             Literal::Bool(b) => b.to_string(),
             Literal::Number(n) => n.to_string(),
+            Literal::Int(n) => n.to_string(),
+            Literal::BigInt(n) => format!("{n}n"),
+            Literal::Decimal(n) => format!("{n}m"),
             Literal::String(s) => format!("\"{}\"", s),
             Literal::Array(a) => {
                 let mut s = "[".to_string();
@@ -826,7 +1142,12 @@ impl Literal {
             Value::Void => Literal::Void,
             Value::Null => Literal::Null,
             Value::Bool(b) => Literal::Bool(*b),
-            Value::Number(n) => Literal::Number(n.get_value()),
+            Value::Number(n) => match n.get_number() {
+                NumberValue::Int(i) => Literal::Int(i),
+                NumberValue::Float(f) => Literal::Number(f),
+                NumberValue::BigInt(b) => Literal::BigInt(b.to_string()),
+                NumberValue::Decimal(d) => Literal::Decimal(d.to_string()),
+            },
             Value::String(s) => Literal::String(s.clone().to_string()),
             Value::Array(a) => {
                 let mut arr = Vec::new();
@@ -857,6 +1178,8 @@ pub struct MethodDecl {
     pub vararg: Option<String>,
     pub body: Vec<Stmt>,
     pub modifiers: Vec<Modifiers>,
+    pub signature: Signature,
+    pub decorators: Vec<Decorator>,
 }
 
 pub trait MethodModifiersOperations {
@@ -873,6 +1196,7 @@ pub trait MethodModifiersOperations {
             "static" => self.contains(Modifiers::Static),
             "operator" => self.contains(Modifiers::Operator),
             "private" => self.contains(Modifiers::Private),
+            "abstract" => self.contains(Modifiers::Abstract),
             _ => false,
         }
     }
@@ -887,6 +1211,7 @@ pub enum Modifiers {
     Static,
     Operator,
     Private,
+    Abstract, // método sem corpo ou `abstract class`
 }
 
 impl std::fmt::Display for Stmt {
@@ -896,12 +1221,30 @@ impl std::fmt::Display for Stmt {
 }
 
 impl Stmt {
+    /// Onde o statement começa, para os que guardam a posição.
+    pub fn location(&self) -> Option<&SourceLocation> {
+        match self {
+            Stmt::Let { location, .. }
+            | Stmt::Throw(_, location)
+            | Stmt::ExprStmt(_, location)
+            | Stmt::Return(_, location)
+            | Stmt::Match { location, .. } => Some(location),
+            _ => None,
+        }
+    }
+
     pub fn to_string(&self) -> String {
         match self {
-            Stmt::Let { name, value } => format!("let {} = {};", name, value.to_string()),
-            Stmt::Return(Some(expr)) => format!("return {};", expr.to_string()),
-            Stmt::Return(None) => "return;".to_string(),
-            Stmt::ExprStmt(expr) => format!("{};", expr.to_string()),
+            Stmt::Let {
+                name,
+                ty: Some(ty),
+                value,
+                ..
+            } => format!("let {name}: {ty} = {};", value.to_string()),
+            Stmt::Let { name, value, .. } => format!("let {} = {};", name, value.to_string()),
+            Stmt::Return(Some(expr), _) => format!("return {};", expr.to_string()),
+            Stmt::Return(None, _) => "return;".to_string(),
+            Stmt::ExprStmt(expr, _) => format!("{};", expr.to_string()),
             Stmt::Break => "break;".to_string(),
             Stmt::Continue => "continue;".to_string(),
             Stmt::If {
@@ -988,6 +1331,15 @@ impl Stmt {
                     .join(", ");
                 format!("import {}, {{ {} }} from '{}';", default, named, from)
             }
+            Stmt::Defer(expr) => format!("defer {};", expr.to_string()),
+            Stmt::Using { name, value, body } => {
+                let mut s = format!("using (let {} = {}) {{\n", name, value.to_string());
+                for stmt in body {
+                    s += &format!("  {}\n", stmt.to_string());
+                }
+                s += "}";
+                s
+            }
             Stmt::Export(stmt) => format!("export {};", stmt.to_string()),
             Stmt::ExportDefault(stmt) => format!("export default {};", stmt.to_string()),
             other => format!("{:?}", other), // fallback para casos não tratados
@@ -1,8 +1,10 @@
 use std::collections::HashMap;
 
 use crate::ast::ast::{
-    AssignOperator, BinaryOperator, CompareOperator, Expr, FunctionStmt, Literal, LogicalOperator,
-    MethodDecl, Modifiers, ObjectEntry, Operator, Stmt, UnaryOperator,
+    AssignOperator, BinaryOperator, CatchBinding, CatchClause, CompareOperator,
+    ComprehensionClause, Decorator, EnumVariant, ErrorKind, Expr, FunctionStmt, Literal,
+    LogicalOperator, MatchPattern, MethodDecl, Modifiers, ObjectEntry, Operator, Signature,
+    SourceLocation, Stmt, UnaryOperator,
 };
 use std::fmt::Write;
 
@@ -37,6 +39,10 @@ impl ClassGenerator {
             "message".to_string(),
             Expr::Literal(Literal::String("Default error message".to_string())),
         );
+        instance_fields.insert(
+            "stack".to_string(),
+            Expr::Literal(Literal::String(String::new())),
+        );
 
         let constructor = MethodDecl {
             name: "constructor".to_string(),
@@ -53,14 +59,17 @@ impl ClassGenerator {
                         left: Box::new(Expr::Identifier("name".to_string())),
                         right: Box::new(Expr::Literal(Literal::Null)),
                     },
-                    then_branch: vec![Stmt::ExprStmt(Expr::Assign {
-                        target: Box::new(Expr::GetProperty {
-                            object: Box::new(Expr::Identifier("self".to_string())),
-                            property: Box::new(Expr::Identifier("name".to_string())),
-                        }),
-                        op: AssignOperator::Assign,
-                        value: Box::new(Expr::Identifier("name".to_string())),
-                    })],
+                    then_branch: vec![Stmt::ExprStmt(
+                        Expr::Assign {
+                            target: Box::new(Expr::GetProperty {
+                                object: Box::new(Expr::Identifier("self".to_string())),
+                                property: Box::new(Expr::Identifier("name".to_string())),
+                            }),
+                            op: AssignOperator::Assign,
+                            value: Box::new(Expr::Identifier("name".to_string())),
+                        },
+                        SourceLocation::default(),
+                    )],
                     else_ifs: vec![],
                     else_branch: None,
                 },
@@ -70,35 +79,46 @@ impl ClassGenerator {
                         left: Box::new(Expr::Identifier("message".to_string())),
                         right: Box::new(Expr::Literal(Literal::Null)),
                     },
-                    then_branch: vec![Stmt::ExprStmt(Expr::Assign {
-                        target: Box::new(Expr::GetProperty {
-                            object: Box::new(Expr::Identifier("self".to_string())),
-                            property: Box::new(Expr::Identifier("message".to_string())),
-                        }),
-                        op: AssignOperator::Assign,
-                        value: Box::new(Expr::Identifier("message".to_string())),
-                    })],
+                    then_branch: vec![Stmt::ExprStmt(
+                        Expr::Assign {
+                            target: Box::new(Expr::GetProperty {
+                                object: Box::new(Expr::Identifier("self".to_string())),
+                                property: Box::new(Expr::Identifier("message".to_string())),
+                            }),
+                            op: AssignOperator::Assign,
+                            value: Box::new(Expr::Identifier("message".to_string())),
+                        },
+                        SourceLocation::default(),
+                    )],
                     else_ifs: vec![],
                     else_branch: None,
                 },
             ],
             modifiers: vec![],
+            signature: Signature::default(),
+            decorators: vec![],
         };
 
         let throw = MethodDecl {
             name: "throw".to_string(),
             params: vec!["name".to_string(), "message".to_string()],
             vararg: None,
-            body: vec![Stmt::Return(Some(Expr::New {
-                class_expr: Box::new(Expr::Call {
-                    callee: Box::new(Expr::Identifier("Error".to_string())),
-                    args: vec![
-                        Expr::Identifier("name".to_string()),
-                        Expr::Identifier("message".to_string()),
-                    ],
+            body: vec![Stmt::Return(
+                Some(Expr::New {
+                    class_expr: Box::new(Expr::Call {
+                        callee: Box::new(Expr::Identifier("Error".to_string())),
+                        args: vec![
+                            Expr::Identifier("name".to_string()),
+                            Expr::Identifier("message".to_string()),
+                        ],
+                        location: SourceLocation::default(),
+                    }),
                 }),
-            }))],
+                SourceLocation::default(),
+            )],
             modifiers: vec![Modifiers::Static],
+            signature: Signature::default(),
+            decorators: vec![],
         };
 
         let paint = MethodDecl {
@@ -108,6 +128,7 @@ impl ClassGenerator {
             body: vec![
                 Stmt::Let {
                     name: "redName".to_string(),
+                    ty: None,
                     value: Expr::BinaryOp {
                         op: Operator::Binary(crate::ast::ast::BinaryOperator::Add),
                         left: Box::new(Expr::BinaryOp {
@@ -120,101 +141,155 @@ impl ClassGenerator {
                         }),
                         right: Box::new(Expr::Literal(Literal::String("\x1b[0m".to_string()))),
                     },
+                    location: SourceLocation::default(),
                 },
-                Stmt::Return(Some(Expr::BinaryOp {
-                    op: Operator::Binary(crate::ast::ast::BinaryOperator::Add),
-                    left: Box::new(Expr::BinaryOp {
+                Stmt::Return(
+                    Some(Expr::BinaryOp {
                         op: Operator::Binary(crate::ast::ast::BinaryOperator::Add),
-                        left: Box::new(Expr::Identifier("redName".to_string())),
-                        right: Box::new(Expr::Literal(Literal::String(": ".to_string()))),
-                    }),
-                    right: Box::new(Expr::GetProperty {
-                        object: Box::new(Expr::Identifier("self".to_string())),
-                        property: Box::new(Expr::Identifier("message".to_string())),
+                        left: Box::new(Expr::BinaryOp {
+                            op: Operator::Binary(crate::ast::ast::BinaryOperator::Add),
+                            left: Box::new(Expr::Identifier("redName".to_string())),
+                            right: Box::new(Expr::Literal(Literal::String(": ".to_string()))),
+                        }),
+                        right: Box::new(Expr::GetProperty {
+                            object: Box::new(Expr::Identifier("self".to_string())),
+                            property: Box::new(Expr::Identifier("message".to_string())),
+                        }),
                     }),
-                })),
+                    SourceLocation::default(),
+                ),
             ],
             modifiers: vec![],
+            signature: Signature::default(),
+            decorators: vec![],
         };
 
         let to_string = MethodDecl {
             name: "toString".to_string(),
             params: vec!["self".to_string()],
             vararg: None,
-            body: vec![Stmt::Return(Some(Expr::Call {
-                callee: Box::new(Expr::GetProperty {
-                    object: Box::new(Expr::Identifier("self".to_string())),
-                    property: Box::new(Expr::Identifier("paint".to_string())),
+            body: vec![Stmt::Return(
+                Some(Expr::Call {
+                    callee: Box::new(Expr::GetProperty {
+                        object: Box::new(Expr::Identifier("self".to_string())),
+                        property: Box::new(Expr::Identifier("paint".to_string())),
+                    }),
+                    args: vec![],
+                    location: SourceLocation::default(),
                 }),
-                args: vec![],
-            }))],
+                SourceLocation::default(),
+            )],
             modifiers: vec![],
+            signature: Signature::default(),
+            decorators: vec![],
         };
 
         let value_of = MethodDecl {
             name: "valueOf".to_string(),
             params: vec!["self".to_string()],
             vararg: None,
-            body: vec![Stmt::Return(Some(Expr::Call {
-                callee: Box::new(Expr::GetProperty {
-                    object: Box::new(Expr::Identifier("self".to_string())),
-                    property: Box::new(Expr::Identifier("toString".to_string())),
+            body: vec![Stmt::Return(
+                Some(Expr::Call {
+                    callee: Box::new(Expr::GetProperty {
+                        object: Box::new(Expr::Identifier("self".to_string())),
+                        property: Box::new(Expr::Identifier("toString".to_string())),
+                    }),
+                    args: vec![],
+                    location: SourceLocation::default(),
                 }),
-                args: vec![],
-            }))],
+                SourceLocation::default(),
+            )],
             modifiers: vec![],
+            signature: Signature::default(),
+            decorators: vec![],
         };
 
         let get_message = MethodDecl {
             name: "getMessage".to_string(),
             params: vec!["self".to_string()],
             vararg: None,
-            body: vec![Stmt::Return(Some(Expr::GetProperty {
-                object: Box::new(Expr::Identifier("self".to_string())),
-                property: Box::new(Expr::Identifier("message".to_string())),
-            }))],
+            body: vec![Stmt::Return(
+                Some(Expr::GetProperty {
+                    object: Box::new(Expr::Identifier("self".to_string())),
+                    property: Box::new(Expr::Identifier("message".to_string())),
+                }),
+                SourceLocation::default(),
+            )],
             modifiers: vec![],
+            signature: Signature::default(),
+            decorators: vec![],
+        };
+
+        let get_stack = MethodDecl {
+            name: "getStack".to_string(),
+            params: vec!["self".to_string()],
+            vararg: None,
+            body: vec![Stmt::Return(
+                Some(Expr::GetProperty {
+                    object: Box::new(Expr::Identifier("self".to_string())),
+                    property: Box::new(Expr::Identifier("stack".to_string())),
+                }),
+                SourceLocation::default(),
+            )],
+            modifiers: vec![],
+            signature: Signature::default(),
+            decorators: vec![],
         };
 
         let get_name = MethodDecl {
             name: "getName".to_string(),
             params: vec!["self".to_string()],
             vararg: None,
-            body: vec![Stmt::Return(Some(Expr::GetProperty {
-                object: Box::new(Expr::Identifier("self".to_string())),
-                property: Box::new(Expr::Identifier("name".to_string())),
-            }))],
+            body: vec![Stmt::Return(
+                Some(Expr::GetProperty {
+                    object: Box::new(Expr::Identifier("self".to_string())),
+                    property: Box::new(Expr::Identifier("name".to_string())),
+                }),
+                SourceLocation::default(),
+            )],
             modifiers: vec![],
+            signature: Signature::default(),
+            decorators: vec![],
         };
 
         let set_name = MethodDecl {
             name: "setName".to_string(),
             params: vec!["self".to_string(), "name".to_string()],
             vararg: None,
-            body: vec![Stmt::ExprStmt(Expr::Assign {
-                target: Box::new(Expr::GetProperty {
-                    object: Box::new(Expr::Identifier("self".to_string())),
-                    property: Box::new(Expr::Identifier("name".to_string())),
-                }),
-                op: AssignOperator::Assign,
-                value: Box::new(Expr::Identifier("name".to_string())),
-            })],
+            body: vec![Stmt::ExprStmt(
+                Expr::Assign {
+                    target: Box::new(Expr::GetProperty {
+                        object: Box::new(Expr::Identifier("self".to_string())),
+                        property: Box::new(Expr::Identifier("name".to_string())),
+                    }),
+                    op: AssignOperator::Assign,
+                    value: Box::new(Expr::Identifier("name".to_string())),
+                },
+                SourceLocation::default(),
+            )],
             modifiers: vec![],
+            signature: Signature::default(),
+            decorators: vec![],
         };
 
         let set_message = MethodDecl {
             name: "setMessage".to_string(),
             params: vec!["self".to_string(), "message".to_string()],
             vararg: None,
-            body: vec![Stmt::ExprStmt(Expr::Assign {
-                target: Box::new(Expr::GetProperty {
-                    object: Box::new(Expr::Identifier("self".to_string())),
-                    property: Box::new(Expr::Identifier("message".to_string())),
-                }),
-                op: AssignOperator::Assign,
-                value: Box::new(Expr::Identifier("message".to_string())),
-            })],
+            body: vec![Stmt::ExprStmt(
+                Expr::Assign {
+                    target: Box::new(Expr::GetProperty {
+                        object: Box::new(Expr::Identifier("self".to_string())),
+                        property: Box::new(Expr::Identifier("message".to_string())),
+                    }),
+                    op: AssignOperator::Assign,
+                    value: Box::new(Expr::Identifier("message".to_string())),
+                },
+                SourceLocation::default(),
+            )],
             modifiers: vec![],
+            signature: Signature::default(),
+            decorators: vec![],
         };
 
         let class_stmt = Stmt::ClassDecl {
@@ -227,16 +302,132 @@ impl ClassGenerator {
                 to_string,
                 value_of,
                 get_message,
+                get_stack,
                 get_name,
                 set_name,
                 set_message,
             ],
             static_fields,
             instance_fields,
+            field_types: HashMap::new(),
+            interfaces: vec![],
+            modifiers: vec![],
         };
         class_stmt
     }
 
+    /// Gera os enums `Option` e `Result` do prelúdio, equivalentes a:
+    ///
+    /// ```text
+    /// enum Option { Some(value), None }
+    /// enum Result { Ok(value), Err(error) }
+    /// let Some = Option.Some; let None = Option.None;
+    /// let Ok = Result.Ok; let Err = Result.Err;
+    /// ```
+    pub fn create_option_enums() -> Vec<Stmt> {
+        let variant = |name: &str, fields: &[&str]| EnumVariant {
+            name: name.to_string(),
+            fields: fields.iter().map(|field| field.to_string()).collect(),
+        };
+        let alias = |enum_name: &str, name: &str| Stmt::Let {
+            name: name.to_string(),
+            ty: None,
+            value: Expr::GetProperty {
+                object: Box::new(Expr::Identifier(enum_name.to_string())),
+                property: Box::new(Expr::Identifier(name.to_string())),
+            },
+            location: SourceLocation::default(),
+        };
+        vec![
+            Stmt::EnumDecl {
+                name: "Option".to_string(),
+                variants: vec![variant("Some", &["value"]), variant("None", &[])],
+            },
+            Stmt::EnumDecl {
+                name: "Result".to_string(),
+                variants: vec![variant("Ok", &["value"]), variant("Err", &["error"])],
+            },
+            alias("Option", "Some"),
+            alias("Option", "None"),
+            alias("Result", "Ok"),
+            alias("Result", "Err"),
+        ]
+    }
+
+    /// Gera uma subclasse nativa de `Error` equivalente a:
+    ///
+    /// ```text
+    /// class TypeError extends Error {
+    ///     name = "TypeError";
+    ///     constructor(self, message) { self.super(null, message); }
+    ///     static throw(name, message) { return new TypeError(message); }
+    /// }
+    /// ```
+    ///
+    /// O construtor não repassa o nome, então subclasses do usuário podem
+    /// declarar o próprio campo `name`.
+    pub fn create_native_error_class(kind: ErrorKind) -> Stmt {
+        let name = kind.name().to_string();
+        let mut instance_fields = HashMap::new();
+        instance_fields.insert(
+            "name".to_string(),
+            Expr::Literal(Literal::String(name.clone())),
+        );
+
+        let constructor = MethodDecl {
+            name: "constructor".to_string(),
+            params: vec!["self".to_string(), "message".to_string()],
+            vararg: None,
+            body: vec![Stmt::ExprStmt(
+                Expr::Call {
+                    callee: Box::new(Expr::GetProperty {
+                        object: Box::new(Expr::Identifier("self".to_string())),
+                        property: Box::new(Expr::Identifier("super".to_string())),
+                    }),
+                    args: vec![
+                        Expr::Literal(Literal::Null),
+                        Expr::Identifier("message".to_string()),
+                    ],
+                    location: SourceLocation::default(),
+                },
+                SourceLocation::default(),
+            )],
+            modifiers: vec![],
+            signature: Signature::default(),
+            decorators: vec![],
+        };
+
+        let throw = MethodDecl {
+            name: "throw".to_string(),
+            params: vec!["name".to_string(), "message".to_string()],
+            vararg: None,
+            body: vec![Stmt::Return(
+                Some(Expr::New {
+                    class_expr: Box::new(Expr::Call {
+                        callee: Box::new(Expr::Identifier(name.clone())),
+                        args: vec![Expr::Identifier("message".to_string())],
+                        location: SourceLocation::default(),
+                    }),
+                }),
+                SourceLocation::default(),
+            )],
+            modifiers: vec![Modifiers::Static],
+            signature: Signature::default(),
+            decorators: vec![],
+        };
+
+        Stmt::ClassDecl {
+            name,
+            superclass: Some(Expr::Identifier("Error".to_string())),
+            methods: vec![constructor, throw],
+            static_fields: HashMap::new(),
+            instance_fields,
+            field_types: HashMap::new(),
+            interfaces: vec![],
+            modifiers: vec![],
+        }
+    }
+
     pub fn generate_class_function(decl: &Stmt) -> Option<String> {
         if let Stmt::ClassDecl {
             name,
@@ -316,6 +507,8 @@ impl ClassGenerator {
                 )
                 .unwrap();
 
+                writeln!(&mut out, "        signature: Signature::default(),").unwrap();
+                writeln!(&mut out, "        decorators: vec![],").unwrap();
                 writeln!(&mut out, "    }};\n").unwrap();
             }
 
@@ -327,6 +520,9 @@ impl ClassGenerator {
         methods: vec![{}],
         static_fields,
         instance_fields,
+        field_types: HashMap::new(),
+        interfaces: vec![],
+        modifiers: vec![],
     }};",
                 name,
                 match superclass {
@@ -374,6 +570,9 @@ impl ClassGenerator {
             Literal::Null => "Expr::Literal(Literal::Null)".to_string(),
             Literal::String(s) => format!("Expr::Literal(Literal::String(\"{}\".to_string()))", s),
             Literal::Number(n) => format!("Expr::Literal(Literal::Number({:?}))", n),
+            Literal::Int(n) => format!("Expr::Literal(Literal::Int({:?}))", n),
+            Literal::BigInt(n) => format!("Expr::Literal(Literal::BigInt({:?}.to_string()))", n),
+            Literal::Decimal(n) => format!("Expr::Literal(Literal::Decimal({:?}.to_string()))", n),
             Literal::Bool(b) => format!("Expr::Literal(Literal::Bool({}))", b),
             Literal::Void =>"Expr::Literal(Literal::Void)".to_string(),
             Literal::Array(exprs) =>  format!("Expr::Literal(Literal::Array(vec![{}]))", exprs.iter().map(Self::expr_to_code).collect::<Vec<_>>().join(", ")),
@@ -430,8 +629,8 @@ impl ClassGenerator {
             Self::expr_to_code(expr),
             postfix
         ),
-        Expr::Call { callee, args } => format!(
-            "Expr::Call {{ callee: Box::new({}), args: vec![{}] }}",
+        Expr::Call { callee, args, .. } => format!(
+            "Expr::Call {{ callee: Box::new({}), args: vec![{}], location: SourceLocation::default() }}",
             Self::expr_to_code(callee),
             args.iter().map(Self::expr_to_code).collect::<Vec<_>>().join(", ")
         ),
@@ -445,6 +644,35 @@ impl ClassGenerator {
             stmts.iter().map(Self::stmt_to_code).collect::<Vec<_>>().join(", ")
         ),
         Expr::Spread(expr) => format!("Expr::Spread(Box::new({}))", Self::expr_to_code(expr)),
+        Expr::Propagate(expr) => {
+            format!("Expr::Propagate(Box::new({}))", Self::expr_to_code(expr))
+        }
+        Expr::ArrayComprehension { element, clauses } => format!(
+            "Expr::ArrayComprehension {{ element: Box::new({}), clauses: vec![{}] }}",
+            Self::expr_to_code(element),
+            Self::clauses_to_code(clauses)
+        ),
+        Expr::ObjectComprehension {
+            key,
+            value,
+            clauses,
+        } => format!(
+            "Expr::ObjectComprehension {{ key: Box::new({}), value: Box::new({}), clauses: vec![{}] }}",
+            Self::expr_to_code(key),
+            Self::expr_to_code(value),
+            Self::clauses_to_code(clauses)
+        ),
+        Expr::Pipe { value, target, .. } => format!(
+            "Expr::Pipe {{ value: Box::new({}), target: Box::new({}), location: SourceLocation::default() }}",
+            Self::expr_to_code(value),
+            Self::expr_to_code(target)
+        ),
+        Expr::NamedArg { name, value } => format!(
+            "Expr::NamedArg {{ name: {:?}.to_string(), value: Box::new({}) }}",
+            name,
+            Self::expr_to_code(value)
+        ),
+        Expr::Delete(target) => format!("Expr::Delete(Box::new({}))", Self::expr_to_code(target)),
     }
     }
 
@@ -477,22 +705,105 @@ impl ClassGenerator {
                 ),
             Stmt::Export(inner) => format!("Stmt::Export(Rc::new({}))", Self::stmt_to_code(inner)),
             Stmt::ExportDefault(inner) => format!("Stmt::ExportDefault(Rc::new({}))", Self::stmt_to_code(inner)),
-            Stmt::Let { name, value } => format!(
-                        "Stmt::Let {{ name: \"{}\".to_string(), value: {} }}",
+            Stmt::Let { name, value, .. } => format!(
+                        "Stmt::Let {{ name: \"{}\".to_string(), ty: None, value: {}, location: SourceLocation::default() }}",
                         name,
                         Self::expr_to_code(value)
                     ),
-            Stmt::Throw(expr) => format!("Stmt::Throw({})", Self::expr_to_code(expr)),
-            Stmt::ExprStmt(expr) => format!("Stmt::ExprStmt({})", Self::expr_to_code(expr)),
-            Stmt::Return(Some(expr)) => format!("Stmt::Return(Some({}))", Self::expr_to_code(expr)),
-            Stmt::Return(None) => "Stmt::Return(None)".to_string(),
+            Stmt::Throw(expr, _) => format!("Stmt::Throw({}, SourceLocation::default())", Self::expr_to_code(expr)),
+            Stmt::Defer(expr) => format!("Stmt::Defer({})", Self::expr_to_code(expr)),
+            Stmt::Using { name, value, body } => format!(
+                "Stmt::Using {{ name: \"{}\".to_string(), value: {}, body: vec![{}] }}",
+                name,
+                Self::expr_to_code(value),
+                Self::stmt_vec_to_code(body)
+            ),
+            Stmt::ExprStmt(expr, _) => format!("Stmt::ExprStmt({}, SourceLocation::default())", Self::expr_to_code(expr)),
+            Stmt::Return(Some(expr), _) => format!("Stmt::Return(Some({}), SourceLocation::default())", Self::expr_to_code(expr)),
+            Stmt::Return(None, _) => "Stmt::Return(None, SourceLocation::default())".to_string(),
             Stmt::Break => "Stmt::Break".to_string(),
             Stmt::Continue => "Stmt::Continue".to_string(),
+            Stmt::InterfaceDecl { name, extends, methods } => {
+            let extends_code = extends
+                .iter()
+                .map(Self::expr_to_code)
+                .collect::<Vec<_>>()
+                .join(", ");
+            let methods_code = methods
+                .iter()
+                .map(Self::method_to_code)
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "Stmt::InterfaceDecl {{ name: \"{}\".to_string(), extends: vec![{}], methods: vec![{}] }}",
+                name, extends_code, methods_code
+            )
+        }
+            Stmt::EnumDecl { name, variants } => {
+            let variants_code = variants
+                .iter()
+                .map(|variant| {
+                    let fields = variant
+                        .fields
+                        .iter()
+                        .map(|field| format!("\"{}\".to_string()", field))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    format!(
+                        "EnumVariant {{ name: \"{}\".to_string(), fields: vec![{}] }}",
+                        variant.name, fields
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "Stmt::EnumDecl {{ name: \"{}\".to_string(), variants: vec![{}] }}",
+                name, variants_code
+            )
+        }
+        Stmt::Match { subject, arms, .. } => {
+            let arms_code = arms
+                .iter()
+                .map(|arm| {
+                    let pattern = match &arm.pattern {
+                        MatchPattern::Wildcard => "MatchPattern::Wildcard".to_string(),
+                        MatchPattern::Variant { path, bindings } => format!(
+                            "MatchPattern::Variant {{ path: {}, bindings: vec![{}] }}",
+                            Self::expr_to_code(path),
+                            bindings
+                                .iter()
+                                .map(|binding| format!("\"{}\".to_string()", binding))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                        MatchPattern::Value(expr) => {
+                            format!("MatchPattern::Value({})", Self::expr_to_code(expr))
+                        }
+                    };
+                    format!(
+                        "MatchArm {{ pattern: {}, body: vec![{}] }}",
+                        pattern,
+                        Self::stmt_vec_to_code(&arm.body)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "Stmt::Match {{ subject: {}, arms: vec![{}], location: SourceLocation::default() }}",
+                Self::expr_to_code(subject),
+                arms_code
+            )
+        }
  Stmt::FuncDecl(func) => {
             // Supondo que você tenha um func_to_code implementado
             format!("Stmt::FuncDecl({})", Self::func_to_code(func))
         }
-        Stmt::ClassDecl { name, superclass, methods, static_fields, instance_fields } => {
+        Stmt::Decorated { decorators, target } => format!(
+            "Stmt::Decorated {{ decorators: vec![{}], target: Box::new({}) }}",
+            Self::decorators_to_code(decorators),
+            Self::stmt_to_code(target)
+        ),
+        Stmt::ClassDecl { name, superclass, methods, static_fields, instance_fields, .. } => {
             let superclass_code = if let Some(sc) = superclass {
                 format!("Some({})", Self::expr_to_code(sc))
             } else {
@@ -515,7 +826,7 @@ impl ClassGenerator {
                 .join(", ");
 
             format!(
-                "Stmt::ClassDecl {{ name: \"{}\".to_string(), superclass: {}, methods: vec![{}], static_fields: std::collections::HashMap::from([{}]), instance_fields: std::collections::HashMap::from([{}]) }}",
+                "Stmt::ClassDecl {{ name: \"{}\".to_string(), superclass: {}, methods: vec![{}], static_fields: std::collections::HashMap::from([{}]), instance_fields: std::collections::HashMap::from([{}]), field_types: std::collections::HashMap::new(), interfaces: vec![], modifiers: vec![] }}",
                 name,
                 superclass_code,
                 methods_code,
@@ -592,19 +903,21 @@ impl ClassGenerator {
                 body_code
             )
         }
-        Stmt::TryCatchFinally { try_block, catch_block, finally_block } => {
+        Stmt::TryCatchFinally { try_block, catch_clauses, finally_block } => {
             let try_code = Self::stmt_vec_to_code(try_block);
 
-            let catch_code = catch_block.as_ref()
-                .map(|(param, block)| format!("Some((\"{}\".to_string(), vec![{}]))", param, Self::stmt_vec_to_code(block)))
-                .unwrap_or_else(|| "None".to_string());
+            let catch_code = catch_clauses
+                .iter()
+                .map(Self::catch_clause_to_code)
+                .collect::<Vec<_>>()
+                .join(", ");
 
             let finally_code = finally_block.as_ref()
                 .map(|block| format!("Some(vec![{}])", Self::stmt_vec_to_code(block)))
                 .unwrap_or_else(|| "None".to_string());
 
             format!(
-                "Stmt::TryCatchFinally {{ try_block: vec![{}], catch_block: {}, finally_block: {} }}",
+                "Stmt::TryCatchFinally {{ try_block: vec![{}], catch_clauses: vec![{}], finally_block: {} }}",
                 try_code,
                 catch_code,
                 finally_code
@@ -612,6 +925,37 @@ impl ClassGenerator {
         }
         }
     }
+    pub fn catch_clause_to_code(clause: &CatchClause) -> String {
+        let binding = match &clause.binding {
+            None => "None".to_string(),
+            Some(CatchBinding::Identifier(name)) => {
+                format!("Some(CatchBinding::Identifier(\"{}\".to_string()))", name)
+            }
+            Some(CatchBinding::Object(fields)) => {
+                let fields = fields
+                    .iter()
+                    .map(|(prop, local)| {
+                        format!("(\"{}\".to_string(), \"{}\".to_string())", prop, local)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("Some(CatchBinding::Object(vec![{}]))", fields)
+            }
+        };
+        let optional_expr = |expr: &Option<Expr>| {
+            expr.as_ref()
+                .map(|expr| format!("Some({})", Self::expr_to_code(expr)))
+                .unwrap_or_else(|| "None".to_string())
+        };
+
+        format!(
+            "CatchClause {{ binding: {}, error_type: {}, guard: {}, body: vec![{}] }}",
+            binding,
+            optional_expr(&clause.error_type),
+            optional_expr(&clause.guard),
+            Self::stmt_vec_to_code(&clause.body)
+        )
+    }
     pub fn stmt_vec_to_code(stmts: &[Stmt]) -> String {
         stmts
             .iter()
@@ -643,15 +987,53 @@ impl ClassGenerator {
         };
 
         format!(
-            "MethodDecl {{ name: \"{}\".to_string(), params: vec![{}], body: vec![{}], modifiers: vec![{}], vararg: {} }}",
+            "MethodDecl {{ name: \"{}\".to_string(), params: vec![{}], body: vec![{}], modifiers: vec![{}], vararg: {}, signature: Signature::default(), decorators: vec![{}] }}",
             method.name,
             params_code,
             body_code,
             modifiers_code,
             vararg_code,
+            Self::decorators_to_code(&method.decorators),
         )
     }
 
+    fn clauses_to_code(clauses: &[ComprehensionClause]) -> String {
+        clauses
+            .iter()
+            .map(|clause| match clause {
+                ComprehensionClause::For { target, iterable } => format!(
+                    "ComprehensionClause::For {{ target: {}, iterable: {} }}",
+                    Self::expr_to_code(target),
+                    Self::expr_to_code(iterable)
+                ),
+                ComprehensionClause::If(condition) => {
+                    format!("ComprehensionClause::If({})", Self::expr_to_code(condition))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn decorators_to_code(decorators: &[Decorator]) -> String {
+        decorators
+            .iter()
+            .map(|decorator| {
+                format!(
+                    "Decorator {{ name: \"{}\".to_string(), callee: {}, args: vec![{}], location: SourceLocation::default() }}",
+                    decorator.name,
+                    Self::expr_to_code(&decorator.callee),
+                    decorator
+                        .args
+                        .iter()
+                        .map(Self::expr_to_code)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     pub fn func_to_code(func: &FunctionStmt) -> String {
         let params_code = func
             .params
@@ -668,7 +1050,7 @@ impl ClassGenerator {
         };
 
         format!(
-            "FunctionStmt {{ name: \"{}\".to_string(), params: vec![{}], body: vec![{}], vararg: {} }}",
+            "FunctionStmt {{ name: \"{}\".to_string(), params: vec![{}], body: vec![{}], vararg: {}, signature: Signature::default() }}",
             func.name,
             params_code,
             body_code,
@@ -726,6 +1108,7 @@ impl ClassGenerator {
             BinaryOperator::Subtract => "Subtract",
             BinaryOperator::Multiply => "Multiply",
             BinaryOperator::Divide => "Divide",
+            BinaryOperator::FloorDivide => "FloorDivide",
             BinaryOperator::Modulo => "Modulo",
             BinaryOperator::Exponentiate => "Exponentiate",
         }
@@ -735,11 +1118,14 @@ impl ClassGenerator {
         match op {
             CompareOperator::Eq => "Eq",
             CompareOperator::Ne => "Ne",
+            CompareOperator::StrictEq => "StrictEq",
+            CompareOperator::StrictNe => "StrictNe",
             CompareOperator::Gt => "Gt",
             CompareOperator::Ge => "Ge",
             CompareOperator::Lt => "Lt",
             CompareOperator::Le => "Le",
             CompareOperator::InstanceOf => "InstanceOf",
+            CompareOperator::Implements => "Implements",
             CompareOperator::In => "In",
         }
     }
@@ -7,14 +7,21 @@ pub mod values;
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use serde::{Deserialize, Serialize};
-use values::Value;
+use values::{Function, Value};
 
-use crate::environment::values::NativeObjectTrait;
+use crate::{
+    ast::ast::{Expr, SourceLocation, Stmt},
+    interpreter::gc,
+};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Environment {
     pub variables: Vec<(String, Value)>,
     pub parent: Option<Rc<RefCell<Environment>>>,
+    /// Expressões de `defer` (e limpezas de `using`) pendentes neste escopo,
+    /// avaliadas em ordem inversa por `Interpreter::exit_scope`.
+    #[serde(skip)]
+    pub deferred: Vec<Expr>,
 }
 
 // export "EnvironmentMap" as "Environment"
@@ -47,6 +54,69 @@ fn global() -> Vec<(String, Value)> {
         }),
     ));
 
+    env.push((
+        "deepEqual".to_string(),
+        Value::Builtin(|args: Vec<Value>| match &args[..] {
+            [a, b] => Value::Bool(a.deep_equal(b)),
+            args => Value::Error(Rc::new(RefCell::new(Value::String(format!(
+                "deepEqual expects 2 arguments, got {}",
+                args.len()
+            ))))),
+        }),
+    ));
+
+    // Reflexão: `decorators(f)` -> [{ name: "memoize", args: [] }, ...]
+    env.push((
+        "decorators".to_string(),
+        Value::Builtin(|args: Vec<Value>| match args.first() {
+            Some(target) => Value::array(
+                target
+                    .decorations()
+                    .iter()
+                    .map(|decoration| decoration.to_value())
+                    .collect(),
+            ),
+            None => Value::array(vec![]),
+        }),
+    ));
+
+    // `compose(f, g)(x)` é `f(g(x))`; `pipe(f, g)(x)` é `g(f(x))`
+    env.push((
+        "compose".to_string(),
+        Value::Builtin(|args: Vec<Value>| composed("compose", args.into_iter().rev().collect())),
+    ));
+    env.push((
+        "pipe".to_string(),
+        Value::Builtin(|args: Vec<Value>| composed("pipe", args)),
+    ));
+
+    // Coletor de ciclos: `gc()` retorna quantos objetos foram liberados
+    env.push((
+        "gc".to_string(),
+        Value::Builtin(|_args: Vec<Value>| Value::Number(gc::collect().into())),
+    ));
+    env.push((
+        "gcStats".to_string(),
+        Value::Builtin(|_args: Vec<Value>| {
+            let stats = gc::stats();
+            Value::object(vec![
+                (
+                    "collections".to_string(),
+                    Value::Number(stats.collections.into()),
+                ),
+                (
+                    "collected".to_string(),
+                    Value::Number(stats.collected.into()),
+                ),
+                (
+                    "lastCollected".to_string(),
+                    Value::Number(stats.last_collected.into()),
+                ),
+                ("tracked".to_string(), Value::Number(gc::tracked().into())),
+            ])
+        }),
+    ));
+
     env.push((
         "now".to_string(),
         Value::Builtin(|_args: Vec<Value>| {
@@ -55,7 +125,7 @@ fn global() -> Vec<(String, Value)> {
                 .duration_since(std::time::UNIX_EPOCH)
                 .expect("Time went backwards");
             let in_ms = since_the_epoch.as_millis();
-            Value::Number((in_ms as f64).into())
+            Value::Number((in_ms as i64).into())
         }),
     ));
 
@@ -71,23 +141,71 @@ fn global() -> Vec<(String, Value)> {
             env.push((name, value));
         }
     }
-    env.set_prop("NaN", Value::Number(f64::NAN.into())).unwrap();
 
     // find "Io"
     env
 }
 
+/// Função que aplica `functions` em sequência: a primeira recebe os argumentos da
+/// chamada e cada uma das seguintes o resultado da anterior.
+fn composed(name: &str, functions: Vec<Value>) -> Value {
+    if functions.is_empty() {
+        return Value::Error(Rc::new(RefCell::new(Value::String(format!(
+            "{name} expects at least one function"
+        )))));
+    }
+    let closure = Environment::new_rc();
+    let mut body = Expr::Spread(Box::new(Expr::Identifier("%args".to_string())));
+    for (index, function) in functions.into_iter().enumerate() {
+        let callable = match &function {
+            Value::Function(_) | Value::Builtin(_) | Value::InternalFunction(_) => true,
+            instance @ Value::Instance(_) => instance.has_operator("call"),
+            _ => false,
+        };
+        if !callable {
+            return Value::Error(Rc::new(RefCell::new(Value::String(format!(
+                "{name} expects functions, got {}",
+                function.type_of()
+            )))));
+        }
+        let hidden = format!("%f{index}");
+        closure.borrow_mut().define(hidden.clone(), function);
+        body = Expr::Call {
+            callee: Box::new(Expr::Identifier(hidden)),
+            args: vec![body],
+            location: SourceLocation::default(),
+        };
+    }
+    let function = Function::new(
+        name.to_string(),
+        vec![],
+        Some("%args".to_string()),
+        vec![Stmt::Return(Some(body), SourceLocation::default())],
+        closure,
+        vec![],
+    );
+    Value::Function(Rc::new(function))
+}
+
 impl Environment {
     pub fn new() -> Self {
         let global = global();
         Environment {
             variables: global,
             parent: None,
+            deferred: vec![],
         }
     }
 
     pub fn new_rc() -> Rc<RefCell<Environment>> {
-        Rc::new(RefCell::new(Environment::new()))
+        Environment::new().into_tracked_rc()
+    }
+
+    /// `Rc` registrado no coletor de ciclos (ver `interpreter::gc`).
+    fn into_tracked_rc(self) -> Rc<RefCell<Environment>> {
+        let env = Rc::new(RefCell::new(self));
+        gc::track_env(&env);
+        env
     }
 
     pub fn merge_environments(&mut self, other: Environment) {
@@ -98,26 +216,26 @@ impl Environment {
         Environment {
             variables: global(),
             parent: Some(Rc::clone(parent)),
+            deferred: vec![],
         }
     }
 
     pub fn new_rc_enclosed(parent: &mut Rc<RefCell<Environment>>) -> Rc<RefCell<Environment>> {
-        Rc::new(RefCell::new(Environment {
-            variables: global(),
-            parent: Some(Rc::clone(parent)),
-        }))
+        Environment::new_enclosed(parent).into_tracked_rc()
     }
 
     pub fn new_rc_merged(parent: Rc<RefCell<Environment>>) -> Rc<RefCell<Environment>> {
         let mut env = Environment::new();
         env.merge_environments(parent.borrow().clone());
-        Rc::new(RefCell::new(env))
+        env.into_tracked_rc()
     }
     pub fn rc_enclosed(&self, parent: Rc<RefCell<Environment>>) -> Rc<RefCell<Environment>> {
-        Rc::new(RefCell::new(Environment {
+        Environment {
             variables: global(),
             parent: Some(parent),
-        }))
+            deferred: vec![],
+        }
+        .into_tracked_rc()
     }
 
     pub fn copy_from(&mut self, other: Rc<RefCell<Environment>>) {
@@ -185,11 +303,12 @@ impl Environment {
         Environment {
             variables: self.variables.clone(), // ou shallow copy se possível
             parent: Some(parent),
+            deferred: vec![],
         }
     }
 
     pub fn to_rc(&self) -> Rc<RefCell<Environment>> {
-        Rc::new(RefCell::new(self.clone()))
+        self.clone().into_tracked_rc()
     }
     pub fn get_vars(&self) -> Vec<(String, Value)> {
         self.variables.clone()
@@ -37,6 +37,33 @@ macro_rules! impl_from_for_class {
         }
     };
 
+    // Caso especial para inteiros
+    ($t:ty, i64, $c:ty) => {
+        impl From<$t> for $c {
+            fn from(value: $t) -> Self {
+                Self::new_with_int(value as i64)
+            }
+        }
+
+        impl From<&$t> for $c {
+            fn from(value: &$t) -> Self {
+                Self::new_with_int(*value as i64)
+            }
+        }
+
+        impl From<$c> for $t {
+            fn from(value: $c) -> Self {
+                value.to_i64() as $t
+            }
+        }
+
+        impl From<&$c> for $t {
+            fn from(value: &$c) -> Self {
+                value.to_i64() as $t
+            }
+        }
+    };
+
     // Caso especial para String
     ($t:ty, String, $c:ty) => {
         impl From<$t> for $c {
@@ -105,45 +132,45 @@ macro_rules! impl_math_operations {
         impl std::ops::Div<$Rhs> for $Lhs {
             type Output = NativeNumberClass;
             fn div(self, rhs: $Rhs) -> Self::Output {
-                let lhs_val = (&self).get_value();
-                let rhs_val = (&rhs).get_value();
-                NativeNumberClass::new_with_value(lhs_val / rhs_val)
+                let lhs: &NativeNumberClass = &self;
+                let rhs: &NativeNumberClass = &rhs;
+                lhs.binary_or_float(&$crate::ast::ast::BinaryOperator::Divide, rhs)
             }
         }
 
         impl std::ops::Mul<$Rhs> for $Lhs {
             type Output = NativeNumberClass;
             fn mul(self, rhs: $Rhs) -> Self::Output {
-                let mut value = (&self).get_value();
-                value *= (&rhs).get_value();
-                NativeNumberClass::new_with_value(value)
+                let lhs: &NativeNumberClass = &self;
+                let rhs: &NativeNumberClass = &rhs;
+                lhs.binary_or_float(&$crate::ast::ast::BinaryOperator::Multiply, rhs)
             }
         }
 
         impl std::ops::Sub<$Rhs> for $Lhs {
             type Output = NativeNumberClass;
             fn sub(self, rhs: $Rhs) -> Self::Output {
-                let mut value = (&self).get_value();
-                value -= (&rhs).get_value();
-                NativeNumberClass::new_with_value(value)
+                let lhs: &NativeNumberClass = &self;
+                let rhs: &NativeNumberClass = &rhs;
+                lhs.binary_or_float(&$crate::ast::ast::BinaryOperator::Subtract, rhs)
             }
         }
 
         impl std::ops::Add<$Rhs> for $Lhs {
             type Output = NativeNumberClass;
             fn add(self, rhs: $Rhs) -> Self::Output {
-                let mut value = (&self).get_value();
-                value += (&rhs).get_value();
-                NativeNumberClass::new_with_value(value)
+                let lhs: &NativeNumberClass = &self;
+                let rhs: &NativeNumberClass = &rhs;
+                lhs.binary_or_float(&$crate::ast::ast::BinaryOperator::Add, rhs)
             }
         }
 
         impl std::ops::Rem<$Rhs> for $Lhs {
             type Output = NativeNumberClass;
             fn rem(self, rhs: $Rhs) -> Self::Output {
-                let mut value = (&self).get_value();
-                value %= (&rhs).get_value();
-                NativeNumberClass::new_with_value(value)
+                let lhs: &NativeNumberClass = &self;
+                let rhs: &NativeNumberClass = &rhs;
+                lhs.binary_or_float(&$crate::ast::ast::BinaryOperator::Modulo, rhs)
             }
         }
     };
@@ -1,7 +1,10 @@
 use dyn_clone::DynClone;
-use std::{any::Any, cell::RefCell, rc::Rc};
+use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
 
-use crate::{ast::ast::ControlFlow, environment::values::Value};
+use crate::{
+    ast::ast::{ControlFlow, ErrorKind},
+    environment::values::Value,
+};
 
 dyn_clone::clone_trait_object!(NativeCallable);
 
@@ -21,6 +24,11 @@ pub trait NativeCallable: std::fmt::Debug + NativeCallableClone + Any + DynClone
     }
     fn get_name(&self) -> String;
 
+    /// Classe de erro usada quando um método do módulo retorna `ControlFlow::Error`.
+    fn error_kind(&self) -> ErrorKind {
+        ErrorKind::TypeError
+    }
+
     fn get_args(&self) -> Vec<Value>;
 
     fn set_args(&mut self, args: Vec<Value>) -> Result<(), String> {
@@ -41,6 +49,22 @@ pub trait NativeCallable: std::fmt::Debug + NativeCallableClone + Any + DynClone
     fn call_with_args(&self, method_name: &str, args: Vec<Value>) -> ControlFlow<Value> {
         todo!("Method called {method_name} {args:?}")
     }
+    /// Chamada com argumentos nomeados (`modulo.metodo(a: 1)`), recebidos em `named`
+    /// junto com os posicionais da chamada. Por padrão os nomes não são aceitos.
+    fn call_with_named_args(
+        &self,
+        method_name: &str,
+        _args: Vec<Value>,
+        _named: HashMap<String, Value>,
+    ) -> ControlFlow<Value> {
+        ControlFlow::Error(
+            format!(
+                "Method {}.{method_name} does not accept named arguments",
+                self.get_name()
+            )
+            .into(),
+        )
+    }
     fn instantiate(&self, _args: Vec<Value>) -> Result<Value, String> {
         Err(format!("Class {} cannot be instantiated", self.get_name()))
     }
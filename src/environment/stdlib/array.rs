@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     ast::ast::ControlFlow,
     environment::{native::native_callable::NativeCallable, values::Value},
+    interpreter::gc,
 };
 
 create_instance_fn!(NativeArrayClass);
@@ -18,6 +19,7 @@ pub struct NativeArrayClass {
 
 impl NativeArrayClass {
     pub fn new_with_value(value: Rc<RefCell<Vec<Value>>>) -> Self {
+        gc::track_array(&value);
         Self {
             args: vec![],
             value: Some(value),
@@ -170,8 +172,8 @@ impl NativeCallable for NativeArrayClass {
                 let index = vec
                     .iter()
                     .position(|v| v.equal(value))
-                    .map(|i| i as f64)
-                    .unwrap_or(-1.0);
+                    .map(|i| i as i64)
+                    .unwrap_or(-1);
                 ControlFlow::Return(Value::Number(index.into()))
             }
             "lastIndexOf" => {
@@ -182,8 +184,8 @@ impl NativeCallable for NativeArrayClass {
                 let index = vec
                     .iter()
                     .rposition(|v| v.equal(value))
-                    .map(|i| i as f64)
-                    .unwrap_or(-1.0);
+                    .map(|i| i as i64)
+                    .unwrap_or(-1);
                 ControlFlow::Return(Value::Number(index.into()))
             }
             "includes" => {
@@ -202,7 +204,7 @@ impl NativeCallable for NativeArrayClass {
                 _ => ControlFlow::Error("isArray espera um único argumento".to_string().into()),
             },
             "length" => ControlFlow::Return(Value::Number(
-                (self.get_value().borrow().len() as f64).into(),
+                (self.get_value().borrow().len() as i64).into(),
             )),
             "sort" => {
                 let vec = self.get_value();
@@ -0,0 +1,92 @@
+use num_traits::ToPrimitive;
+
+use crate::{
+    ast::ast::ControlFlow,
+    environment::{
+        native::native_callable::NativeCallable, stdlib::number::NativeNumberClass, values::Value,
+    },
+};
+
+create_instance_fn!(NativeBigIntClass);
+
+#[derive(Debug, Clone)]
+pub struct NativeBigIntClass {
+    args: Vec<Value>,
+}
+
+impl NativeBigIntClass {
+    /// Converte strings e números para BigInt (floats e decimais são truncados).
+    pub fn convert(value: &Value) -> Result<NativeNumberClass, String> {
+        match value {
+            Value::Number(n) => n.to_bigint().map(NativeNumberClass::new_with_bigint),
+            Value::String(s) => NativeNumberClass::parse_bigint(&s.to_string()),
+            other => Err(format!("Cannot convert {} to bigint", other.type_of())),
+        }
+    }
+}
+
+impl NativeCallable for NativeBigIntClass {
+    fn new() -> Self {
+        Self { args: vec![] }
+    }
+
+    fn call_with_args(&self, method_name: &str, args: Vec<Value>) -> ControlFlow<Value> {
+        match (method_name, &args[..]) {
+            ("from", [value]) => match Self::convert(value) {
+                Ok(n) => ControlFlow::Return(Value::Number(n)),
+                Err(err) => ControlFlow::Error(err.into()),
+            },
+            ("toNumber", [Value::Number(n)]) => {
+                let number = match n.to_bigint().map(|b| b.to_i64()) {
+                    Ok(Some(i)) => i.into(),
+                    _ => n.get_value().into(),
+                };
+                ControlFlow::Return(Value::Number(number))
+            }
+            ("toString", [Value::Number(n)]) => match n.to_bigint() {
+                Ok(b) => ControlFlow::Return(Value::String(b.to_string())),
+                Err(err) => ControlFlow::Error(err.into()),
+            },
+            ("from" | "toNumber" | "toString", _) => ControlFlow::Error(
+                format!("BigInt.{method_name} espera um único argumento").into(),
+            ),
+            _ => ControlFlow::Error(
+                format!("Método nativo desconhecido: BigInt.{}", method_name).into(),
+            ),
+        }
+    }
+
+    fn methods_names(&self) -> Vec<String> {
+        vec!["from", "toNumber", "toString"]
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    fn get_args(&self) -> Vec<Value> {
+        self.args.clone()
+    }
+
+    fn add_args(&mut self, args: Vec<Value>) -> Result<(), String> {
+        self.args = args;
+        Ok(())
+    }
+
+    fn instantiate(&self, args: Vec<Value>) -> Result<Value, String> {
+        match &args[..] {
+            [value] => Self::convert(value).map(Value::Number),
+            _ => Err(format!(
+                "Class 'BigInt' expected 1 argument but received {}",
+                args.len()
+            )),
+        }
+    }
+
+    fn get_name(&self) -> String {
+        "BigInt".to_string()
+    }
+
+    fn is_static(&self) -> bool {
+        true
+    }
+}
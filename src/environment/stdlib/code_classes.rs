@@ -22,31 +22,39 @@ pub fn create_testsexception_class() -> Stmt {
                     left: Box::new(Expr::Identifier("message".to_string())),
                     right: Box::new(Expr::Literal(Literal::Null)),
                 },
-                then_branch: vec![Stmt::ExprStmt(Expr::Assign {
-                    target: Box::new(Expr::Identifier("message".to_string())),
-                    op: AssignOperator::Assign,
-                    value: Box::new(Expr::BinaryOp {
-                        op: Operator::Binary(crate::ast::ast::BinaryOperator::Add),
-                        left: Box::new(Expr::BinaryOp {
+                then_branch: vec![Stmt::ExprStmt(
+                    Expr::Assign {
+                        target: Box::new(Expr::Identifier("message".to_string())),
+                        op: AssignOperator::Assign,
+                        value: Box::new(Expr::BinaryOp {
                             op: Operator::Binary(crate::ast::ast::BinaryOperator::Add),
-                            left: Box::new(Expr::Literal(Literal::String("\x1b[31m".to_string()))),
-                            right: Box::new(Expr::Literal(Literal::String(
-                                "Erro de teste".to_string(),
-                            ))),
+                            left: Box::new(Expr::BinaryOp {
+                                op: Operator::Binary(crate::ast::ast::BinaryOperator::Add),
+                                left: Box::new(Expr::Literal(Literal::String(
+                                    "\x1b[31m".to_string(),
+                                ))),
+                                right: Box::new(Expr::Literal(Literal::String(
+                                    "Erro de teste".to_string(),
+                                ))),
+                            }),
+                            right: Box::new(Expr::Literal(Literal::String("\x1b[0m".to_string()))),
                         }),
-                        right: Box::new(Expr::Literal(Literal::String("\x1b[0m".to_string()))),
-                    }),
-                })],
+                    },
+                    SourceLocation::default(),
+                )],
                 else_ifs: vec![],
                 else_branch: None,
             },
-            Stmt::ExprStmt(Expr::Call {
-                callee: Box::new(Expr::Identifier("super".to_string())),
-                args: vec![
-                    Expr::Identifier("message".to_string()),
-                    Expr::Literal(Literal::String("Exc".to_string())),
-                ],
-            }),
+            Stmt::ExprStmt(
+                Expr::Call {
+                    callee: Box::new(Expr::Identifier("super".to_string())),
+                    args: vec![
+                        Expr::Identifier("message".to_string()),
+                        Expr::Literal(Literal::String("Exc".to_string())),
+                    ],
+                },
+                SourceLocation::default(),
+            ),
         ],
         modifiers: vec![],
     };
@@ -55,10 +63,13 @@ pub fn create_testsexception_class() -> Stmt {
         name: "getTeste".to_string(),
         params: vec![],
         vararg: None,
-        body: vec![Stmt::Return(Some(Expr::GetProperty {
-            object: Box::new(Expr::This),
-            property: Box::new(Expr::Identifier("teste".to_string())),
-        }))],
+        body: vec![Stmt::Return(
+            Some(Expr::GetProperty {
+                object: Box::new(Expr::This),
+                property: Box::new(Expr::Identifier("teste".to_string())),
+            }),
+            SourceLocation::default(),
+        )],
         modifiers: vec![],
     };
 
@@ -66,7 +77,7 @@ pub fn create_testsexception_class() -> Stmt {
         name: "throw".to_string(),
         params: vec![],
         vararg: None,
-        body: vec![Stmt::Throw(Expr::This)],
+        body: vec![Stmt::Throw(Expr::This, SourceLocation::default())],
         modifiers: vec![],
     };
 
@@ -0,0 +1,112 @@
+use num_traits::ToPrimitive;
+use rust_decimal::RoundingStrategy;
+
+use crate::{
+    ast::ast::ControlFlow,
+    environment::{
+        native::native_callable::NativeCallable, stdlib::number::NativeNumberClass, values::Value,
+    },
+};
+
+create_instance_fn!(NativeDecimalClass);
+
+#[derive(Debug, Clone)]
+pub struct NativeDecimalClass {
+    args: Vec<Value>,
+}
+
+impl NativeDecimalClass {
+    /// Converte strings e números para Decimal sem passar por `f64` quando possível.
+    pub fn convert(value: &Value) -> Result<NativeNumberClass, String> {
+        match value {
+            Value::Number(n) => n.to_decimal().map(NativeNumberClass::new_with_decimal),
+            Value::String(s) => NativeNumberClass::parse_decimal(&s.to_string()),
+            other => Err(format!("Cannot convert {} to decimal", other.type_of())),
+        }
+    }
+}
+
+impl NativeCallable for NativeDecimalClass {
+    fn new() -> Self {
+        Self { args: vec![] }
+    }
+
+    fn call_with_args(&self, method_name: &str, args: Vec<Value>) -> ControlFlow<Value> {
+        match (method_name, &args[..]) {
+            ("from", [value]) => match Self::convert(value) {
+                Ok(n) => ControlFlow::Return(Value::Number(n)),
+                Err(err) => ControlFlow::Error(err.into()),
+            },
+            ("toNumber", [Value::Number(n)]) => {
+                ControlFlow::Return(Value::Number(n.get_value().into()))
+            }
+            ("toString", [Value::Number(n)]) => match n.to_decimal() {
+                Ok(d) => ControlFlow::Return(Value::String(d.to_string())),
+                Err(err) => ControlFlow::Error(err.into()),
+            },
+            ("round", [Value::Number(n), Value::Number(places)]) => {
+                let places = match places.get_int().and_then(|p| p.to_u32()) {
+                    Some(places) => places,
+                    None => {
+                        return ControlFlow::Error(
+                            "Decimal.round espera um número inteiro de casas".to_string().into(),
+                        )
+                    }
+                };
+                match n.to_decimal() {
+                    Ok(d) => ControlFlow::Return(Value::Number(
+                        NativeNumberClass::new_with_decimal(
+                            d.round_dp_with_strategy(places, RoundingStrategy::MidpointAwayFromZero),
+                        ),
+                    )),
+                    Err(err) => ControlFlow::Error(err.into()),
+                }
+            }
+            ("from" | "toNumber" | "toString", _) => ControlFlow::Error(
+                format!("Decimal.{method_name} espera um único argumento").into(),
+            ),
+            ("round", _) => ControlFlow::Error(
+                "Decimal.round espera um número e a quantidade de casas"
+                    .to_string()
+                    .into(),
+            ),
+            _ => ControlFlow::Error(
+                format!("Método nativo desconhecido: Decimal.{}", method_name).into(),
+            ),
+        }
+    }
+
+    fn methods_names(&self) -> Vec<String> {
+        vec!["from", "toNumber", "toString", "round"]
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    fn get_args(&self) -> Vec<Value> {
+        self.args.clone()
+    }
+
+    fn add_args(&mut self, args: Vec<Value>) -> Result<(), String> {
+        self.args = args;
+        Ok(())
+    }
+
+    fn instantiate(&self, args: Vec<Value>) -> Result<Value, String> {
+        match &args[..] {
+            [value] => Self::convert(value).map(Value::Number),
+            _ => Err(format!(
+                "Class 'Decimal' expected 1 argument but received {}",
+                args.len()
+            )),
+        }
+    }
+
+    fn get_name(&self) -> String {
+        "Decimal".to_string()
+    }
+
+    fn is_static(&self) -> bool {
+        true
+    }
+}
@@ -1,7 +1,7 @@
 use std::io::Write;
 
 use crate::{
-    ast::ast::ControlFlow,
+    ast::ast::{ControlFlow, ErrorKind},
     environment::{native::native_callable::NativeCallable, Value},
 };
 
@@ -26,33 +26,49 @@ impl NativeCallable for NativeFsClass {
         Self { args: vec![] }
     }
     fn call_with_args(&self, method_name: &str, args: Vec<Value>) -> ControlFlow<Value> {
+        let Some(path) = args.first().map(|path| path.to_string()) else {
+            return ControlFlow::Error(format!("Fs.{method_name} espera um caminho").into());
+        };
+        let content = args.get(1).map(|content| content.to_string()).unwrap_or_default();
         match method_name {
             "write" => {
-                let mut file = std::fs::File::create(args[0].to_string()).unwrap();
-                let ret = file.write_all(args[1].to_string().as_bytes()).is_ok();
-                ControlFlow::Return(Value::Bool(ret))
+                let mut file = match std::fs::File::create(&path) {
+                    Ok(file) => file,
+                    Err(err) => return ControlFlow::Error(format!("Cannot write '{path}': {err}").into()),
+                };
+                match file.write_all(content.as_bytes()) {
+                    Ok(_) => ControlFlow::Return(Value::Bool(true)),
+                    Err(err) => ControlFlow::Error(format!("Cannot write '{path}': {err}").into()),
+                }
             }
             "writeLine" => {
-                let mut file = std::fs::OpenOptions::new()
+                let file = std::fs::OpenOptions::new()
                     .create(true)
                     .append(true)
-                    .open(args[0].to_string())
-                    .unwrap();
+                    .open(&path);
+                let mut file = match file {
+                    Ok(file) => file,
+                    Err(err) => return ControlFlow::Error(format!("Cannot write '{path}': {err}").into()),
+                };
 
-                let line = args[1].to_string() + "\n";
-                let ret = file.write_all(line.as_bytes()).is_ok();
-                ControlFlow::Return(Value::Bool(ret))
+                let line = content + "\n";
+                match file.write_all(line.as_bytes()) {
+                    Ok(_) => ControlFlow::Return(Value::Bool(true)),
+                    Err(err) => ControlFlow::Error(format!("Cannot write '{path}': {err}").into()),
+                }
             }
             "readFile" => {
-                let file = std::fs::read_to_string(args[0].to_string()).unwrap();
-                ControlFlow::Return(Value::String(file.into()))
+                match std::fs::read_to_string(&path) {
+                    Ok(file) => ControlFlow::Return(Value::String(file)),
+                    Err(err) => ControlFlow::Error(format!("Cannot read '{path}': {err}").into()),
+                }
             }
             _ => ControlFlow::Error(format!("Método nativo desconhecido: {}", method_name).into()),
         }
     }
 
     fn methods_names(&self) -> Vec<String> {
-        let methods = vec!["write", "readFile"];
+        let methods = ["write", "writeLine", "readFile"];
         methods.iter().map(|s| s.to_string()).collect()
     }
 
@@ -66,6 +82,9 @@ impl NativeCallable for NativeFsClass {
     fn get_name(&self) -> String {
         "Fs".to_string()
     }
+    fn error_kind(&self) -> ErrorKind {
+        ErrorKind::IoError
+    }
     fn is_static(&self) -> bool {
         true
     }
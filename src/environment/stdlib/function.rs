@@ -0,0 +1,119 @@
+use std::{collections::HashMap, rc::Rc};
+
+use crate::{
+    ast::ast::ControlFlow,
+    environment::{
+        native::native_callable::NativeCallable,
+        values::{bind_named_args, Function, Value},
+    },
+};
+
+/// Métodos de valores de função: `f.bind(this, ...parciais)`, `f.call(this, ...args)`,
+/// `f.apply(this, [args])` e `f.toString()`. Um `this` nulo mantém o receptor atual.
+#[derive(Debug, Clone)]
+pub struct NativeFunctionClass {
+    function: Option<Rc<Function>>,
+}
+
+impl NativeFunctionClass {
+    pub const METHODS: [&'static str; 4] = ["bind", "call", "apply", "toString"];
+
+    pub fn new_with_function(function: Rc<Function>) -> Self {
+        Self {
+            function: Some(function),
+        }
+    }
+
+    /// Argumentos repassados à função por `call` e `apply`, sem o `this`.
+    fn forwarded_args(method_name: &str, args: &[Value]) -> Result<Vec<Value>, String> {
+        match method_name {
+            "apply" => Self::apply_args(args),
+            _ => Ok(args.get(1..).unwrap_or_default().to_vec()),
+        }
+    }
+
+    fn apply_args(args: &[Value]) -> Result<Vec<Value>, String> {
+        match args.get(1) {
+            None | Some(Value::Null) | Some(Value::Void) => Ok(vec![]),
+            Some(Value::Array(items)) => Ok(items.get_value().borrow().clone()),
+            Some(other) => Err(format!(
+                "Function.apply espera um array de argumentos, recebeu {}",
+                other.type_of()
+            )),
+        }
+    }
+}
+
+impl NativeCallable for NativeFunctionClass {
+    fn new() -> Self {
+        Self { function: None }
+    }
+
+    fn call_with_args(&self, method_name: &str, args: Vec<Value>) -> ControlFlow<Value> {
+        let Some(function) = &self.function else {
+            return ControlFlow::Error(format!("Function.{method_name} sem função").into());
+        };
+        let this = args.first().cloned().unwrap_or(Value::Null);
+        let rest = args.get(1..).unwrap_or_default().to_vec();
+
+        match method_name {
+            "bind" => ControlFlow::Return(Value::Function(function.bind(this, rest).into())),
+            // Erros lançados pela função voltam como `Value::Error` para o chamador propagar
+            "call" | "apply" => match Self::forwarded_args(method_name, &args) {
+                Ok(rest) => ControlFlow::Return(function.bind(this, vec![]).call(rest)),
+                Err(err) => ControlFlow::Error(err.into()),
+            },
+            "toString" => {
+                let mut function = Function::from(function.clone());
+                ControlFlow::Return(Value::String(function.generate_proto().to_string()))
+            }
+            _ => ControlFlow::Error(format!("Método nativo desconhecido: {}", method_name).into()),
+        }
+    }
+
+    /// `f.call(this, a: 1)` e `f.apply(this, [..], a: 1)` repassam os nomes para a função.
+    fn call_with_named_args(
+        &self,
+        method_name: &str,
+        args: Vec<Value>,
+        named: HashMap<String, Value>,
+    ) -> ControlFlow<Value> {
+        let Some(function) = &self.function else {
+            return ControlFlow::Error(format!("Function.{method_name} sem função").into());
+        };
+        if !matches!(method_name, "call" | "apply") {
+            return ControlFlow::Error(
+                format!("Function.{method_name} não aceita argumentos nomeados").into(),
+            );
+        }
+        let this = args.first().cloned().unwrap_or(Value::Null);
+        let function = function.bind(this, vec![]);
+        let args = Self::forwarded_args(method_name, &args).and_then(|args| {
+            bind_named_args(&function.name, function.explicit_params(), args, named)
+        });
+        match args {
+            Ok(args) => ControlFlow::Return(function.call(args)),
+            Err(err) => ControlFlow::Error(err.into()),
+        }
+    }
+
+    fn methods_names(&self) -> Vec<String> {
+        Self::METHODS.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn get_args(&self) -> Vec<Value> {
+        vec![]
+    }
+
+    fn add_args(&mut self, _args: Vec<Value>) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn get_name(&self) -> String {
+        "Function".to_string()
+    }
+
+    fn is_static(&self) -> bool {
+        true
+    }
+}
@@ -29,7 +29,7 @@ impl NativeIoClass {
         match val {
             Value::String(s) => format!("\x1b[32m\"{}\"\x1b[0m", s), // verde com aspas
             Value::Number(n) => {
-                format!("\x1b[33m{}\x1b[0m", n.get_value())
+                format!("\x1b[33m{}\x1b[0m", n)
             } // amarelo
             Value::Bool(b) => format!("\x1b[36m{}\x1b[0m", b),       // ciano
             Value::Null => format!("\x1b[90mnull\x1b[0m"),           // cinza
@@ -59,6 +59,9 @@ impl NativeIoClass {
                     .join(", ");
                 format!("{{{}}}", props)
             }
+            Value::Instance(instance) if instance.borrow().class.variant_fields().is_some() => {
+                format!("\x1b[34m{}\x1b[0m", val.to_string())
+            } // variantes de enum como Color.Rgb(1, 2, 3)
             Value::Instance(instance) => {
                 let value_of_method = instance.borrow().get_value_of(); // Usa o valueOf se existir
 
@@ -1,5 +1,5 @@
 use crate::{
-    ast::ast::ControlFlow,
+    ast::ast::{ControlFlow, ErrorKind},
     environment::{native::native_callable::NativeCallable, Value},
 };
 
@@ -25,8 +25,10 @@ impl NativeCallable for NativeJsonClass {
         match method_name {
             "parse" => {
                 let json_string = args[0].to_string();
-                let json: serde_json::Value = serde_json::from_str(&json_string).unwrap();
-                ControlFlow::Return(Value::from(json))
+                match serde_json::from_str::<serde_json::Value>(&json_string) {
+                    Ok(json) => ControlFlow::Return(Value::from(json)),
+                    Err(err) => ControlFlow::Error(format!("Invalid JSON: {err}").into()),
+                }
             }
 
             "stringify" => {
@@ -57,6 +59,10 @@ impl NativeCallable for NativeJsonClass {
         "JSON".to_string()
     }
 
+    fn error_kind(&self) -> ErrorKind {
+        ErrorKind::SyntaxError
+    }
+
     fn is_static(&self) -> bool {
         true
     }
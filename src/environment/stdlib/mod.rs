@@ -38,4 +38,9 @@ macro_rules! declare_modules {
 }
 
 // Use:
-declare_modules!(fs, io, json, math, array, number);
+declare_modules!(fs, io, json, math, array, number, bigint, decimal, object, weakref, weakmap);
+
+// Métodos de valores de função; não é um módulo global
+pub mod function;
+// Combinadores de Option e Result, declarados como enums no prelúdio
+pub mod option;
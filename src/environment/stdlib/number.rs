@@ -1,25 +1,62 @@
 use std::{collections::HashMap, fmt::Display};
 
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{FromPrimitive, Pow, ToPrimitive, Zero};
+use rust_decimal::{Decimal, MathematicalOps};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    ast::ast::{ControlFlow, Expr, MethodDecl, Stmt},
+    ast::ast::{
+        BinaryOperator, ControlFlow, ErrorKind, Expr, MethodDecl, Signature, SourceLocation, Stmt,
+    },
     environment::{
         helpers::class::ClassGenerator, native::native_callable::NativeCallable, values::Value,
     },
-    impl_from_for_class, impl_logical_operations, impl_math_operations,
+    impl_from_for_class, impl_math_operations,
 };
 
 create_instance_fn!(NativeNumberClass);
+
+/// Resultado de uma operação aritmética; o erro carrega a categoria
+/// (`TypeError` para tipos incompatíveis, `RangeError` para divisão por zero etc.).
+pub type ArithmeticResult<T> = Result<T, (ErrorKind, String)>;
+
+fn range_error(message: String) -> (ErrorKind, String) {
+    (ErrorKind::RangeError, message)
+}
+
+/// Representação interna de um número. `Int` é promovido para `BigInt` em
+/// caso de overflow; `Decimal` mantém precisão exata para valores monetários.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum NumberValue {
+    Int(i64),
+    Float(f64),
+    BigInt(BigInt),
+    Decimal(Decimal),
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NativeNumberClass {
     pub args: Vec<Value>,
-    pub value: Option<f64>,
+    pub value: Option<NumberValue>,
     pub is_static: bool,
 }
 
 impl NativeNumberClass {
     pub fn new_with_value(value: f64) -> Self {
+        Self::new_with_number(NumberValue::Float(value))
+    }
+    pub fn new_with_int(value: i64) -> Self {
+        Self::new_with_number(NumberValue::Int(value))
+    }
+    pub fn new_with_bigint(value: BigInt) -> Self {
+        Self::new_with_number(NumberValue::BigInt(value))
+    }
+    pub fn new_with_decimal(value: Decimal) -> Self {
+        Self::new_with_number(NumberValue::Decimal(value))
+    }
+    pub fn new_with_number(value: NumberValue) -> Self {
         Self {
             args: vec![],
             value: Some(value),
@@ -37,15 +74,152 @@ impl NativeNumberClass {
         self.args = args;
     }
 
+    /// Converte texto para BigInt (aceita o sufixo `n` e separadores `_`).
+    pub fn parse_bigint(text: &str) -> Result<Self, String> {
+        let digits = text.trim().trim_end_matches('n').replace('_', "");
+        digits
+            .parse::<BigInt>()
+            .map(Self::new_with_bigint)
+            .map_err(|_| format!("Cannot convert '{text}' to bigint"))
+    }
+
+    /// Converte texto para Decimal (aceita o sufixo `m` e separadores `_`).
+    pub fn parse_decimal(text: &str) -> Result<Self, String> {
+        let digits = text.trim().trim_end_matches('m').replace('_', "");
+        Decimal::from_str_exact(&digits)
+            .or_else(|_| Decimal::from_scientific(&digits))
+            .map(Self::new_with_decimal)
+            .map_err(|_| format!("Cannot convert '{text}' to decimal"))
+    }
+
     pub fn get_value(&self) -> f64 {
+        match self.get_number() {
+            NumberValue::Int(i) => i as f64,
+            NumberValue::Float(f) => f,
+            NumberValue::BigInt(b) => b.to_f64().unwrap_or(f64::NAN),
+            NumberValue::Decimal(d) => d.to_f64().unwrap_or(f64::NAN),
+        }
+    }
+
+    pub fn get_number(&self) -> NumberValue {
         if self.is_static {
-            return self.args[0].clone().to_number();
+            return match self.args.first() {
+                Some(Value::Number(n)) => n.get_number(),
+                Some(other) => NumberValue::Float(other.to_number()),
+                None => NumberValue::Float(f64::NAN),
+            };
         }
         self.value.clone().unwrap()
     }
 
+    pub fn get_int(&self) -> Option<i64> {
+        match self.get_number() {
+            NumberValue::Int(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    /// Valor inteiro, truncando floats (usado em índices e conversões).
+    pub fn to_i64(&self) -> i64 {
+        match self.get_number() {
+            NumberValue::Int(i) => i,
+            NumberValue::BigInt(b) => b.to_i64().unwrap_or(i64::MAX),
+            NumberValue::Decimal(d) => d.trunc().to_i64().unwrap_or(i64::MAX),
+            NumberValue::Float(f) => f as i64,
+        }
+    }
+
+    /// Valor como BigInt; floats e decimais são truncados.
+    pub fn to_bigint(&self) -> Result<BigInt, String> {
+        match self.get_number() {
+            NumberValue::Int(i) => Ok(BigInt::from(i)),
+            NumberValue::BigInt(b) => Ok(b),
+            NumberValue::Decimal(d) => Ok(d.trunc().mantissa().into()),
+            NumberValue::Float(f) => {
+                BigInt::from_f64(f.trunc()).ok_or(format!("Cannot convert {f} to bigint"))
+            }
+        }
+    }
+
+    pub fn to_decimal(&self) -> Result<Decimal, String> {
+        match self.get_number() {
+            NumberValue::Int(i) => Ok(Decimal::from(i)),
+            NumberValue::Decimal(d) => Ok(d),
+            NumberValue::BigInt(b) => Decimal::from_str_exact(&b.to_string())
+                .map_err(|_| format!("Bigint {b} is too large for decimal")),
+            NumberValue::Float(f) => {
+                Decimal::from_f64(f).ok_or(format!("Cannot convert {f} to decimal"))
+            }
+        }
+    }
+
+    pub fn is_int(&self) -> bool {
+        matches!(self.get_number(), NumberValue::Int(_))
+    }
+
+    pub fn is_float(&self) -> bool {
+        matches!(self.get_number(), NumberValue::Float(_))
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self.get_number() {
+            NumberValue::Int(_) => "int",
+            NumberValue::Float(_) => "float",
+            NumberValue::BigInt(_) => "bigint",
+            NumberValue::Decimal(_) => "decimal",
+        }
+    }
+
     pub fn get_this(&self) -> Value {
-        Value::Number(self.get_value().into())
+        Value::Number(Self::new_with_number(self.get_number()))
+    }
+
+    /// Aplica um operador aritmético. Inteiros permanecem inteiros e são
+    /// promovidos para BigInt em caso de overflow; Decimal tem precedência sobre
+    /// inteiros; qualquer operando float promove o resultado para float.
+    pub fn checked_binary(
+        &self,
+        op: &BinaryOperator,
+        rhs: &NativeNumberClass,
+    ) -> ArithmeticResult<NativeNumberClass> {
+        use NumberValue::{Decimal as Dec, Float, Int};
+        match (self.get_number(), rhs.get_number()) {
+            (Int(a), Int(b)) => {
+                if let Some(result) = int_binary(a, b, op)? {
+                    return Ok(result);
+                }
+                bigint_binary(BigInt::from(a), BigInt::from(b), op)
+            }
+            (NumberValue::BigInt(_), Float(_)) | (Float(_), NumberValue::BigInt(_)) => Err((
+                ErrorKind::TypeError,
+                format!(
+                    "Cannot mix bigint and float in '{}', convert explicitly",
+                    op.symbol()
+                ),
+            )),
+            (Dec(_), _) | (_, Dec(_)) => decimal_binary(
+                self.to_decimal().map_err(range_error)?,
+                rhs.to_decimal().map_err(range_error)?,
+                op,
+            ),
+            (NumberValue::BigInt(_), _) | (_, NumberValue::BigInt(_)) => bigint_binary(
+                self.to_bigint().map_err(range_error)?,
+                rhs.to_bigint().map_err(range_error)?,
+                op,
+            ),
+            _ => {
+                let value = float_binary(self.get_value(), rhs.get_value(), op);
+                Ok(NativeNumberClass::new_with_value(value))
+            }
+        }
+    }
+
+    /// Versão infalível de `checked_binary`: em caso de erro o resultado
+    /// é calculado em ponto flutuante.
+    pub fn binary_or_float(&self, op: &BinaryOperator, rhs: &NativeNumberClass) -> Self {
+        self.checked_binary(op, rhs).unwrap_or_else(|_| {
+            NativeNumberClass::new_with_value(float_binary(self.get_value(), rhs.get_value(), op))
+        })
     }
 
     pub fn get_method_info(&self, method_name: &str) -> (String, usize) {
@@ -74,23 +248,33 @@ impl NativeNumberClass {
         let method_value_of = MethodDecl {
             name: "valueOf".to_string(),
             params: vec![],
-            body: vec![Stmt::Return(Some(crate::ast::ast::Expr::GetProperty {
-                object: Box::new(Expr::This),
-                property: Box::new(Expr::Identifier("value".to_string())),
-            }))],
+            body: vec![Stmt::Return(
+                Some(crate::ast::ast::Expr::GetProperty {
+                    object: Box::new(Expr::This),
+                    property: Box::new(Expr::Identifier("value".to_string())),
+                }),
+                SourceLocation::default(),
+            )],
             modifiers: vec![],
             vararg: None,
+            signature: Signature::default(),
+            decorators: vec![],
         };
 
         let constructor = MethodDecl {
             name: "constructor".to_string(),
             params: vec!["value".to_string()],
-            body: vec![Stmt::ExprStmt(ClassGenerator::set_prop_from_this(
-                "value".to_string(),
-                Expr::Identifier("value".to_string()),
-            ))],
+            body: vec![Stmt::ExprStmt(
+                ClassGenerator::set_prop_from_this(
+                    "value".to_string(),
+                    Expr::Identifier("value".to_string()),
+                ),
+                SourceLocation::default(),
+            )],
             modifiers: vec![],
             vararg: None,
+            signature: Signature::default(),
+            decorators: vec![],
         };
 
         let class_stmt = Stmt::ClassDecl {
@@ -99,25 +283,302 @@ impl NativeNumberClass {
             methods: vec![method_value_of, constructor],
             static_fields: static_fields,
             instance_fields: instance_fields,
+            field_types: HashMap::new(),
+            interfaces: vec![],
+            modifiers: vec![],
         };
 
         return class_stmt;
     }
 }
 
+fn float_binary(a: f64, b: f64, op: &BinaryOperator) -> f64 {
+    match op {
+        BinaryOperator::Add => a + b,
+        BinaryOperator::Subtract => a - b,
+        BinaryOperator::Multiply => a * b,
+        BinaryOperator::Divide => a / b,
+        BinaryOperator::FloorDivide => (a / b).floor(),
+        // Resto com o sinal do divisor, para que `(a ~/ b) * b + a % b == a`
+        BinaryOperator::Modulo => {
+            let rem = a % b;
+            if rem != 0.0 && (rem < 0.0) != (b < 0.0) {
+                rem + b
+            } else {
+                rem
+            }
+        }
+        BinaryOperator::Exponentiate => a.powf(b),
+    }
+}
+
+/// Aritmética de inteiros de 64 bits. Retorna `None` quando o resultado não
+/// cabe em i64 e precisa ser recalculado como BigInt.
+fn int_binary(a: i64, b: i64, op: &BinaryOperator) -> ArithmeticResult<Option<NativeNumberClass>> {
+    let result = match op {
+        BinaryOperator::Add => a.checked_add(b),
+        BinaryOperator::Subtract => a.checked_sub(b),
+        BinaryOperator::Multiply => a.checked_mul(b),
+        BinaryOperator::Divide => {
+            return Ok(Some(NativeNumberClass::new_with_value(a as f64 / b as f64)));
+        }
+        BinaryOperator::FloorDivide => {
+            if b == 0 {
+                return Err(range_error("Integer division by zero".to_string()));
+            }
+            a.checked_div(b).map(|quotient| {
+                if a % b != 0 && ((a < 0) != (b < 0)) {
+                    quotient - 1
+                } else {
+                    quotient
+                }
+            })
+        }
+        BinaryOperator::Modulo => {
+            if b == 0 {
+                return Err(range_error("Integer modulo by zero".to_string()));
+            }
+            a.checked_rem(b).map(|rem| {
+                if rem != 0 && ((rem < 0) != (b < 0)) {
+                    rem + b
+                } else {
+                    rem
+                }
+            })
+        }
+        BinaryOperator::Exponentiate => {
+            if b < 0 {
+                let value = (a as f64).powf(b as f64);
+                return Ok(Some(NativeNumberClass::new_with_value(value)));
+            }
+            u32::try_from(b).ok().and_then(|exp| a.checked_pow(exp))
+        }
+    };
+    Ok(result.map(NativeNumberClass::new_with_int))
+}
+
+/// Aritmética de inteiros de precisão arbitrária. `/` trunca como em `~/`
+/// com sinal, para que o resultado continue exato.
+fn bigint_binary(a: BigInt, b: BigInt, op: &BinaryOperator) -> ArithmeticResult<NativeNumberClass> {
+    let result = match op {
+        BinaryOperator::Add => a + b,
+        BinaryOperator::Subtract => a - b,
+        BinaryOperator::Multiply => a * b,
+        BinaryOperator::Divide | BinaryOperator::FloorDivide | BinaryOperator::Modulo
+            if b.is_zero() =>
+        {
+            return Err(range_error("BigInt division by zero".to_string()));
+        }
+        BinaryOperator::Divide => a / b,
+        BinaryOperator::FloorDivide => Integer::div_floor(&a, &b),
+        BinaryOperator::Modulo => Integer::mod_floor(&a, &b),
+        BinaryOperator::Exponentiate => {
+            let exp = b
+                .to_u32()
+                .ok_or_else(|| range_error(format!("Invalid bigint exponent: {b}")))?;
+            a.pow(exp)
+        }
+    };
+    Ok(NativeNumberClass::new_with_bigint(result))
+}
+
+fn decimal_binary(
+    a: Decimal,
+    b: Decimal,
+    op: &BinaryOperator,
+) -> ArithmeticResult<NativeNumberClass> {
+    let overflow = || range_error(format!("Decimal overflow: {a} {} {b}", op.symbol()));
+    if b.is_zero()
+        && matches!(
+            op,
+            BinaryOperator::Divide | BinaryOperator::FloorDivide | BinaryOperator::Modulo
+        )
+    {
+        return Err(range_error("Decimal division by zero".to_string()));
+    }
+    let result = match op {
+        BinaryOperator::Add => a.checked_add(b),
+        BinaryOperator::Subtract => a.checked_sub(b),
+        BinaryOperator::Multiply => a.checked_mul(b),
+        BinaryOperator::Divide => a.checked_div(b),
+        BinaryOperator::FloorDivide => a.checked_div(b).map(|d| d.floor()),
+        BinaryOperator::Modulo => a.checked_rem(b).map(|rem| {
+            if !rem.is_zero() && rem.is_sign_negative() != b.is_sign_negative() {
+                rem + b
+            } else {
+                rem
+            }
+        }),
+        BinaryOperator::Exponentiate => {
+            if !b.fract().is_zero() {
+                return Err(range_error(format!(
+                    "Decimal exponent must be an integer: {b}"
+                )));
+            }
+            b.to_i64().and_then(|exp| a.checked_powi(exp))
+        }
+    };
+    result
+        .map(NativeNumberClass::new_with_decimal)
+        .ok_or_else(overflow)
+}
+
+/// Semelhante ao `parseInt` do JavaScript: ignora espaços iniciais, aceita sinal
+/// e o prefixo `0x` (quando a base é 16 ou omitida) e lê o maior prefixo válido.
+/// Retorna NaN quando nenhum dígito é reconhecido.
+fn parse_int(text: &str, radix: Option<i64>) -> NativeNumberClass {
+    let nan = NativeNumberClass::new_with_value(f64::NAN);
+    let mut text = text.trim_start();
+    let negative = text.starts_with('-');
+    text = text.strip_prefix(['-', '+']).unwrap_or(text);
+
+    let radix = match radix {
+        None | Some(0) => {
+            if let Some(rest) = text.strip_prefix("0x").or(text.strip_prefix("0X")) {
+                text = rest;
+                16
+            } else {
+                10
+            }
+        }
+        Some(16) => {
+            text = text
+                .strip_prefix("0x")
+                .or(text.strip_prefix("0X"))
+                .unwrap_or(text);
+            16
+        }
+        Some(radix @ 2..=36) => radix as u32,
+        Some(_) => return nan,
+    };
+
+    let end = text
+        .find(|c: char| !c.is_digit(radix))
+        .unwrap_or(text.len());
+    let digits = &text[..end];
+    if digits.is_empty() {
+        return nan;
+    }
+    let value = match BigInt::parse_bytes(digits.as_bytes(), radix) {
+        Some(value) => value,
+        None => return nan,
+    };
+    let value = if negative { -value } else { value };
+    match value.to_i64() {
+        Some(value) => NativeNumberClass::new_with_int(value),
+        None => NativeNumberClass::new_with_bigint(value),
+    }
+}
+
+/// Semelhante ao `parseFloat` do JavaScript: lê o maior prefixo que forma um
+/// número decimal (incluindo `Infinity`) e retorna NaN quando não há nenhum.
+fn parse_float(text: &str) -> f64 {
+    let text = text.trim_start();
+    let unsigned = text.strip_prefix(['-', '+']).unwrap_or(text);
+    if unsigned.starts_with("Infinity") {
+        return if text.starts_with('-') {
+            f64::NEG_INFINITY
+        } else {
+            f64::INFINITY
+        };
+    }
+    (1..=text.len())
+        .rev()
+        .filter(|end| text.is_char_boundary(*end))
+        .find_map(|end| {
+            let candidate = &text[..end];
+            let last = candidate.chars().last()?;
+            // Rust aceita "inf"/"nan"; aqui somente dígitos e pontuação numérica
+            if last.is_ascii_digit() || last == '.' {
+                candidate.parse::<f64>().ok()
+            } else {
+                None
+            }
+        })
+        .unwrap_or(f64::NAN)
+}
+
+/// Representa o número na base informada (2 a 36), como `toString(radix)`.
+fn to_string_radix(number: &NumberValue, radix: i64) -> Result<String, String> {
+    if !(2..=36).contains(&radix) {
+        return Err(format!(
+            "toString() radix must be between 2 and 36, received {radix}"
+        ));
+    }
+    let radix = radix as u32;
+    if radix == 10 {
+        return Ok(NativeNumberClass::new_with_number(number.clone()).to_string());
+    }
+    match number {
+        NumberValue::Int(i) => Ok(BigInt::from(*i).to_str_radix(radix)),
+        NumberValue::BigInt(b) => Ok(b.to_str_radix(radix)),
+        NumberValue::Decimal(_) => Err("toString(radix) is not supported for decimal".to_string()),
+        NumberValue::Float(f) => Ok(float_to_radix(*f, radix)),
+    }
+}
+
+fn float_to_radix(value: f64, radix: u32) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let sign = if value < 0.0 { "-" } else { "" };
+    let value = value.abs();
+    let integer = BigInt::from_f64(value.trunc()).unwrap_or_default();
+    let mut fraction = value.fract();
+    let mut text = format!("{sign}{}", integer.to_str_radix(radix));
+    if fraction > 0.0 {
+        text.push('.');
+        // 52 dígitos bastam para esgotar a mantissa de um f64 na base 2
+        for _ in 0..52 {
+            fraction *= radix as f64;
+            let digit = fraction.trunc() as u32;
+            text.push(std::char::from_digit(digit, radix).unwrap());
+            fraction = fraction.fract();
+            if fraction == 0.0 {
+                break;
+            }
+        }
+    }
+    text
+}
+
 impl_math_operations!(NativeNumberClass);
 
-impl_from_for_class!(
-    [f64, i32, i64, u32, u64, isize, usize],
-    f64,
-    NativeNumberClass
-);
+impl_from_for_class!([f64], f64, NativeNumberClass);
+impl_from_for_class!([i32, i64, u32, u64, isize, usize], i64, NativeNumberClass);
 
-impl_logical_operations!(NativeNumberClass, NativeNumberClass);
+impl PartialEq for NativeNumberClass {
+    fn eq(&self, rhs: &NativeNumberClass) -> bool {
+        self.partial_cmp(rhs) == Some(std::cmp::Ordering::Equal)
+    }
+}
+
+impl PartialOrd for NativeNumberClass {
+    fn partial_cmp(&self, rhs: &NativeNumberClass) -> Option<std::cmp::Ordering> {
+        use NumberValue::{Decimal as Dec, Float, Int};
+        match (self.get_number(), rhs.get_number()) {
+            (Int(a), Int(b)) => a.partial_cmp(&b),
+            (Float(_), _) | (_, Float(_)) => self.get_value().partial_cmp(&rhs.get_value()),
+            (Dec(_), _) | (_, Dec(_)) => match (self.to_decimal(), rhs.to_decimal()) {
+                (Ok(a), Ok(b)) => a.partial_cmp(&b),
+                _ => self.get_value().partial_cmp(&rhs.get_value()),
+            },
+            _ => self.to_bigint().ok()?.partial_cmp(&rhs.to_bigint().ok()?),
+        }
+    }
+}
 
 impl Display for NativeNumberClass {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.get_value())
+        match self.get_number() {
+            NumberValue::Int(i) => write!(f, "{}", i),
+            NumberValue::Float(n) if n.is_infinite() => {
+                write!(f, "{}Infinity", if n < 0.0 { "-" } else { "" })
+            }
+            NumberValue::Float(n) => write!(f, "{}", n),
+            NumberValue::BigInt(b) => write!(f, "{}", b),
+            NumberValue::Decimal(d) => write!(f, "{}", d),
+        }
     }
 }
 
@@ -135,6 +596,12 @@ impl NativeCallable for NativeNumberClass {
         if args.len() < 1 && self.args.len() > 0 {
             args = self.args.clone();
         }
+        // Na forma estática (Number.toString(x, 16)) o primeiro argumento é o próprio número
+        let extra_args = if self.is_static && !args.is_empty() {
+            &args[1..]
+        } else {
+            &args[..]
+        };
         match method_name {
             "valueOf" => {
                 let arg = self.get_this();
@@ -142,21 +609,51 @@ impl NativeCallable for NativeNumberClass {
                 ControlFlow::Return(Value::Number(arg.to_number().into()))
             }
             "toString" => {
-                let arg = self.get_this();
-
-                ControlFlow::Return(Value::String(arg.to_string().into()))
+                let radix = match extra_args.first() {
+                    None => 10,
+                    Some(Value::Number(radix)) => radix.to_i64(),
+                    Some(other) => {
+                        return ControlFlow::Error(
+                            format!(
+                                "toString() radix must be a number, received {}",
+                                other.type_of()
+                            )
+                            .into(),
+                        )
+                    }
+                };
+                match to_string_radix(&self.get_number(), radix) {
+                    Ok(text) => ControlFlow::Return(Value::String(text)),
+                    Err(err) => ControlFlow::Error(err.into()),
+                }
             }
             "isNaN" => {
                 let arg = self.get_this();
 
                 ControlFlow::Return(Value::Bool(arg.to_number().is_nan()))
             }
+            "parseInt" => {
+                let text = args.first().map(|v| v.to_string()).unwrap_or_default();
+                let radix = match args.get(1) {
+                    None | Some(Value::Null) | Some(Value::Void) => None,
+                    Some(radix) => Some(radix.to_number() as i64),
+                };
+                ControlFlow::Return(Value::Number(parse_int(&text, radix)))
+            }
+            "parseFloat" => {
+                let text = args.first().map(|v| v.to_string()).unwrap_or_default();
+                ControlFlow::Return(Value::Number(parse_float(&text).into()))
+            }
             _ => ControlFlow::Error(format!("Método nativo desconhecido: {}", method_name).into()),
         }
     }
 
     fn methods_names(&self) -> Vec<String> {
-        let methods = vec!["valueOf", "toString"];
+        let methods = if self.is_static {
+            vec!["valueOf", "toString", "isNaN", "parseInt", "parseFloat"]
+        } else {
+            vec!["valueOf", "toString", "isNaN"]
+        };
 
         methods.iter().map(|s| s.to_string()).collect()
     }
@@ -185,13 +682,24 @@ impl NativeCallable for NativeNumberClass {
                 args.len()
             ));
         }
-        let arg = args[0].clone();
-        Ok(Value::Number(arg.to_number().into()))
+        match &args[0] {
+            Value::Number(n) => Ok(n.get_this()),
+            arg => Ok(Value::Number(arg.to_number().into())),
+        }
     }
 
     fn get_name(&self) -> String {
         "Number".to_string()
     }
+
+    fn is_static(&self) -> bool {
+        self.is_static
+    }
+
+    fn call_with_args(&self, method_name: &str, args: Vec<Value>) -> ControlFlow<Value> {
+        // O módulo é compartilhado: os argumentos valem apenas para esta chamada
+        NativeNumberClass::new_with_args(args).call(method_name)
+    }
 }
 
 pub struct MethodInfo {
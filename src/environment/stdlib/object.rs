@@ -0,0 +1,137 @@
+use crate::{
+    ast::ast::ControlFlow,
+    environment::{
+        native::native_callable::NativeCallable,
+        values::{NativeObjectTrait, Value},
+    },
+};
+
+create_instance_fn!(NativeObjectClass);
+
+/// Funções estáticas de `Object`. Funcionam da mesma forma para objetos
+/// literais e instâncias de classes; métodos das instâncias são ignorados.
+#[derive(Debug, Clone)]
+pub struct NativeObjectClass {
+    args: Vec<Value>,
+}
+
+impl NativeObjectClass {
+    fn entries_of(method_name: &str, value: &Value) -> Result<Vec<(String, Value)>, String> {
+        value.own_entries().ok_or(format!(
+            "Object.{method_name} espera um objeto, recebeu {}",
+            value.type_of()
+        ))
+    }
+
+    fn assign(target: &Value, sources: &[Value]) -> Result<Value, String> {
+        for source in sources {
+            if source.is_null() || source.is_void() {
+                continue;
+            }
+            for (key, value) in Self::entries_of("assign", source)? {
+                match target {
+                    Value::Object(map) => map.borrow_mut().set_prop(&key, value)?,
+                    Value::Instance(instance) => instance.borrow_mut().set(&key, value)?,
+                    other => {
+                        return Err(format!(
+                            "Object.assign espera um objeto como destino, recebeu {}",
+                            other.type_of()
+                        ))
+                    }
+                }
+            }
+        }
+        Ok(target.clone())
+    }
+
+    fn from_entries(entries: &Value) -> Result<Value, String> {
+        let Value::Array(entries) = entries else {
+            return Err("Object.fromEntries espera um array de pares [chave, valor]".to_string());
+        };
+        let mut map: Vec<(String, Value)> = Vec::new();
+        for entry in entries.get_value().borrow().iter() {
+            let pair = match entry {
+                Value::Array(pair) => pair.get_value().borrow().clone(),
+                other => {
+                    return Err(format!(
+                        "Object.fromEntries espera pares [chave, valor], recebeu {}",
+                        other.type_of()
+                    ))
+                }
+            };
+            let key = pair.first().cloned().unwrap_or(Value::Void).to_string();
+            let value = pair.get(1).cloned().unwrap_or(Value::Void);
+            map.set_prop(&key, value)?;
+        }
+        Ok(Value::object(map))
+    }
+}
+
+impl NativeCallable for NativeObjectClass {
+    fn new() -> Self {
+        Self { args: vec![] }
+    }
+
+    fn call_with_args(&self, method_name: &str, args: Vec<Value>) -> ControlFlow<Value> {
+        let result = match (method_name, &args[..]) {
+            ("keys", [value]) => Self::entries_of(method_name, value).map(|entries| {
+                Value::array(
+                    entries
+                        .into_iter()
+                        .map(|(key, _)| Value::String(key))
+                        .collect(),
+                )
+            }),
+            ("values", [value]) => Self::entries_of(method_name, value).map(|entries| {
+                Value::array(entries.into_iter().map(|(_, value)| value).collect())
+            }),
+            ("entries", [value]) => Self::entries_of(method_name, value).map(|entries| {
+                Value::array(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| Value::array(vec![Value::String(key), value]))
+                        .collect(),
+                )
+            }),
+            ("hasOwn", [value, key]) => Self::entries_of(method_name, value)
+                .map(|entries| Value::Bool(entries.contains_key(&key.to_string()))),
+            ("assign", [target, sources @ ..]) => Self::assign(target, sources),
+            ("fromEntries", [entries]) => Self::from_entries(entries),
+            ("keys" | "values" | "entries" | "fromEntries", _) => Err(format!(
+                "Object.{method_name} espera um único argumento"
+            )),
+            ("hasOwn", _) => Err("Object.hasOwn espera um objeto e uma chave".to_string()),
+            ("assign", _) => Err("Object.assign espera ao menos um objeto".to_string()),
+            _ => Err(format!("Método nativo desconhecido: Object.{}", method_name)),
+        };
+
+        match result {
+            Ok(value) => ControlFlow::Return(value),
+            Err(err) => ControlFlow::Error(err.into()),
+        }
+    }
+
+    fn methods_names(&self) -> Vec<String> {
+        vec!["keys", "values", "entries", "assign", "fromEntries", "hasOwn"]
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    fn get_args(&self) -> Vec<Value> {
+        self.args.clone()
+    }
+
+    fn add_args(&mut self, args: Vec<Value>) -> Result<(), String> {
+        self.args = args;
+        Ok(())
+    }
+
+    fn get_name(&self) -> String {
+        "Object".to_string()
+    }
+
+    fn is_static(&self) -> bool {
+        true
+    }
+}
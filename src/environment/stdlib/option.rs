@@ -0,0 +1,169 @@
+use std::cell::Cell;
+
+use crate::{
+    ast::ast::ControlFlow,
+    environment::{
+        native::native_callable::NativeCallable,
+        values::{Class, ClassKind, Value},
+    },
+};
+
+thread_local! {
+    static IO_RESULTS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Modo em que os métodos de I/O (`Fs`) retornam `Ok(valor)`/`Err(mensagem)` em vez
+/// de lançar `IoError`.
+pub fn set_io_results(enabled: bool) {
+    IO_RESULTS.with(|flag| flag.set(enabled));
+}
+
+pub fn io_results() -> bool {
+    IO_RESULTS.with(|flag| flag.get())
+}
+
+/// Variante de `Option` ou `Result` (enums do prelúdio) e o seu campo:
+/// `Some(1)` -> `("Some", 1)`, `None` -> `("None", null)`.
+pub fn unpack(value: &Value) -> Option<(String, Value)> {
+    let Value::Instance(instance) = value else {
+        return None;
+    };
+    let instance = instance.borrow();
+    let ClassKind::Variant { enum_name, fields } = &instance.class.kind else {
+        return None;
+    };
+    if enum_name != "Option" && enum_name != "Result" {
+        return None;
+    }
+    let field = fields
+        .first()
+        .and_then(|field| instance.this.borrow().get(field))
+        .unwrap_or(Value::Null);
+    Some((instance.class.name.clone(), field))
+}
+
+/// `Some` e `Ok` carregam um valor; `None` e `Err` interrompem `map`, `andThen` e `?`.
+pub fn has_value(variant: &str) -> bool {
+    matches!(variant, "Some" | "Ok")
+}
+
+/// Combinadores de `Option` e `Result`: `opt.map(f)`, `res.mapErr(f)`, `opt.andThen(f)`,
+/// `opt.unwrap()`, `opt.unwrapOr(padrao)` e os testes `isSome`, `isNone`, `isOk`, `isErr`.
+#[derive(Debug, Clone)]
+pub struct NativeOptionClass {
+    value: Option<Value>,
+}
+
+impl NativeOptionClass {
+    pub const METHODS: [&'static str; 9] = [
+        "map", "mapErr", "andThen", "unwrap", "unwrapOr", "isSome", "isNone", "isOk", "isErr",
+    ];
+
+    pub fn new_with_value(value: Value) -> Self {
+        Self { value: Some(value) }
+    }
+
+    /// Aplica `function` ao campo; erros lançados voltam como `Value::Error`.
+    fn apply(method_name: &str, function: Option<&Value>, arg: Value) -> Result<Value, String> {
+        match function {
+            Some(Value::Function(function)) => Ok(function.call(vec![arg])),
+            Some(Value::Builtin(function)) => Ok(function(vec![arg])),
+            Some(Value::InternalFunction((name, native))) if native.borrow().is_static() => {
+                match native.borrow().call_with_args(name, vec![arg]) {
+                    ControlFlow::Return(value) => Ok(value),
+                    ControlFlow::Error(err) => Err(err.to_string()),
+                    _ => Ok(Value::Void),
+                }
+            }
+            other => Err(format!(
+                "{method_name} espera uma função, recebeu {}",
+                other
+                    .map(|value| value.type_of())
+                    .unwrap_or("nada".to_string())
+            )),
+        }
+    }
+
+    /// Mesma variante do receptor com outro campo: `Some(1).map(f)` -> `Some(f(1))`.
+    fn rewrap(receiver: &Value, field: Value) -> Result<Value, String> {
+        let Value::Instance(instance) = receiver else {
+            return Err(format!("{} não é Option nem Result", receiver.type_of()));
+        };
+        let class = instance.borrow().class.clone();
+        Class::instantiate_variant(&class, vec![field])
+    }
+}
+
+impl NativeCallable for NativeOptionClass {
+    fn new() -> Self {
+        Self { value: None }
+    }
+
+    fn call_with_args(&self, method_name: &str, args: Vec<Value>) -> ControlFlow<Value> {
+        let Some((variant, field)) = self.value.as_ref().and_then(unpack) else {
+            return ControlFlow::Error(format!("{method_name} espera um Option ou Result").into());
+        };
+        let receiver = self.value.clone().unwrap();
+
+        let mapped = match method_name {
+            "isSome" | "isNone" | "isOk" | "isErr" => {
+                return ControlFlow::Return(Value::Bool(method_name[2..] == variant))
+            }
+            "unwrap" if has_value(&variant) => return ControlFlow::Return(field),
+            "unwrap" => {
+                return ControlFlow::Error(
+                    format!("unwrap chamado em {}", receiver.to_string()).into(),
+                )
+            }
+            "unwrapOr" if has_value(&variant) => return ControlFlow::Return(field),
+            "unwrapOr" => return ControlFlow::Return(args.first().cloned().unwrap_or(Value::Null)),
+            "map" | "andThen" if has_value(&variant) => {
+                Self::apply(method_name, args.first(), field)
+            }
+            "mapErr" if variant == "Err" => Self::apply(method_name, args.first(), field),
+            // `None` e `Err` passam adiante sem chamar a função (e `mapErr` ignora `Ok`)
+            "map" | "andThen" | "mapErr" => return ControlFlow::Return(receiver),
+            _ => {
+                return ControlFlow::Error(
+                    format!("Método nativo desconhecido: {}", method_name).into(),
+                )
+            }
+        };
+
+        let mapped = match mapped {
+            Ok(Value::Error(error)) => return ControlFlow::Return(Value::Error(error)),
+            Ok(mapped) => mapped,
+            Err(err) => return ControlFlow::Error(err.into()),
+        };
+        if method_name == "andThen" {
+            return ControlFlow::Return(mapped);
+        }
+        match Self::rewrap(&receiver, mapped) {
+            Ok(value) => ControlFlow::Return(value),
+            Err(err) => ControlFlow::Error(err.into()),
+        }
+    }
+
+    fn methods_names(&self) -> Vec<String> {
+        Self::METHODS.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn get_args(&self) -> Vec<Value> {
+        vec![]
+    }
+
+    fn add_args(&mut self, _args: Vec<Value>) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn get_name(&self) -> String {
+        match self.value.as_ref().and_then(unpack) {
+            Some((variant, _)) if matches!(variant.as_str(), "Ok" | "Err") => "Result".to_string(),
+            _ => "Option".to_string(),
+        }
+    }
+
+    fn is_static(&self) -> bool {
+        true
+    }
+}
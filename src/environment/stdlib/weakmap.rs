@@ -0,0 +1,120 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    ast::ast::ControlFlow,
+    environment::{
+        native::native_callable::NativeCallable, stdlib::weakref::WeakTarget, values::Value,
+    },
+    interpreter::gc,
+};
+
+pub type WeakEntries = Vec<(WeakTarget, Value)>;
+
+create_instance_fn!(NativeWeakMapClass);
+
+/// `WeakMap()`: mapa indexado pela identidade de instâncias, objetos e arrays, sem
+/// mantê-los vivos. Entradas de chaves liberadas somem. Os valores são fortes, mas o
+/// coletor os trata como efêmeros: um valor só mantém algo vivo enquanto a chave vive.
+#[derive(Debug, Clone)]
+pub struct NativeWeakMapClass {
+    entries: Option<Rc<RefCell<WeakEntries>>>,
+}
+
+impl NativeWeakMapClass {
+    /// Posição da chave no mapa, descartando antes as entradas de chaves mortas.
+    fn position(entries: &mut WeakEntries, key: &WeakTarget) -> Option<usize> {
+        entries.retain(|(target, _)| target.is_alive());
+        entries
+            .iter()
+            .position(|(target, _)| target.address() == key.address())
+    }
+}
+
+impl NativeCallable for NativeWeakMapClass {
+    fn new() -> Self {
+        Self { entries: None }
+    }
+
+    fn call_with_args(&self, method_name: &str, args: Vec<Value>) -> ControlFlow<Value> {
+        let Some(entries) = &self.entries else {
+            return ControlFlow::Error(
+                format!("WeakMap.{method_name} espera uma instância de WeakMap").into(),
+            );
+        };
+        let arity = if method_name == "set" { 2 } else { 1 };
+        if !matches!(method_name, "get" | "set" | "has" | "delete") {
+            return ControlFlow::Error(
+                format!("Método nativo desconhecido: WeakMap.{}", method_name).into(),
+            );
+        }
+        if args.len() != arity {
+            return ControlFlow::Error(
+                format!("WeakMap.{method_name} espera {arity} argumento(s)").into(),
+            );
+        }
+        let key = match WeakTarget::new(&args[0]) {
+            Ok(key) => key,
+            Err(err) => return ControlFlow::Error(err.into()),
+        };
+
+        let mut entries = entries.borrow_mut();
+        let position = Self::position(&mut entries, &key);
+        let result = match (method_name, position) {
+            ("get", Some(i)) => entries[i].1.clone(),
+            ("get", None) => Value::Null,
+            ("has", position) => Value::Bool(position.is_some()),
+            ("delete", Some(i)) => {
+                entries.remove(i);
+                Value::Bool(true)
+            }
+            ("delete", None) => Value::Bool(false),
+            (_, Some(i)) => {
+                entries[i].1 = args[1].clone();
+                Value::Void
+            }
+            (_, None) => {
+                entries.push((key, args[1].clone()));
+                Value::Void
+            }
+        };
+        ControlFlow::Return(result)
+    }
+
+    fn methods_names(&self) -> Vec<String> {
+        ["get", "set", "has", "delete"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    fn get_args(&self) -> Vec<Value> {
+        vec![]
+    }
+
+    fn add_args(&mut self, _args: Vec<Value>) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn instantiate(&self, args: Vec<Value>) -> Result<Value, String> {
+        if !args.is_empty() {
+            return Err(format!(
+                "Class 'WeakMap' expected 0 arguments but received {}",
+                args.len()
+            ));
+        }
+        let entries = Rc::new(RefCell::new(vec![]));
+        gc::track_weak_map(&entries);
+        let map = Self {
+            entries: Some(entries),
+        };
+        Ok(Value::InternalClass(Rc::new(RefCell::new(map))))
+    }
+
+    fn get_name(&self) -> String {
+        "WeakMap".to_string()
+    }
+
+    fn is_static(&self) -> bool {
+        true
+    }
+}
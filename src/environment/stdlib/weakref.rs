@@ -0,0 +1,124 @@
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+use crate::{
+    ast::ast::ControlFlow,
+    environment::{
+        native::native_callable::NativeCallable,
+        values::{Instance, Value},
+    },
+};
+
+create_instance_fn!(NativeWeakRefClass);
+
+/// Referência fraca a um valor com identidade: instâncias, objetos e arrays.
+#[derive(Debug, Clone)]
+pub enum WeakTarget {
+    Instance(Weak<RefCell<Instance>>),
+    Object(Weak<RefCell<Vec<(String, Value)>>>),
+    Array(Weak<RefCell<Vec<Value>>>),
+}
+
+impl WeakTarget {
+    pub fn new(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::Instance(instance) => Ok(Self::Instance(Rc::downgrade(instance))),
+            Value::Object(object) => Ok(Self::Object(Rc::downgrade(object))),
+            Value::Array(array) => Ok(Self::Array(Rc::downgrade(&array.get_value()))),
+            other => Err(format!(
+                "Referências fracas exigem instância, objeto ou array, recebeu {}",
+                other.type_of()
+            )),
+        }
+    }
+
+    /// O valor, se ainda houver alguma referência forte a ele.
+    pub fn upgrade(&self) -> Option<Value> {
+        match self {
+            Self::Instance(weak) => weak.upgrade().map(Value::Instance),
+            Self::Object(weak) => weak.upgrade().map(Value::Object),
+            Self::Array(weak) => weak
+                .upgrade()
+                .map(|items| Value::Array(items.into())),
+        }
+    }
+
+    pub fn is_alive(&self) -> bool {
+        match self {
+            Self::Instance(weak) => weak.strong_count() > 0,
+            Self::Object(weak) => weak.strong_count() > 0,
+            Self::Array(weak) => weak.strong_count() > 0,
+        }
+    }
+
+    /// Endereço da alocação. Enquanto o `Weak` existir ele não é reaproveitado, então
+    /// serve de identidade mesmo depois que o valor morre.
+    pub fn address(&self) -> *const () {
+        match self {
+            Self::Instance(weak) => weak.as_ptr() as *const (),
+            Self::Object(weak) => weak.as_ptr() as *const (),
+            Self::Array(weak) => weak.as_ptr() as *const (),
+        }
+    }
+}
+
+/// `WeakRef(obj)`: `ref.deref()` retorna o objeto ou `null` depois que ele foi liberado.
+#[derive(Debug, Clone)]
+pub struct NativeWeakRefClass {
+    target: Option<WeakTarget>,
+}
+
+impl NativeCallable for NativeWeakRefClass {
+    fn new() -> Self {
+        Self { target: None }
+    }
+
+    fn call_with_args(&self, method_name: &str, _args: Vec<Value>) -> ControlFlow<Value> {
+        match (method_name, &self.target) {
+            ("deref", Some(target)) => {
+                ControlFlow::Return(target.upgrade().unwrap_or(Value::Null))
+            }
+            ("deref", None) => {
+                ControlFlow::Error("WeakRef.deref espera uma instância de WeakRef".to_string().into())
+            }
+            _ => ControlFlow::Error(
+                format!("Método nativo desconhecido: WeakRef.{}", method_name).into(),
+            ),
+        }
+    }
+
+    fn methods_names(&self) -> Vec<String> {
+        vec!["deref".to_string()]
+    }
+
+    fn get_args(&self) -> Vec<Value> {
+        vec![]
+    }
+
+    fn add_args(&mut self, _args: Vec<Value>) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn instantiate(&self, args: Vec<Value>) -> Result<Value, String> {
+        let [value] = &args[..] else {
+            return Err(format!(
+                "Class 'WeakRef' expected 1 argument but received {}",
+                args.len()
+            ));
+        };
+        let weak_ref = Self {
+            target: Some(WeakTarget::new(value)?),
+        };
+        Ok(Value::InternalClass(Rc::new(RefCell::new(weak_ref))))
+    }
+
+    fn get_name(&self) -> String {
+        "WeakRef".to_string()
+    }
+
+    fn is_static(&self) -> bool {
+        true
+    }
+}
@@ -4,9 +4,13 @@ use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc, vec};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    ast::ast::{BinaryOperator, ControlFlow, Expr, MethodModifiersOperations, Modifiers, Stmt},
-    environment::stdlib::number::NativeNumberClass,
-    interpreter::Interpreter,
+    ast::ast::{
+        BinaryOperator, ControlFlow, ErrorKind, Expr, MethodModifiersOperations, Modifiers,
+        Signature, SourceLocation, Stmt, TypeAnnotation,
+    },
+    environment::stdlib::number::{ArithmeticResult, NativeNumberClass},
+    interpreter::{call_stack, finalizer, gc, Interpreter},
+    typecheck,
 };
 
 use super::{
@@ -89,6 +93,7 @@ pub trait NativeObjectTrait {
     fn contains_key(&self, key: &str) -> bool;
     fn get_prop(&self, key: &str) -> Option<Value>;
     fn set_prop(&mut self, key: &str, value: Value) -> Result<(), String>;
+    fn remove_prop(&mut self, key: &str) -> bool;
 }
 impl NativeObjectTrait for Vec<(String, Value)> {
     fn contains_key(&self, key: &str) -> bool {
@@ -126,6 +131,11 @@ impl NativeObjectTrait for Vec<(String, Value)> {
         }
         Ok(())
     }
+    fn remove_prop(&mut self, key: &str) -> bool {
+        let len = self.len();
+        self.retain(|item| item.0 != key);
+        self.len() != len
+    }
 }
 // #[derive(Clone, Debug)]
 // pub enum Primitive {
@@ -176,7 +186,17 @@ impl From<serde_json::Value> for Value {
         match value {
             serde_json::Value::Null => Value::Null,
             serde_json::Value::Bool(b) => Value::Bool(b),
-            serde_json::Value::Number(n) => Value::Number(n.as_f64().unwrap().into()),
+            serde_json::Value::Number(n) => match n.as_i64() {
+                Some(i) => Value::Number(i.into()),
+                // Inteiros fora do intervalo de i64 viram BigInt e os demais números
+                // viram Decimal, para não perder precisão; Float só se nenhum servir
+                None => match NativeNumberClass::parse_bigint(&n.to_string())
+                    .or_else(|_| NativeNumberClass::parse_decimal(&n.to_string()))
+                {
+                    Ok(number) => Value::Number(number),
+                    Err(_) => Value::Number(n.as_f64().unwrap().into()),
+                },
+            },
             serde_json::Value::String(s) => Value::String(s.into()),
             serde_json::Value::Array(a) => Value::Array(
                 Rc::new(RefCell::new(a.into_iter().map(|v| v.into()).collect())).into(),
@@ -193,6 +213,16 @@ impl From<serde_json::Value> for Value {
 pub struct Instance {
     pub this: Rc<RefCell<Environment>>,
     pub class: Rc<Class>,
+    /// `drop(self)` já foi chamado (ou está agendado) para este objeto.
+    #[serde(skip)]
+    pub finalized: bool,
+}
+
+/// A última referência sumiu: agenda o `drop(self)` da classe, se houver.
+impl Drop for Instance {
+    fn drop(&mut self) {
+        finalizer::schedule(self);
+    }
 }
 
 impl Instance {
@@ -205,6 +235,17 @@ impl Instance {
         }
     }
 
+    /// Campos declarados na classe com seus valores atuais (sem métodos).
+    pub fn fields(&self) -> Vec<(String, Value)> {
+        let declared = self.class.instance_variables.borrow();
+        self.this
+            .borrow()
+            .get_vars()
+            .into_iter()
+            .filter(|(name, value)| declared.contains_key(name) && !value.is_function())
+            .collect()
+    }
+
     pub fn find_operation(&self, operator: &str) -> Option<Rc<Function>> {
         let class = self.class.clone();
 
@@ -229,11 +270,25 @@ impl Instance {
         }
     }
 
-    pub fn set(&mut self, name: &str, value: Value) -> Result<(), String> {
-        let class_this = self.class.this.borrow_mut().assign(&name, value.clone());
+    /// Remove um campo declarado da instância (métodos não podem ser removidos).
+    pub fn remove_field(&mut self, name: &str) -> bool {
+        if !self.class.instance_variables.borrow().contains_key(name) {
+            return false;
+        }
+        self.this.borrow_mut().variables.remove_prop(name)
+    }
 
-        if class_this.is_err() {
-            return class_this;
+    pub fn set(&mut self, name: &str, value: Value) -> Result<(), String> {
+        // Campo removido com `delete` volta a existir ao ser atribuído
+        if !self.this.borrow().exist(name)
+            && self.class.instance_variables.borrow().contains_key(name)
+        {
+            self.this.borrow_mut().define(name.to_string(), Value::Null);
+        }
+        // Só confere se o campo foi declarado; guardar o valor na classe prenderia o
+        // objeto atribuído (e o ciclo de que ele faça parte) enquanto a classe existir
+        if !self.class.this.borrow().exist(name) {
+            return Err(format!("Variable '{}' not defined", name));
         }
         let this = self.this.borrow_mut().assign(&name, value);
 
@@ -261,6 +316,14 @@ impl Instance {
 
     pub fn is_instance_of(&self, class: &Value) -> bool {
         let inst = self;
+        // Valores de enum são instâncias das classes das variantes
+        if let Some(target) = class.to_class() {
+            match target.kind {
+                ClassKind::Enum(_) => return target.has_variant(&inst.class),
+                ClassKind::Interface { .. } => return inst.class.implements(&target),
+                _ => {}
+            }
+        }
         let mut current = Some(&inst.class);
 
         while let Some(cls) = current {
@@ -296,6 +359,49 @@ pub struct Class {
     pub this: Rc<RefCell<Environment>>,
 
     pub closure: Rc<RefCell<Environment>>,
+    pub kind: ClassKind,
+    pub field_types: HashMap<String, TypeAnnotation>, // incluindo os herdados
+    pub interfaces: Vec<Rc<Class>>, // `implements` de classes ou `extends` de interfaces
+    pub decorations: RefCell<Vec<Decoration>>,
+}
+
+/// Classes comuns, abstratas, interfaces ou geradas por `enum`. Cada variante é uma classe
+/// própria que guarda o nome do enum e os campos na ordem declarada.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum ClassKind {
+    #[default]
+    Class,
+    Enum(Vec<String>),
+    Variant {
+        enum_name: String,
+        fields: Vec<String>,
+    },
+    /// Métodos sem corpo que as classes precisam implementar; os métodos com
+    /// corpo ficam em `methods` e são copiados para quem não os define.
+    Interface {
+        required: Vec<RequiredMethod>,
+    },
+    /// `abstract class`: não pode ser instanciada. `required` guarda os métodos
+    /// abstratos ainda sem implementação, cobrados das subclasses concretas.
+    Abstract {
+        required: Vec<RequiredMethod>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RequiredMethod {
+    pub name: String,
+    pub operator: bool, // precisa ser declarado com `operator`
+}
+
+impl std::fmt::Display for RequiredMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.operator {
+            write!(f, "operator {}", self.name)
+        } else {
+            write!(f, "{}", self.name)
+        }
+    }
 }
 
 impl Class {
@@ -318,9 +424,139 @@ impl Class {
             instance_variables,
             static_variables,
             closure,
+            kind: ClassKind::Class,
+            field_types: HashMap::new(),
+            interfaces: vec![],
+            decorations: RefCell::new(vec![]),
         }
     }
 
+    /// Classe de uma variante de enum, sem métodos; os campos viram variáveis de instância.
+    pub fn new_variant(
+        enum_name: &str,
+        name: &str,
+        fields: Vec<String>,
+        closure: Rc<RefCell<Environment>>,
+    ) -> Class {
+        let instance_variables = fields
+            .iter()
+            .map(|field| (field.clone(), Value::Null))
+            .chain([("tag".to_string(), Value::String(name.to_string()))])
+            .collect();
+        let mut class = Class::new(
+            name.to_string(),
+            vec![],
+            None,
+            Environment::new_rc(),
+            vec![],
+            Rc::new(RefCell::new(instance_variables)),
+            HashMap::new(),
+            closure,
+        );
+        class.kind = ClassKind::Variant {
+            enum_name: enum_name.to_string(),
+            fields,
+        };
+        class
+    }
+
+    /// Cria uma instância de variante atribuindo os campos por posição.
+    pub fn instantiate_variant(class: &Rc<Class>, args: Vec<Value>) -> Result<Value, String> {
+        let ClassKind::Variant { enum_name, fields } = &class.kind else {
+            return Err(format!("'{}' is not an enum variant", class.name));
+        };
+        if args.len() != fields.len() {
+            return Err(format!(
+                "{enum_name}.{} expects {} argument(s), got {}",
+                class.name,
+                fields.len(),
+                args.len()
+            ));
+        }
+        let instance = Class::instantiate(class, vec![]);
+        if let Value::Instance(instance) = &instance {
+            let this = instance.borrow().this.clone();
+            for (field, value) in fields.iter().zip(args) {
+                this.borrow_mut().define(field.clone(), value);
+            }
+        }
+        Ok(instance)
+    }
+
+    pub fn is_interface(&self) -> bool {
+        matches!(self.kind, ClassKind::Interface { .. })
+    }
+
+    pub fn is_abstract(&self) -> bool {
+        matches!(self.kind, ClassKind::Abstract { .. })
+    }
+
+    /// Métodos abstratos pendentes de uma interface ou classe abstrata.
+    pub fn required_methods(&self) -> &[RequiredMethod] {
+        match &self.kind {
+            ClassKind::Interface { required } | ClassKind::Abstract { required } => required,
+            _ => &[],
+        }
+    }
+
+    /// Verdadeiro se esta classe, uma superclasse ou uma interface herdada declara `interface`.
+    pub fn implements(&self, interface: &Rc<Class>) -> bool {
+        let declared = self
+            .interfaces
+            .iter()
+            .any(|own| Rc::ptr_eq(own, interface) || own.implements(interface));
+        declared
+            || self
+                .superclass
+                .as_ref()
+                .is_some_and(|parent| parent.implements(interface))
+    }
+
+    /// Verdadeiro se `variant` é a classe de uma das variantes deste enum.
+    pub fn has_variant(&self, variant: &Rc<Class>) -> bool {
+        match self.static_variables.get(&variant.name) {
+            Some(Value::Class(class)) => Rc::ptr_eq(class, variant),
+            Some(Value::Instance(unit)) => Rc::ptr_eq(&unit.borrow().class, variant),
+            _ => false,
+        }
+    }
+
+    /// Nome do enum ao qual a variante pertence.
+    pub fn enum_name(&self) -> Option<String> {
+        match &self.kind {
+            ClassKind::Variant { enum_name, .. } => Some(enum_name.clone()),
+            _ => None,
+        }
+    }
+
+    /// Campos da variante na ordem declarada, ou `None` se não for variante de enum.
+    pub fn variant_fields(&self) -> Option<&Vec<String>> {
+        match &self.kind {
+            ClassKind::Variant { fields, .. } => Some(fields),
+            _ => None,
+        }
+    }
+
+    /// `Cor.Rgb` para variantes; o próprio nome para as demais classes.
+    pub fn qualified_name(&self) -> String {
+        match &self.kind {
+            ClassKind::Variant { enum_name, .. } => format!("{enum_name}.{}", self.name),
+            _ => self.name.clone(),
+        }
+    }
+
+    /// Verdadeiro se a classe é `name` ou herda de uma classe com esse nome.
+    pub fn extends(&self, name: &str) -> bool {
+        let mut current = Some(self);
+        while let Some(class) = current {
+            if class.name == name {
+                return true;
+            }
+            current = class.superclass.as_deref();
+        }
+        false
+    }
+
     pub fn get_all_vars_in_this(&self) -> Vec<(String, Value)> {
         let this = self.this.borrow();
 
@@ -333,6 +569,13 @@ impl Class {
     }
 
     pub fn instantiate(class: &Rc<Class>, mut args: Vec<Value>) -> Value {
+        if class.is_abstract() {
+            return Value::new_typed_error(
+                &mut class.closure.clone(),
+                ErrorKind::TypeError,
+                format!("Cannot instantiate abstract class '{}'", class.name),
+            );
+        }
         let this = Environment::new_rc();
         // let closure = interpreter.env.clone();
         this.borrow_mut().copy_from(class.this.clone());
@@ -363,9 +606,19 @@ impl Class {
         let instance = Instance {
             class: class.clone(),
             this: this.clone(),
+            finalized: false,
         };
 
+        // Erros registram a pilha de chamadas no momento da criação
+        if class.extends("Error") {
+            this.borrow_mut().define(
+                "stack".to_string(),
+                Value::String(call_stack::format_stack()),
+            );
+        }
+
         let instance = Rc::new(RefCell::new(instance));
+        gc::track_instance(&instance);
         let value = Value::Instance(instance.clone());
         // add "value" at start
         args.insert(0, value.clone());
@@ -376,7 +629,13 @@ impl Class {
             // for (idx, ele) in args.iter().enumerate() {
             //     println!("Argumento {idx} {ele} {}", class.name)
             // }
+            // Frame próprio: chamadas dentro do construtor não mudam a posição de quem fez o `new`
+            call_stack::push(
+                format!("new {}", class.name),
+                call_stack::current_location(),
+            );
             let call = constructor.call(args);
+            call_stack::pop();
 
             return call;
         }
@@ -531,6 +790,65 @@ pub struct Function {
     pub prototype: Option<FunctionPrototype>,
     pub modifiers: Vec<Modifiers>,
     pub this: Value,
+    pub signature: Signature, // anotações, conferidas só em modo estrito
+    pub decorations: RefCell<Vec<Decoration>>,
+    pub bound_args: Vec<Value>, // argumentos parciais de `bind`, passados depois de `this`
+}
+
+/// Decorador aplicado a uma função, método ou classe, na ordem do código.
+/// Coloca os argumentos nomeados (`f(a: 1)`) na posição do parâmetro de mesmo nome,
+/// depois dos posicionais. Parâmetros pulados recebem `null`.
+pub fn bind_named_args(
+    callee: &str,
+    params: &[String],
+    mut args: Vec<Value>,
+    mut named: HashMap<String, Value>,
+) -> Result<Vec<Value>, String> {
+    if named.is_empty() {
+        return Ok(args);
+    }
+    let mut unknown: Vec<&String> = named.keys().filter(|name| !params.contains(name)).collect();
+    unknown.sort();
+    if let Some(name) = unknown.first() {
+        return Err(format!("Unknown argument '{name}' for '{callee}'"));
+    }
+    for (index, param) in params.iter().enumerate() {
+        let Some(value) = named.remove(param) else {
+            continue;
+        };
+        if index < args.len() {
+            return Err(format!(
+                "Argument '{param}' given more than once to '{callee}'"
+            ));
+        }
+        args.resize(index, Value::Null);
+        args.push(value);
+    }
+    Ok(args)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Decoration {
+    pub name: String,
+    pub args: Vec<Value>,
+}
+
+impl Decoration {
+    /// `{ name: "deprecated", args: ["msg"] }`, como visto por `decorators(alvo)`.
+    pub fn to_value(&self) -> Value {
+        Value::Object(Rc::new(RefCell::new(vec![
+            ("name".to_string(), Value::String(self.name.clone())),
+            ("args".to_string(), Value::array(self.args.clone())),
+        ])))
+    }
+}
+
+/// Chamada adiada por `return f(args)`, executada pelo trampolim de `Function::call`.
+#[derive(Debug, Clone)]
+pub struct TailCall {
+    pub function: Rc<Function>,
+    pub args: Vec<Value>,
+    pub location: SourceLocation,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -545,6 +863,17 @@ impl FunctionPrototype {
     }
 }
 
+/// Código-fonte aproximado, usado por `f.toString()`.
+impl std::fmt::Display for FunctionPrototype {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "function {}({}) {{", self.name, self.params.join(", "))?;
+        for line in self.body.lines() {
+            writeln!(f, "  {line}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
 impl Function {
     pub fn new(
         name: String,
@@ -563,11 +892,27 @@ impl Function {
             prototype: None,
             modifiers,
             this: Value::Null,
+            signature: Signature::default(),
+            decorations: RefCell::new(vec![]),
+            bound_args: vec![],
         };
         func.generate_proto();
         func
     }
 
+    pub fn with_signature(mut self, signature: Signature) -> Self {
+        self.signature = signature;
+        self
+    }
+
+    /// Nome exibido no stack trace: `Classe.metodo` para métodos ligados a uma instância.
+    pub fn qualified_name(&self) -> String {
+        match &self.this {
+            Value::Instance(instance) => format!("{}.{}", instance.borrow().class.name, self.name),
+            _ => self.name.clone(),
+        }
+    }
+
     pub fn unwrap(&self) -> Self {
         Self {
             name: self.name.clone(),
@@ -578,6 +923,9 @@ impl Function {
             prototype: self.prototype.clone(),
             modifiers: self.modifiers.clone(),
             this: self.this.clone(),
+            signature: self.signature.clone(),
+            decorations: self.decorations.clone(),
+            bound_args: self.bound_args.clone(),
         }
     }
 
@@ -597,9 +945,34 @@ impl Function {
             prototype: func.prototype.clone(),
             modifiers: func.modifiers.clone(),
             this: func.this.clone(),
+            signature: func.signature.clone(),
+            decorations: func.decorations.clone(),
+            bound_args: func.bound_args.clone(),
         }
     }
 
+    /// Cópia com outro `this` (se não for `null`) e mais argumentos parciais.
+    pub fn bind(&self, this: Value, partial: Vec<Value>) -> Self {
+        let mut func = self.clone();
+        if !this.is_null() {
+            func.this = this;
+        }
+        func.bound_args.extend(partial);
+        func
+    }
+
+    /// Parâmetros que ainda faltam ao chamador: sem o `this` ligado nem os
+    /// argumentos de `bind`; o parâmetro `...resto` não conta.
+    pub fn arity(&self) -> usize {
+        self.explicit_params().len()
+    }
+
+    /// Parâmetros que o chamador informa, por posição ou por nome.
+    pub fn explicit_params(&self) -> &[String] {
+        let bound = self.bound_args.len() + usize::from(!self.this.is_null());
+        self.params.get(bound..).unwrap_or_default()
+    }
+
     pub fn generate_proto(&mut self) -> FunctionPrototype {
         let name = self.name.clone();
         let params = self.params.clone();
@@ -619,20 +992,56 @@ impl Function {
         body
     }
 
-    pub fn call(&self, mut args: Vec<Value>) -> Value {
+    /// Executa a função. Chamadas em posição de cauda (`return f(args)`) voltam
+    /// como `TailCall` e são executadas neste laço (trampolim), então recursão
+    /// própria ou mútua não aprofunda a pilha nativa.
+    pub fn call(&self, args: Vec<Value>) -> Value {
+        let mut outcome = self.invoke(args);
+        loop {
+            match outcome {
+                Ok(value) => return value,
+                Err(tail_call) => {
+                    call_stack::replace_top(
+                        tail_call.function.qualified_name(),
+                        tail_call.location,
+                    );
+                    outcome = tail_call.function.invoke(tail_call.args);
+                }
+            }
+        }
+    }
+
+    fn invoke(&self, mut args: Vec<Value>) -> Result<Value, TailCall> {
         let name = &self.name;
         let body = &self.body;
         let mut interpreter = Interpreter::new_empty();
 
         let is_initializer = self.name == "constructor";
-        // let _guard = DepthGuard::new().map_err(|e| e.to_string())?;
+        // Em modo estrito o retorno anotado precisa ser conferido nesta chamada
+        let checks_return = typecheck::is_strict() && self.signature.returns.is_some();
+        interpreter.set_tail_calls(!is_initializer && !checks_return);
+        interpreter.enter_function();
 
         // let binding = self.environment.borrow();
         let closure = self.environment.borrow(); // evita clone
 
         let mut local_env = closure.to_rc();
 
+        // Toda chamada passa por aqui, inclusive `.call`, operadores e destrutores
+        if call_stack::is_full() {
+            return Ok(call_stack::with_headroom(|| {
+                Value::new_typed_error(
+                    &mut local_env,
+                    ErrorKind::RangeError,
+                    "Maximum call stack size exceeded".to_string(),
+                )
+            }));
+        }
+
         // remove last arg
+        if !self.bound_args.is_empty() {
+            args.splice(0..0, self.bound_args.iter().cloned());
+        }
         if !self.this.is_null() {
             args.insert(0, self.this.clone());
         }
@@ -665,36 +1074,103 @@ impl Function {
                 .define(vararg_name.clone(), vararg_values);
         }
 
+        if typecheck::is_strict() {
+            if let Some(error) = self.check_param_types(&mut local_env) {
+                return Ok(error);
+            }
+        }
+
         // Executa o corpo da função
+        let mut flow = ControlFlow::None;
         for stmt in body {
-            match interpreter.eval_stmt(stmt, &mut local_env) {
-                ControlFlow::Return(val) => {
-                    return val;
+            flow = interpreter.eval_stmt(stmt, &mut local_env);
+            if !matches!(flow, ControlFlow::None) {
+                break;
+            }
+        }
+        // Os `defer` do corpo rodam depois de o valor de retorno ser calculado
+        match interpreter.exit_scope(&mut local_env, flow) {
+            ControlFlow::Return(val) => {
+                if let Some(tail_call) = interpreter.take_tail_call() {
+                    return Err(tail_call);
                 }
-                ControlFlow::Break => {
-                    return Value::new_error(
-                        &mut local_env,
-                        format!("Break not allowed in function {}", name).into(),
-                    )
+                if checks_return {
+                    return Ok(self.check_return_type(val, &mut local_env));
                 }
-                ControlFlow::Continue => {
-                    return Value::new_error(
-                        &mut local_env,
-                        format!("Continue not allowed in function {}", name).into(),
-                    )
+                return Ok(val);
+            }
+            ControlFlow::Break => {
+                return Ok(Value::new_error(
+                    &mut local_env,
+                    format!("Break not allowed in function {}", name),
+                ))
+            }
+            ControlFlow::Continue => {
+                return Ok(Value::new_error(
+                    &mut local_env,
+                    format!("Continue not allowed in function {}", name),
+                ))
+            }
+            ControlFlow::None => {}
+            // Erros lançados voltam embrulhados em `Value::Error` para o chamador propagar
+            ControlFlow::Error(err) => {
+                // `expr?` com `None`/`Err`: retorno antecipado, não um erro
+                if let Some(value) = interpreter.take_propagated() {
+                    if checks_return {
+                        return Ok(self.check_return_type(value, &mut local_env));
+                    }
+                    return Ok(value);
                 }
-                ControlFlow::None => {}
-                ControlFlow::Error(err) => {
-                    return err;
+                if err.is_error() {
+                    return Ok(err);
                 }
+                return Ok(Value::Error(Rc::new(RefCell::new(err))));
             }
         }
 
         if is_initializer {
-            return this;
+            return Ok(this);
+        }
+        if checks_return {
+            return Ok(self.check_return_type(Value::Void, &mut local_env));
         }
 
-        Value::Void
+        Ok(Value::Void)
+    }
+
+    /// Modo estrito: confere os parâmetros anotados já definidos em `local_env`.
+    fn check_param_types(&self, local_env: &mut Rc<RefCell<Environment>>) -> Option<Value> {
+        for (param, ty) in self.params.iter().zip(&self.signature.params) {
+            let Some(ty) = ty else {
+                continue;
+            };
+            let value = local_env.borrow().get(param).unwrap_or(Value::Null);
+            if !typecheck::value_matches(&value, ty, local_env) {
+                let msg = format!(
+                    "Argument '{param}' of '{}' expects {ty}, got {}",
+                    self.qualified_name(),
+                    typecheck::describe(&value)
+                );
+                return Some(Value::new_typed_error(local_env, ErrorKind::TypeError, msg));
+            }
+        }
+        None
+    }
+
+    /// Modo estrito: troca o valor retornado por um `TypeError` se não bater com a anotação.
+    fn check_return_type(&self, value: Value, local_env: &mut Rc<RefCell<Environment>>) -> Value {
+        let Some(ty) = &self.signature.returns else {
+            return value;
+        };
+        if value.is_error() || typecheck::value_matches(&value, ty, local_env) {
+            return value;
+        }
+        let msg = format!(
+            "'{}' must return {ty}, got {}",
+            self.qualified_name(),
+            typecheck::describe(&value)
+        );
+        Value::new_typed_error(local_env, ErrorKind::TypeError, msg)
     }
 
     pub fn is_static(&self) -> bool {
@@ -708,37 +1184,42 @@ impl Function {
     }
 }
 impl Value {
-    #[track_caller]
     pub fn new_error(env: &mut Rc<RefCell<Environment>>, msg: String) -> Value {
-        let location = std::panic::Location::caller().to_string()
-            + " "
-            + std::file!()
-            + ":"
-            + &std::line!().to_string();
-        let env = env.borrow();
-        let error_class = env.get("Error");
-        if error_class.is_none() {
-            panic!("Error class not found {msg} {location}")
-        }
-        let error_class = error_class.unwrap().to_class();
-        if error_class.is_none() {
-            panic!("Class 'Error' not found");
-        }
-        let error_class = error_class.unwrap();
-
-        let throw_method = error_class.find_static_method("throw");
-
-        if throw_method.is_none() {
-            panic!("throw method not found");
+        Value::new_typed_error(env, ErrorKind::Error, msg)
+    }
+
+    /// Instancia a classe de erro de `kind` via `throw` estático. Se ela não
+    /// existir usa `Error`; sem nenhuma classe de erro carregada, devolve um
+    /// objeto simples com `name` e `message` em vez de abortar.
+    pub fn new_typed_error(
+        env: &mut Rc<RefCell<Environment>>,
+        kind: ErrorKind,
+        msg: String,
+    ) -> Value {
+        let throw_method = [kind.name(), ErrorKind::Error.name()]
+            .iter()
+            .filter_map(|name| env.borrow().get(name))
+            .filter_map(|class| class.to_class())
+            .find_map(|class| class.find_static_method("throw"));
+
+        if let Some(throw_method) = throw_method {
+            let error = throw_method.call(vec![Value::Null, Value::String(msg.clone())]);
+            if !error.is_error() {
+                return Value::Error(Rc::new(error.into()));
+            }
         }
-        let throw_method = throw_method.unwrap();
-
-        let error = throw_method.call(vec![Value::Null, Value::String(msg.into())]);
 
-        if error.is_error() {
-            panic!("Error creating error object {:?}", error.to_string());
-        }
-        Value::Error(Rc::new(error.into()))
+        Value::Error(Rc::new(
+            Value::object(vec![
+                ("name".to_string(), Value::String(kind.name().into())),
+                ("message".to_string(), Value::String(msg)),
+                (
+                    "stack".to_string(),
+                    Value::String(call_stack::format_stack()),
+                ),
+            ])
+            .into(),
+        ))
     }
 
     pub fn is_error(&self) -> bool {
@@ -755,27 +1236,186 @@ impl Value {
         }
     }
 
+    /// Igualdade usada por `indexOf`/`includes`: mesma semântica de `===`.
     pub fn equal(&self, other: &Value) -> bool {
+        self.strict_equal(other)
+    }
+
+    /// Igualdade estrita (`===`), sem nenhuma coerção:
+    /// - números precisam ter o mesmo tipo (`int`, `float`, `bigint`, `decimal`) e valor;
+    /// - `bool`, `string`, `null` e `void` comparam por valor;
+    /// - arrays, objetos, instâncias, funções e classes comparam por identidade.
+    pub fn strict_equal(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Number(a), Value::Number(b)) => a.type_name() == b.type_name() && a == b,
+            (Value::Error(a), Value::Error(b)) => Rc::ptr_eq(a, b),
+            (Value::Instance(a), Value::Instance(b)) => {
+                Rc::ptr_eq(a, b) || Self::variant_equal(&a.borrow(), &b.borrow())
+            }
+            (a, b) => a == b,
+        }
+    }
+
+    /// Variantes de enum são valores: iguais quando têm a mesma variante e campos `===`.
+    fn variant_equal(a: &Instance, b: &Instance) -> bool {
+        let Some(fields) = a.class.variant_fields() else {
+            return false;
+        };
+        Rc::ptr_eq(&a.class, &b.class)
+            && fields.iter().all(|field| {
+                match (a.this.borrow().get(field), b.this.borrow().get(field)) {
+                    (Some(x), Some(y)) => x.strict_equal(&y),
+                    _ => false,
+                }
+            })
+    }
+
+    /// Igualdade com coerção (`==`). Regras, aplicadas em ordem:
+    /// 1. números comparam pelo valor numérico, independente do tipo (`1 == 1.0`);
+    /// 2. valores do mesmo tipo comparam como em `===`;
+    /// 3. `null` e `void` são iguais entre si e diferentes de todo o resto;
+    /// 4. `bool` é convertido para `1`/`0` antes de comparar;
+    /// 5. `string` comparada a número é convertida para número (`"10" == 10`);
+    /// 6. instâncias comparadas a primitivos usam o resultado de `valueOf()`;
+    /// 7. qualquer outra combinação é diferente.
+    pub fn loose_equal(&self, other: &Value) -> bool {
         match (self, other) {
-            (Value::Void, Value::Void) => true,
-            (Value::Null, Value::Null) => true,
-            (Value::Bool(a), Value::Bool(b)) => a == b,
             (Value::Number(a), Value::Number(b)) => a == b,
-            (Value::String(a), Value::String(b)) => a == b,
-            (Value::Array(a), Value::Array(b)) => a == b,
-            (Value::Object(a), Value::Object(b)) => Rc::ptr_eq(&*a, &*b),
-            (Value::Function(a), Value::Function(b)) => Rc::ptr_eq(&*a, &*b),
-            (Value::Class(a), Value::Class(b)) => Rc::ptr_eq(&*a, &*b),
-            (Value::Instance(a), Value::Instance(b)) => Rc::ptr_eq(&*a, &*b),
-            (Value::InternalClass(a), Value::InternalClass(b)) => Rc::ptr_eq(&*a, &*b),
-            (Value::InternalFunction(a), Value::InternalFunction(b)) => {
-                let a = &a.1;
-                let b = &b.1;
-                Rc::ptr_eq(a, b)
+            (a, b) if a.type_of() == b.type_of() => a.strict_equal(b),
+            (Value::Null | Value::Void, b) => b.is_null() || b.is_void(),
+            (_, Value::Null | Value::Void) => false,
+            (Value::Bool(a), b) => Value::Number((*a as i64).into()).loose_equal(b),
+            (a, Value::Bool(b)) => a.loose_equal(&Value::Number((*b as i64).into())),
+            (Value::String(s), Value::Number(_)) => Value::parse_number(&s.to_string())
+                .map(|n| n.loose_equal(other))
+                .unwrap_or(false),
+            (Value::Number(_), Value::String(_)) => other.loose_equal(self),
+            (Value::Instance(instance), b) if b.is_primitive() => {
+                Value::primitive_of(instance).is_some_and(|a| a.loose_equal(b))
+            }
+            (a, Value::Instance(instance)) if a.is_primitive() => {
+                Value::primitive_of(instance).is_some_and(|b| a.loose_equal(&b))
             }
             _ => false,
         }
     }
+
+    /// Converte texto em número para o `==`: espaços são ignorados e texto vazio vale 0.
+    fn parse_number(text: &str) -> Option<Value> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Some(Value::Number(0.into()));
+        }
+        if let Ok(i) = text.parse::<i64>() {
+            return Some(Value::Number(i.into()));
+        }
+        text.parse::<f64>().ok().map(|f| Value::Number(f.into()))
+    }
+
+    /// Resultado de `valueOf()` de uma instância, quando for um primitivo.
+    fn primitive_of(instance: &Rc<RefCell<Instance>>) -> Option<Value> {
+        let method = instance.borrow().class.find_method("valueOf")?;
+        let value = method.call(vec![Value::Instance(instance.clone())]);
+        if value.is_primitive() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Igualdade estrutural usada pelo builtin `deepEqual(a, b)`: arrays comparam
+    /// elemento a elemento, objetos por conjunto de chaves e valores (sem importar a
+    /// ordem) e instâncias da mesma classe pelos seus campos. Um método
+    /// `@Operator eq` na instância da esquerda tem precedência. Demais valores
+    /// comparam como em `===`, exceto números, que comparam pelo valor.
+    pub fn deep_equal(&self, other: &Value) -> bool {
+        self.deep_equal_with(other, &mut Vec::new())
+    }
+
+    fn deep_equal_with(&self, other: &Value, visited: &mut Vec<(usize, usize)>) -> bool {
+        // Estruturas cíclicas: um par já em comparação é considerado igual
+        if let (Some(a), Some(b)) = (self.ref_address(), other.ref_address()) {
+            if a == b || visited.contains(&(a, b)) {
+                return true;
+            }
+            visited.push((a, b));
+        }
+
+        match (self, other) {
+            (Value::Number(a), Value::Number(b)) => a == b,
+            (Value::Array(a), Value::Array(b)) => {
+                let a = a.get_value();
+                let b = b.get_value();
+                let a = a.borrow().clone();
+                let b = b.borrow().clone();
+                a.len() == b.len()
+                    && a.iter()
+                        .zip(b.iter())
+                        .all(|(x, y)| x.deep_equal_with(y, visited))
+            }
+            (Value::Object(a), Value::Object(b)) => {
+                let a = a.borrow().clone();
+                let b = b.borrow().clone();
+                Value::deep_equal_entries(&a, &b, visited)
+            }
+            (Value::Instance(a), _) if a.borrow().find_operation("eq").is_some() => {
+                let method = a.borrow().find_operation("eq").unwrap();
+                method
+                    .call(vec![Value::Instance(a.clone()), other.clone()])
+                    .is_truthy()
+            }
+            (Value::Instance(a), Value::Instance(b)) => {
+                if !Rc::ptr_eq(&a.borrow().class, &b.borrow().class) {
+                    return false;
+                }
+                let a = a.borrow().fields();
+                let b = b.borrow().fields();
+                Value::deep_equal_entries(&a, &b, visited)
+            }
+            (Value::Error(a), Value::Error(b)) => {
+                let a = a.borrow().clone();
+                let b = b.borrow().clone();
+                a.deep_equal_with(&b, visited)
+            }
+            (a, b) => a.strict_equal(b),
+        }
+    }
+
+    fn deep_equal_entries(
+        a: &[(String, Value)],
+        b: &[(String, Value)],
+        visited: &mut Vec<(usize, usize)>,
+    ) -> bool {
+        a.len() == b.len()
+            && a.iter().all(
+                |(key, value)| match b.iter().find(|(other_key, _)| other_key == key) {
+                    Some((_, other)) => value.deep_equal_with(other, visited),
+                    None => false,
+                },
+            )
+    }
+
+    /// Pares chave/valor próprios de objetos e instâncias (sem métodos), usados
+    /// por `Object.keys/values/entries`.
+    pub fn own_entries(&self) -> Option<Vec<(String, Value)>> {
+        match self {
+            Value::Object(o) => Some(o.borrow().clone()),
+            Value::Instance(i) => Some(i.borrow().fields()),
+            Value::Error(e) => e.borrow().own_entries(),
+            _ => None,
+        }
+    }
+
+    /// Endereço do valor referenciado, para detectar ciclos.
+    fn ref_address(&self) -> Option<usize> {
+        match self {
+            Value::Array(a) => Some(Rc::as_ptr(&a.get_value()) as *const () as usize),
+            Value::Object(o) => Some(Rc::as_ptr(o) as *const () as usize),
+            Value::Instance(i) => Some(Rc::as_ptr(i) as *const () as usize),
+            _ => None,
+        }
+    }
+
     pub fn array(vec: Vec<Value>) -> Value {
         Value::Array(Rc::new(RefCell::new(vec)).into())
     }
@@ -783,14 +1423,19 @@ impl Value {
         let instance = Instance {
             class: class.clone(),
             this: Environment::new_rc(),
+            finalized: false,
         };
-        Value::Instance(Rc::new(instance.into()))
+        let instance = Rc::new(RefCell::new(instance));
+        gc::track_instance(&instance);
+        Value::Instance(instance)
     }
     pub fn object(map: Vec<(String, Value)>) -> Value {
-        Value::Object(Rc::new(RefCell::new(map)))
+        let entries = Rc::new(RefCell::new(map));
+        gc::track_object(&entries);
+        Value::Object(entries)
     }
     pub fn empty_object() -> Value {
-        Value::Object(Rc::new(RefCell::new(Vec::new())))
+        Value::object(Vec::new())
     }
 
     pub fn error(message: String) -> Value {
@@ -804,7 +1449,7 @@ impl Value {
         }
     }
     pub fn new_object() -> Value {
-        Value::Object(Rc::new(RefCell::new(Vec::new())))
+        Value::object(Vec::new())
     }
 
     pub fn is_native_class(&self) -> bool {
@@ -825,48 +1470,75 @@ impl Value {
         }
     }
 
-    pub fn call_op(&self, op: BinaryOperator, other: &Value) -> Value {
+    pub fn call_op(&self, op: BinaryOperator, other: &Value) -> ArithmeticResult<Value> {
         let left = self.clone();
         let right = other.clone();
         let op_alias = op.alias();
-        if left.is_instance() || right.is_instance() {
-            let instance = if left.is_instance() {
-                left.to_instance()
-            } else {
-                right.to_instance()
-            };
-            let other = if left.is_instance() {
-                right.clone()
-            } else {
-                left.clone()
+
+        // `vec * 2` chama `mul(vec, 2)`; `2 * vec` chama a versão refletida `rmul(vec, 2)`
+        if let Some(result) = left.call_operator(&op_alias, vec![right.clone()]) {
+            return Ok(result);
+        }
+        if let Some(result) = right.call_operator(&format!("r{op_alias}"), vec![left.clone()]) {
+            return Ok(result);
+        }
+
+        if op == BinaryOperator::Add
+            && !matches!((&left, &right), (Value::Number(_), Value::Number(_)))
+        {
+            return Ok(Value::String(format!("{}{}", left, right)));
+        }
+
+        // Inteiros permanecem inteiros; overflow vira erro em vez de perder precisão
+        let result = left
+            .to_native_number()
+            .checked_binary(&op, &right.to_native_number())?;
+        Ok(Value::Number(result))
+    }
+
+    /// Método chamado ao sair de `using`: `close` ou, na falta dele, `dispose`.
+    pub fn disposer(&self) -> Option<&'static str> {
+        ["close", "dispose"].into_iter().find(|name| {
+            let method = match self {
+                Value::Instance(instance) => instance.borrow().get(name),
+                Value::Object(object) => object.borrow().get_prop(name),
+                _ => None,
             };
+            method.is_some_and(|method| {
+                matches!(
+                    method,
+                    Value::Function(_) | Value::Builtin(_) | Value::InternalFunction(_)
+                )
+            })
+        })
+    }
 
-            let plus_method = instance.borrow().find_operation(&op_alias);
-            if let Some(method) = plus_method {
-                let args = vec![Value::Instance(instance), other.clone()];
-                let val = method.call(args);
-                if !val.is_error() {
-                    return val;
-                }
-            }
+    /// Verdadeiro se o valor é uma instância que declara `operator <alias>`.
+    pub fn has_operator(&self, alias: &str) -> bool {
+        match self {
+            Value::Instance(instance) => instance.borrow().find_operation(alias).is_some(),
+            _ => false,
         }
+    }
 
-        match op {
-            BinaryOperator::Add => match (left, right) {
-                (Value::Number(a), Value::Number(b)) => Value::Number((a + b).into()),
-                (a, b) => Value::String(format!("{}{}", a, b).into()),
-            },
-            BinaryOperator::Subtract => {
-                Value::Number((left.to_number() - right.to_number()).into())
-            }
-            BinaryOperator::Multiply => {
-                Value::Number((left.to_number() * &right.to_number()).into())
-            }
-            BinaryOperator::Divide => Value::Number((left.to_number() / &right.to_number()).into()),
-            BinaryOperator::Modulo => Value::Number((left.to_number() % &right.to_number()).into()),
-            BinaryOperator::Exponentiate => {
-                Value::Number((left.to_number().powf(right.to_number())).into())
-            }
+    /// Chama `operator <alias>(self, ...args)` quando o valor é uma instância que o
+    /// declara; `None` para os demais valores. Erros voltam como `Value::Error`.
+    pub fn call_operator(&self, alias: &str, args: Vec<Value>) -> Option<Value> {
+        let Value::Instance(instance) = self else {
+            return None;
+        };
+        let method = instance.borrow().find_operation(alias)?;
+        let mut call_args = vec![self.clone()];
+        call_args.extend(args);
+        Some(method.call(call_args))
+    }
+
+    /// Converte o valor para número, preservando o tipo (int/float) quando já é um número.
+    pub fn to_native_number(&self) -> NativeNumberClass {
+        match self {
+            Value::Number(n) => n.clone(),
+            Value::Bool(b) => NativeNumberClass::new_with_int(*b as i64),
+            other => NativeNumberClass::new_with_value(other.to_number()),
         }
     }
 
@@ -975,12 +1647,29 @@ impl Value {
         }
     }
 
+    /// Decoradores registrados em funções e classes; vazio para outros valores.
+    pub fn decorations(&self) -> Vec<Decoration> {
+        match self {
+            Value::Function(func) => func.decorations.borrow().clone(),
+            Value::Class(class) => class.decorations.borrow().clone(),
+            _ => vec![],
+        }
+    }
+
+    pub fn set_decorations(&self, decorations: Vec<Decoration>) {
+        match self {
+            Value::Function(func) => *func.decorations.borrow_mut() = decorations,
+            Value::Class(class) => *class.decorations.borrow_mut() = decorations,
+            _ => {}
+        }
+    }
+
     pub fn type_of(&self) -> String {
         match self {
             Value::Void => "void".to_string(),
             Value::Null => "null".to_string(),
             Value::Bool(_) => "bool".to_string(),
-            Value::Number(_) => "number".to_string(),
+            Value::Number(n) => n.type_name().to_string(),
             Value::String(_) => "string".to_string(),
             Value::Array(_) => "array".to_string(),
             Value::Object(_) => "object".to_string(),
@@ -1017,6 +1706,23 @@ impl Value {
             Value::Function(function) => format!("<function {}>", function.name),
             Value::Builtin(_) => "<builtin>".to_string(),
             Value::Class(class) => format!("<class {}>", class.name),
+            Value::Instance(instance) if instance.borrow().class.variant_fields().is_some() => {
+                let instance = instance.borrow();
+                let fields = instance.class.variant_fields().cloned().unwrap_or_default();
+                let name = instance.class.qualified_name();
+                if fields.is_empty() {
+                    return name;
+                }
+                let values = fields
+                    .iter()
+                    .map(|field| {
+                        let value = instance.this.borrow().get(field).unwrap_or(Value::Null);
+                        value.to_string()
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{name}({values})")
+            }
             Value::Instance(instance) => {
                 if let Some(method) = instance.borrow().get_to_string().as_ref() {
                     let method = method.clone();
@@ -1082,6 +1788,21 @@ impl Value {
             Value::Function { .. } => "<function>".to_string(),
             Value::Builtin(_) => "<builtin>".to_string(),
             Value::Class(_) => "<class>".to_string(),
+            Value::Instance(instance) if instance.borrow().class.variant_fields().is_some() => {
+                let instance = instance.borrow();
+                let fields = instance.class.variant_fields().cloned().unwrap_or_default();
+                // {"tag": "Rgb", "r": 1, ...} com os campos na ordem declarada
+                let entries = std::iter::once((
+                    "tag".to_string(),
+                    Value::String(instance.class.name.clone()),
+                ))
+                .chain(fields.into_iter().map(|field| {
+                    let value = instance.this.borrow().get(&field).unwrap_or(Value::Null);
+                    (field, value)
+                }))
+                .collect();
+                Value::object(entries).stringfy()
+            }
             Value::Instance(_) => self.convert_class_to_object().stringfy(),
             Value::InternalClass(_) => "<class>".to_string(),
             Value::InternalFunction(_) => "<function>".to_string(),
@@ -1221,7 +1942,7 @@ impl Value {
                 if class.is_none() {
                     return None;
                 }
-                let class = class.unwrap().to_class().unwrap();
+                let class = class.unwrap().to_class()?;
                 Some(Class::instantiate(&class, vec![self.clone()]))
             }
             Value::Number(_) => {
@@ -1229,7 +1950,7 @@ impl Value {
                 if class.is_none() {
                     return None;
                 }
-                let class = class.unwrap().to_class().unwrap();
+                let class = class.unwrap().to_class()?;
                 Some(Class::instantiate(&class, vec![self.clone()]))
             }
             Value::String(_) => {
@@ -1237,7 +1958,7 @@ impl Value {
                 if class.is_none() {
                     return None;
                 }
-                let class = class.unwrap().to_class().unwrap();
+                let class = class.unwrap().to_class()?;
                 Some(Class::instantiate(&class, vec![self.clone()]))
             }
             _ => None,
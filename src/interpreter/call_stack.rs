@@ -0,0 +1,186 @@
+//! Pilha de chamadas do script. Cada `Function::call` cria um `Interpreter`
+//! novo, então os frames ficam em uma pilha thread-local compartilhada.
+
+use std::cell::{Cell, RefCell};
+
+use crate::ast::ast::SourceLocation;
+
+#[derive(Debug, Clone)]
+pub struct StackFrame {
+    pub function: String,
+    pub location: SourceLocation, // posição atual no frame: a chamada pendente ou onde o erro surgiu
+    pub tail_calls: usize,        // frames substituídos por chamadas de cauda até chegar neste
+    pub tail_caller: Option<String>, // o último deles
+}
+
+/// Profundidade máxima padrão; pode ser alterada com `set_max_depth`.
+pub const DEFAULT_MAX_DEPTH: usize = 10_000;
+
+/// Frames extras permitidos enquanto o `RangeError` de estouro é construído,
+/// já que o construtor do erro também é código do script.
+const OVERFLOW_HEADROOM: usize = 64;
+
+/// Pilha nativa mantida livre abaixo do limite: metade fica para construir o
+/// `RangeError`, o resto para o código nativo chamado pelo último frame.
+const NATIVE_STACK_RESERVE: usize = 64 * 1024 * 1024;
+
+thread_local! {
+    static CALL_STACK: RefCell<Vec<StackFrame>> = const { RefCell::new(Vec::new()) };
+    static MAX_DEPTH: Cell<usize> = const { Cell::new(DEFAULT_MAX_DEPTH) };
+    /// Endereço inicial e bytes utilizáveis da pilha nativa, quando conhecidos.
+    static NATIVE_STACK: Cell<Option<(usize, usize)>> = const { Cell::new(None) };
+    /// Posição atual do código no nível do arquivo, fora de qualquer função.
+    static SCRIPT_LOCATION: RefCell<SourceLocation> = RefCell::new(SourceLocation::default());
+}
+
+pub fn set_max_depth(depth: usize) {
+    MAX_DEPTH.with(|max| max.set(depth));
+}
+
+pub fn max_depth() -> usize {
+    MAX_DEPTH.with(|max| max.get())
+}
+
+/// Registra a pilha nativa da thread atual, com `size` bytes. Cada chamada do
+/// script usa uma quantidade de pilha que varia com o perfil de compilação (bem
+/// mais em debug), então `is_full` também para quando ela está perto do fim.
+/// Deve ser chamada no início da thread.
+pub fn set_native_stack(size: usize) {
+    let budget = size.saturating_sub(NATIVE_STACK_RESERVE);
+    NATIVE_STACK.with(|stack| stack.set(Some((stack_address(), budget))));
+}
+
+fn stack_address() -> usize {
+    let marker = 0u8;
+    std::ptr::addr_of!(marker) as usize
+}
+
+fn native_stack_exhausted() -> bool {
+    NATIVE_STACK.with(|stack| match stack.get() {
+        Some((start, budget)) => start.abs_diff(stack_address()) > budget,
+        None => false,
+    })
+}
+
+/// Verdadeiro quando a pilha passou do limite configurado ou a pilha nativa
+/// está no fim. Conferido ao entrar em cada função, já com o frame empilhado.
+pub fn is_full() -> bool {
+    depth() > max_depth() || native_stack_exhausted()
+}
+
+/// Executa `f` com uma folga temporária nos limites, para criar o erro de estouro.
+pub fn with_headroom<T>(f: impl FnOnce() -> T) -> T {
+    let max = max_depth();
+    let native = NATIVE_STACK.with(Cell::get);
+    set_max_depth(max + OVERFLOW_HEADROOM);
+    NATIVE_STACK.with(|stack| {
+        stack.set(native.map(|(start, budget)| (start, budget + NATIVE_STACK_RESERVE / 2)))
+    });
+    let result = f();
+    set_max_depth(max);
+    NATIVE_STACK.with(|stack| stack.set(native));
+    result
+}
+
+/// Atualiza a posição do frame em execução. Chamado a cada statement e de novo
+/// em chamadas e `new`, que podem estar em outra linha de um statement longo.
+pub fn set_location(location: &SourceLocation) {
+    // AST gerada em Rust não tem posição
+    if location.file.is_empty() {
+        return;
+    }
+    CALL_STACK.with(|stack| match stack.borrow_mut().last_mut() {
+        Some(frame) => frame.location = location.clone(),
+        None => SCRIPT_LOCATION.with(|script| *script.borrow_mut() = location.clone()),
+    });
+}
+
+/// Posição atual do frame em execução.
+pub fn current_location() -> SourceLocation {
+    CALL_STACK.with(|stack| match stack.borrow().last() {
+        Some(frame) => frame.location.clone(),
+        None => SCRIPT_LOCATION.with(|script| script.borrow().clone()),
+    })
+}
+
+/// Entra em `function`, chamada em `location`. Até avaliar a própria primeira
+/// chamada, o novo frame aponta para a linha de onde foi chamado.
+pub fn push(function: String, location: SourceLocation) {
+    set_location(&location);
+    CALL_STACK.with(|stack| {
+        stack.borrow_mut().push(StackFrame {
+            function,
+            location,
+            tail_calls: 0,
+            tail_caller: None,
+        })
+    });
+}
+
+/// Substitui o frame atual, usado quando uma chamada de cauda reaproveita o frame.
+/// O frame substituído não aparece mais no trace, mas fica registrado como
+/// `... tail call from f` logo abaixo do novo.
+pub fn replace_top(function: String, location: SourceLocation) {
+    CALL_STACK.with(|stack| {
+        if let Some(frame) = stack.borrow_mut().last_mut() {
+            let caller = std::mem::replace(&mut frame.function, function);
+            frame.location = location;
+            frame.tail_calls += 1;
+            frame.tail_caller = Some(caller);
+        }
+    });
+}
+
+pub fn pop() {
+    CALL_STACK.with(|stack| stack.borrow_mut().pop());
+}
+
+pub fn depth() -> usize {
+    CALL_STACK.with(|stack| stack.borrow().len())
+}
+
+/// Quantidade máxima de frames exibidos em um stack trace.
+const MAX_PRINTED_FRAMES: usize = 50;
+
+/// `    ... tail call from f`, ou `    ... 3 tail calls, last from f`.
+fn tail_call_note(frame: &StackFrame) -> Option<String> {
+    let caller = frame.tail_caller.as_ref()?;
+    Some(match frame.tail_calls {
+        1 => format!("    ... tail call from {caller}"),
+        count => format!("    ... {count} tail calls, last from {caller}"),
+    })
+}
+
+/// Formata a pilha atual, do frame mais recente ao mais antigo:
+/// `    at inner (main.x:3)`, com a posição atual de cada frame (onde o erro
+/// surgiu no primeiro, a chamada pendente nos demais) e por último o arquivo.
+/// Frames substituídos por chamadas de cauda aparecem como uma nota.
+pub fn format_stack() -> String {
+    let script = SCRIPT_LOCATION.with(|script| script.borrow().clone());
+    CALL_STACK.with(|stack| {
+        let stack = stack.borrow();
+        let mut frames = stack
+            .iter()
+            .rev()
+            .map(|frame| {
+                let note = tail_call_note(frame);
+                (frame.function.as_str(), &frame.location, note)
+            })
+            .collect::<Vec<_>>();
+        if !script.file.is_empty() {
+            frames.push(("<main>", &script, None));
+        }
+        let mut lines = vec![];
+        for (function, location, note) in frames.iter().take(MAX_PRINTED_FRAMES) {
+            lines.push(format!("    at {function} ({location})"));
+            lines.extend(note.clone());
+        }
+        if frames.len() > MAX_PRINTED_FRAMES {
+            lines.push(format!(
+                "    ... {} more frames",
+                frames.len() - MAX_PRINTED_FRAMES
+            ));
+        }
+        lines.join("\n")
+    })
+}
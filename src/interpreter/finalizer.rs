@@ -0,0 +1,82 @@
+//! Destrutores `drop(self)`. Quando a última referência a uma instância some, o
+//! `Drop` de `Instance` enfileira o objeto aqui; os métodos rodam depois, entre
+//! statements, já que o valor pode morrer com ambientes emprestados.
+
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+use crate::environment::{
+    values::{Function, Instance, Value},
+    Environment,
+};
+
+thread_local! {
+    static PENDING: RefCell<VecDeque<(Rc<Function>, Value)>> = const { RefCell::new(VecDeque::new()) };
+}
+
+/// Chamado pelo `Drop` de `Instance`. O conteúdo vai para uma instância nova, já
+/// marcada como finalizada: `drop` roda uma única vez, mesmo se guardar `self`.
+pub fn schedule(instance: &mut Instance) {
+    if instance.finalized || std::thread::panicking() {
+        return;
+    }
+    let Some(method) = instance.class.find_method("drop") else {
+        return;
+    };
+    let this = std::mem::replace(
+        &mut instance.this,
+        Rc::new(RefCell::new(Environment {
+            variables: vec![],
+            parent: None,
+            deferred: vec![],
+        })),
+    );
+    let revived = Instance {
+        class: instance.class.clone(),
+        this,
+        finalized: true,
+    };
+    let revived = Value::Instance(Rc::new(RefCell::new(revived)));
+    // Na destruição da thread a fila pode já não existir
+    let _ = PENDING.try_with(|pending| pending.borrow_mut().push_back((method, revived)));
+}
+
+/// Agenda `drop(self)` para uma instância ainda viva, usada pelo coletor de ciclos
+/// antes de esvaziar o lixo. Retorna `false` se não há destrutor a chamar.
+pub fn schedule_live(instance: &Rc<RefCell<Instance>>) -> bool {
+    let Ok(mut borrowed) = instance.try_borrow_mut() else {
+        return false;
+    };
+    if borrowed.finalized {
+        return false;
+    }
+    let Some(method) = borrowed.class.find_method("drop") else {
+        return false;
+    };
+    borrowed.finalized = true;
+    drop(borrowed);
+    let value = Value::Instance(instance.clone());
+    PENDING.with(|pending| pending.borrow_mut().push_back((method, value)));
+    true
+}
+
+/// Executa os destrutores pendentes, inclusive os de objetos liberados por eles.
+/// Erros em `drop` não têm para onde propagar e viram avisos.
+pub fn run_pending() {
+    loop {
+        let next = PENDING
+            .try_with(|pending| pending.borrow_mut().pop_front())
+            .ok()
+            .flatten();
+        let Some((method, instance)) = next else {
+            return;
+        };
+        let class = match &instance {
+            Value::Instance(instance) => instance.borrow().class.name.clone(),
+            _ => String::new(),
+        };
+        let result = method.call(vec![instance]);
+        if let Value::Error(error) = result {
+            eprintln!("warning: error in {class}.drop(): {}", error.borrow());
+        }
+    }
+}
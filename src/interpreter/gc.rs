@@ -0,0 +1,511 @@
+//! Coletor de ciclos. Arrays, objetos, instâncias e ambientes são `Rc`, então
+//! estruturas que se referenciam nunca chegam a zero. `collect` usa eliminação
+//! experimental: conta quantas referências a cada nó vêm de dentro do grafo
+//! rastreado; o que sobra do `strong_count` vem de fora (variáveis vivas, a pilha
+//! Rust) e marca raízes. O que não é alcançável a partir delas é lixo e tem o
+//! conteúdo esvaziado, quebrando o ciclo.
+//!
+//! Referências que o coletor não percorre (classes, erros, módulos nativos)
+//! contam como externas: no pior caso um ciclo não é coletado, nunca o contrário.
+//! A exceção são os valores de `WeakMap`, tratados como efêmeros: a referência
+//! do mapa conta como interna e o valor só é alcançável depois da chave.
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet},
+    rc::{Rc, Weak},
+};
+
+use crate::{
+    environment::{
+        stdlib::{weakmap::WeakEntries, weakref::WeakTarget},
+        values::{Function, Instance, Value},
+        Environment,
+    },
+    interpreter::finalizer,
+};
+
+type Entries = Vec<(String, Value)>;
+
+/// Tamanho mínimo do registro antes de descartar as entradas mortas.
+const MIN_PRUNE_AT: usize = 1024;
+
+#[derive(Debug, Clone, Default)]
+pub struct GcStats {
+    pub collections: usize,
+    pub collected: usize,      // total de arrays, objetos e instâncias liberados
+    pub last_collected: usize, // liberados pela última coleta
+}
+
+thread_local! {
+    static TRACKED: RefCell<Vec<WeakNode>> = const { RefCell::new(Vec::new()) };
+    static PRUNE_AT: Cell<usize> = const { Cell::new(MIN_PRUNE_AT) };
+    static STATS: RefCell<GcStats> = RefCell::new(GcStats::default());
+    static WEAK_MAPS: RefCell<Vec<Weak<RefCell<WeakEntries>>>> = const { RefCell::new(Vec::new()) };
+}
+
+enum WeakNode {
+    Array(Weak<RefCell<Vec<Value>>>),
+    Object(Weak<RefCell<Entries>>),
+    Instance(Weak<RefCell<Instance>>),
+    Env(Weak<RefCell<Environment>>),
+}
+
+impl WeakNode {
+    fn upgrade(&self) -> Option<Node> {
+        match self {
+            WeakNode::Array(weak) => weak.upgrade().map(Node::Array),
+            WeakNode::Object(weak) => weak.upgrade().map(Node::Object),
+            WeakNode::Instance(weak) => weak.upgrade().map(Node::Instance),
+            WeakNode::Env(weak) => weak.upgrade().map(Node::Env),
+        }
+    }
+
+    fn address(&self) -> usize {
+        match self {
+            WeakNode::Array(weak) => weak.as_ptr() as *const () as usize,
+            WeakNode::Object(weak) => weak.as_ptr() as *const () as usize,
+            WeakNode::Instance(weak) => weak.as_ptr() as *const () as usize,
+            WeakNode::Env(weak) => weak.as_ptr() as *const () as usize,
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        match self {
+            WeakNode::Array(weak) => weak.strong_count() > 0,
+            WeakNode::Object(weak) => weak.strong_count() > 0,
+            WeakNode::Instance(weak) => weak.strong_count() > 0,
+            WeakNode::Env(weak) => weak.strong_count() > 0,
+        }
+    }
+
+    /// Ambientes não entram na contagem de objetos do script.
+    fn is_container(&self) -> bool {
+        !matches!(self, WeakNode::Env(_))
+    }
+}
+
+/// Nó do grafo. Funções não são registradas na criação, mas entram no grafo
+/// quando alcançadas, já que closures são o caminho comum de um ciclo.
+enum Node {
+    Array(Rc<RefCell<Vec<Value>>>),
+    Object(Rc<RefCell<Entries>>),
+    Instance(Rc<RefCell<Instance>>),
+    Env(Rc<RefCell<Environment>>),
+    Function(Rc<Function>),
+}
+
+impl Node {
+    fn address(&self) -> usize {
+        match self {
+            Node::Array(rc) => Rc::as_ptr(rc) as *const () as usize,
+            Node::Object(rc) => Rc::as_ptr(rc) as *const () as usize,
+            Node::Instance(rc) => Rc::as_ptr(rc) as *const () as usize,
+            Node::Env(rc) => Rc::as_ptr(rc) as *const () as usize,
+            Node::Function(rc) => Rc::as_ptr(rc) as *const () as usize,
+        }
+    }
+
+    fn strong_count(&self) -> usize {
+        match self {
+            Node::Array(rc) => Rc::strong_count(rc),
+            Node::Object(rc) => Rc::strong_count(rc),
+            Node::Instance(rc) => Rc::strong_count(rc),
+            Node::Env(rc) => Rc::strong_count(rc),
+            Node::Function(rc) => Rc::strong_count(rc),
+        }
+    }
+
+    fn is_container(&self) -> bool {
+        matches!(self, Node::Array(_) | Node::Object(_) | Node::Instance(_))
+    }
+
+    /// Cada `Rc` que o nó guarda diretamente. `None` se o nó está emprestado para
+    /// escrita; nesse caso ele é tratado como raiz.
+    fn children(&self) -> Option<Vec<Node>> {
+        let mut children = vec![];
+        match self {
+            Node::Array(rc) => {
+                for value in rc.try_borrow().ok()?.iter() {
+                    value_children(value, &mut children);
+                }
+            }
+            Node::Object(rc) => {
+                for (_, value) in rc.try_borrow().ok()?.iter() {
+                    value_children(value, &mut children);
+                }
+            }
+            Node::Instance(rc) => {
+                children.push(Node::Env(rc.try_borrow().ok()?.this.clone()));
+            }
+            Node::Env(rc) => {
+                let env = rc.try_borrow().ok()?;
+                for (_, value) in &env.variables {
+                    value_children(value, &mut children);
+                }
+                if let Some(parent) = &env.parent {
+                    children.push(Node::Env(parent.clone()));
+                }
+            }
+            Node::Function(function) => {
+                children.push(Node::Env(function.environment.clone()));
+                value_children(&function.this, &mut children);
+                for value in &function.bound_args {
+                    value_children(value, &mut children);
+                }
+            }
+        }
+        Some(children)
+    }
+
+    /// Esvazia o nó e devolve o conteúdo, que só é liberado depois de soltar os
+    /// empréstimos (os destrutores podem tocar em outros nós).
+    fn clear(&self, released: &mut Vec<Value>, parents: &mut Vec<Rc<RefCell<Environment>>>) {
+        match self {
+            Node::Array(rc) => {
+                if let Ok(mut items) = rc.try_borrow_mut() {
+                    released.append(&mut items);
+                }
+            }
+            Node::Object(rc) => {
+                if let Ok(mut entries) = rc.try_borrow_mut() {
+                    released.extend(entries.drain(..).map(|(_, value)| value));
+                }
+            }
+            Node::Env(rc) => {
+                if let Ok(mut env) = rc.try_borrow_mut() {
+                    released.extend(env.variables.drain(..).map(|(_, value)| value));
+                    parents.extend(env.parent.take());
+                }
+            }
+            // O ambiente `this` da instância e o da função também são nós
+            Node::Instance(_) | Node::Function(_) => {}
+        }
+    }
+}
+
+fn value_children(value: &Value, children: &mut Vec<Node>) {
+    match value {
+        Value::Array(array) => {
+            if let Some(items) = &array.value {
+                children.push(Node::Array(items.clone()));
+            }
+        }
+        Value::Object(entries) => children.push(Node::Object(entries.clone())),
+        Value::Instance(instance) => children.push(Node::Instance(instance.clone())),
+        Value::Function(function) => children.push(Node::Function(function.clone())),
+        _ => {}
+    }
+}
+
+/// O nó de uma chave de `WeakMap`, sem passar por `Value` (criar um array o registraria).
+fn target_node(target: &WeakTarget) -> Option<Node> {
+    match target {
+        WeakTarget::Instance(weak) => weak.upgrade().map(Node::Instance),
+        WeakTarget::Object(weak) => weak.upgrade().map(Node::Object),
+        WeakTarget::Array(weak) => weak.upgrade().map(Node::Array),
+    }
+}
+
+fn track(node: WeakNode) {
+    let prune = TRACKED.with(|tracked| {
+        let mut tracked = tracked.borrow_mut();
+        tracked.push(node);
+        tracked.len() >= PRUNE_AT.with(|at| at.get())
+    });
+    if prune {
+        prune_tracked();
+    }
+}
+
+pub fn track_array(items: &Rc<RefCell<Vec<Value>>>) {
+    track(WeakNode::Array(Rc::downgrade(items)));
+}
+
+pub fn track_object(entries: &Rc<RefCell<Entries>>) {
+    track(WeakNode::Object(Rc::downgrade(entries)));
+}
+
+pub fn track_instance(instance: &Rc<RefCell<Instance>>) {
+    track(WeakNode::Instance(Rc::downgrade(instance)));
+}
+
+pub fn track_env(env: &Rc<RefCell<Environment>>) {
+    track(WeakNode::Env(Rc::downgrade(env)));
+}
+
+pub fn track_weak_map(entries: &Rc<RefCell<WeakEntries>>) {
+    WEAK_MAPS.with(|maps| {
+        let mut maps = maps.borrow_mut();
+        maps.retain(|map| map.strong_count() > 0);
+        maps.push(Rc::downgrade(entries));
+    });
+}
+
+/// Remove entradas mortas e repetidas. Um `Weak` mantém a alocação do `Rc`,
+/// então o registro não pode crescer sem limite.
+fn prune_tracked() {
+    let _ = TRACKED.try_with(|tracked| {
+        let mut tracked = tracked.borrow_mut();
+        let mut seen = HashMap::new();
+        tracked.retain(|node| node.is_alive() && seen.insert(node.address(), ()).is_none());
+        let next = (tracked.len() * 2).max(MIN_PRUNE_AT);
+        PRUNE_AT.with(|at| at.set(next));
+    });
+}
+
+/// Entrada de `WeakMap` cuja chave está viva: os nós do valor só são alcançáveis
+/// a partir da chave.
+struct Ephemeron {
+    key: usize,
+    values: Vec<usize>,
+}
+
+/// Nós do grafo e, para cada um, os índices dos filhos.
+struct Graph {
+    nodes: Vec<Node>,
+    edges: Vec<Vec<usize>>,
+    opaque: Vec<bool>, // não foi possível ler os filhos
+    ephemerons: Vec<Ephemeron>,
+}
+
+impl Graph {
+    fn build() -> Graph {
+        let mut graph = Graph {
+            nodes: vec![],
+            edges: vec![],
+            opaque: vec![],
+            ephemerons: vec![],
+        };
+        let mut index = HashMap::new();
+
+        let seeds = TRACKED.with(|tracked| {
+            tracked
+                .borrow()
+                .iter()
+                .filter_map(WeakNode::upgrade)
+                .collect::<Vec<_>>()
+        });
+        for node in seeds {
+            graph.insert(node, &mut index);
+        }
+        graph.insert_ephemerons(&mut index);
+
+        let mut current = 0;
+        while current < graph.nodes.len() {
+            match graph.nodes[current].children() {
+                Some(children) => {
+                    for child in children {
+                        let child = graph.insert(child, &mut index);
+                        graph.edges[current].push(child);
+                    }
+                }
+                None => graph.opaque[current] = true,
+            }
+            current += 1;
+        }
+        graph
+    }
+
+    /// Um mapa emprestado para escrita fica de fora: seus valores contam como
+    /// referências externas, como os de qualquer nó opaco.
+    fn insert_ephemerons(&mut self, index: &mut HashMap<usize, usize>) {
+        let maps = WEAK_MAPS.with(|maps| {
+            maps.borrow()
+                .iter()
+                .filter_map(Weak::upgrade)
+                .collect::<Vec<_>>()
+        });
+        for map in maps {
+            let Ok(entries) = map.try_borrow() else {
+                continue;
+            };
+            for (target, value) in entries.iter() {
+                let Some(key) = target_node(target) else {
+                    continue;
+                };
+                let key = self.insert(key, index);
+                let mut children = vec![];
+                value_children(value, &mut children);
+                let values = children
+                    .into_iter()
+                    .map(|child| self.insert(child, index))
+                    .collect();
+                self.ephemerons.push(Ephemeron { key, values });
+            }
+        }
+    }
+
+    fn insert(&mut self, node: Node, index: &mut HashMap<usize, usize>) -> usize {
+        *index.entry(node.address()).or_insert_with(|| {
+            self.nodes.push(node);
+            self.edges.push(vec![]);
+            self.opaque.push(false);
+            self.nodes.len() - 1
+        })
+    }
+
+    /// Índices dos nós que não são alcançáveis a partir de referências externas.
+    fn garbage(&self) -> Vec<usize> {
+        let mut internal = vec![0; self.nodes.len()];
+        for children in &self.edges {
+            for &child in children {
+                internal[child] += 1;
+            }
+        }
+        for ephemeron in &self.ephemerons {
+            for &value in &ephemeron.values {
+                internal[value] += 1;
+            }
+        }
+
+        let mut reachable = vec![false; self.nodes.len()];
+        let mut pending = vec![];
+        for (index, node) in self.nodes.iter().enumerate() {
+            // Uma referência é a do próprio grafo
+            let external = node.strong_count().saturating_sub(1) > internal[index];
+            if external || self.opaque[index] {
+                reachable[index] = true;
+                pending.push(index);
+            }
+        }
+        // Marca até não sobrar efêmero com chave alcançável e valor por marcar
+        loop {
+            while let Some(index) = pending.pop() {
+                for &child in &self.edges[index] {
+                    if !reachable[child] {
+                        reachable[child] = true;
+                        pending.push(child);
+                    }
+                }
+            }
+            for ephemeron in &self.ephemerons {
+                if reachable[ephemeron.key] {
+                    for &value in &ephemeron.values {
+                        if !reachable[value] {
+                            reachable[value] = true;
+                            pending.push(value);
+                        }
+                    }
+                }
+            }
+            if pending.is_empty() {
+                break;
+            }
+        }
+
+        (0..self.nodes.len())
+            .filter(|&index| !reachable[index])
+            .collect()
+    }
+}
+
+/// Chama `drop(self)` nas instâncias do lixo que ainda não foram finalizadas.
+/// Retorna `true` se algum destrutor rodou (e pode ter ressuscitado objetos).
+fn finalize(graph: &Graph, garbage: &[usize]) -> bool {
+    let mut finalized = false;
+    for &index in garbage {
+        let Node::Instance(instance) = &graph.nodes[index] else {
+            continue;
+        };
+        if finalizer::schedule_live(instance) {
+            finalized = true;
+        }
+    }
+    finalized
+}
+
+/// Coleta os ciclos inalcançáveis e retorna quantos arrays, objetos e instâncias
+/// foram liberados.
+pub fn collect() -> usize {
+    // Valores de chaves já liberadas não podem contar como referências externas
+    let mut stale = vec![];
+    remove_dead_keys(&HashSet::new(), &mut stale);
+    drop(stale);
+
+    let mut graph = Graph::build();
+    let mut garbage = graph.garbage();
+
+    // Destrutores podem guardar `self` em algum lugar vivo: refaz a análise
+    if finalize(&graph, &garbage) {
+        drop(graph);
+        finalizer::run_pending();
+        graph = Graph::build();
+        garbage = graph.garbage();
+    }
+
+    let mut released = vec![];
+    let mut parents = vec![];
+    for &index in &garbage {
+        graph.nodes[index].clear(&mut released, &mut parents);
+    }
+    let dead_keys = garbage
+        .iter()
+        .map(|&index| graph.nodes[index].address())
+        .collect::<HashSet<_>>();
+    remove_dead_keys(&dead_keys, &mut released);
+    let watched = garbage
+        .iter()
+        .map(|&index| &graph.nodes[index])
+        .filter(|node| node.is_container())
+        .map(|node| match node {
+            Node::Array(rc) => WeakNode::Array(Rc::downgrade(rc)),
+            Node::Object(rc) => WeakNode::Object(Rc::downgrade(rc)),
+            Node::Instance(rc) => WeakNode::Instance(Rc::downgrade(rc)),
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    drop(graph);
+    drop(released);
+    drop(parents);
+
+    // Conta só o que de fato foi liberado
+    let collected = watched.iter().filter(|node| !node.is_alive()).count();
+    STATS.with(|stats| {
+        let mut stats = stats.borrow_mut();
+        stats.collections += 1;
+        stats.collected += collected;
+        stats.last_collected = collected;
+    });
+    prune_tracked();
+    collected
+}
+
+/// Tira dos `WeakMap`s as entradas cujas chaves são lixo ou já morreram; os valores
+/// vão para `released` e só são liberados depois de soltar os empréstimos.
+fn remove_dead_keys(dead_keys: &HashSet<usize>, released: &mut Vec<Value>) {
+    let maps = WEAK_MAPS.with(|maps| {
+        maps.borrow()
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect::<Vec<_>>()
+    });
+    for map in maps {
+        let Ok(mut entries) = map.try_borrow_mut() else {
+            continue;
+        };
+        entries.retain_mut(|(target, value)| {
+            let dead = !target.is_alive() || dead_keys.contains(&(target.address() as usize));
+            if dead {
+                released.push(std::mem::replace(value, Value::Null));
+            }
+            !dead
+        });
+    }
+}
+
+pub fn stats() -> GcStats {
+    STATS.with(|stats| stats.borrow().clone())
+}
+
+/// Arrays, objetos e instâncias vivos no registro.
+pub fn tracked() -> usize {
+    prune_tracked();
+    TRACKED.with(|tracked| {
+        tracked
+            .borrow()
+            .iter()
+            .filter(|node| node.is_container())
+            .count()
+    })
+}
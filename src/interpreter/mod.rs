@@ -1,21 +1,53 @@
 use logos::Logos;
-use std::{cell::RefCell, collections::HashMap, env, fs, io::Write, path::Path, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    env, fs,
+    io::Write,
+    path::Path,
+    rc::Rc,
+};
 
 use crate::{
     ast::ast::{
-        debug_stmts, BinaryOperator, CompareOperator, ControlFlow, Expr, FunctionStmt, Literal,
-        LogicalOperator, MethodModifiersOperations, ObjectEntry, Operator, Stmt,
+        debug_stmts, BinaryOperator, CatchBinding, CatchClause, CompareOperator,
+        ComprehensionClause, ControlFlow, Decorator, ErrorKind, Expr, FunctionStmt, Literal,
+        LogicalOperator, MatchArm, MatchPattern, MethodDecl, MethodModifiersOperations,
+        ObjectEntry, Operator, SourceLocation, Stmt,
     },
     environment::{
         helpers::class::ClassGenerator,
-        values::{Class, Function, NativeObjectTrait, Value},
+        native::native_callable::NativeCallable,
+        stdlib::{
+            self,
+            function::NativeFunctionClass,
+            number::NativeNumberClass,
+            option::{self, NativeOptionClass},
+        },
+        values::{
+            bind_named_args, Class, ClassKind, Decoration, Function, Instance, NativeObjectTrait,
+            RequiredMethod, TailCall, Value,
+        },
         Environment,
     },
-    lexer::tokens::Token,
+    lexer::tokens::{oversized_int_literal, Token},
     parsers::code::parser::Parser,
+    typecheck::{
+        self,
+        checker::{Checker, Diagnostic},
+    },
 };
 use logos::Lexer;
 
+pub mod call_stack;
+pub mod finalizer;
+pub mod gc;
+
+thread_local! {
+    /// Locais de `match` que já emitiram o aviso de exaustividade.
+    static MATCH_WARNINGS: RefCell<HashSet<(String, usize)>> = RefCell::new(HashSet::new());
+}
+
 pub struct LexerWithLocation<'source> {
     inner: Lexer<'source, Token>,
     line: usize,
@@ -40,7 +72,13 @@ impl<'source> LexerWithLocation<'source> {
         usize,
         usize,
     )> {
-        let tok = self.inner.next()?;
+        let mut tok = self.inner.next()?;
+        // Literal inteiro grande demais para i64 vira BigInt
+        if tok.is_err() {
+            if let Some(digits) = oversized_int_literal(self.inner.slice()) {
+                tok = Ok(Token::BigInt(digits));
+            }
+        }
         let span = self.inner.span();
         let slice = &self.inner.source()[self.last_offset..span.start];
 
@@ -59,11 +97,26 @@ impl<'source> LexerWithLocation<'source> {
         Some((tok, self.line, self.col))
     }
 }
+
+/// Argumentos avaliados de uma chamada: posicionais e nomeados (`f(a: 1)`).
+type CallArgs = (Vec<Value>, HashMap<String, Value>);
+
+/// Recebe cada combinação de uma compreensão; `Some` interrompe com um erro.
+type ComprehensionEmit<'a> =
+    dyn FnMut(&mut Interpreter, &mut Rc<RefCell<Environment>>) -> Option<ControlFlow<Value>> + 'a;
+
 #[derive(Debug, Clone)]
 pub struct Interpreter {
     source: String,
     module_cache: HashMap<String, Rc<RefCell<Environment>>>,
     exported_symbols: HashMap<String, Value>,
+    tail_calls: bool,    // habilitado dentro de `Function::call`
+    tail_position: bool, // a próxima chamada é o valor de um `return`
+    try_depth: usize,    // `return f()` dentro de try não é chamada de cauda
+    pending_tail_call: Option<TailCall>,
+    in_function: bool,          // `?` só pode retornar de dentro de uma função
+    propagating: Option<Value>, // `None`/`Err` levado por `?` até `Function::call`
+    pending_defers: usize,      // `return f()` com `defer` pendente não é chamada de cauda
 }
 
 impl Interpreter {
@@ -72,6 +125,13 @@ impl Interpreter {
             source,
             module_cache: HashMap::new(),
             exported_symbols: HashMap::new(),
+            tail_calls: false,
+            tail_position: false,
+            try_depth: 0,
+            pending_tail_call: None,
+            in_function: false,
+            propagating: None,
+            pending_defers: 0,
         }
     }
 
@@ -80,11 +140,42 @@ impl Interpreter {
             source: String::new(),
             module_cache: HashMap::new(),
             exported_symbols: HashMap::new(),
+            tail_calls: false,
+            tail_position: false,
+            try_depth: 0,
+            pending_tail_call: None,
+            in_function: false,
+            propagating: None,
+            pending_defers: 0,
         }
     }
 
+    pub fn set_tail_calls(&mut self, enabled: bool) {
+        self.tail_calls = enabled;
+    }
+
+    pub fn take_tail_call(&mut self) -> Option<TailCall> {
+        self.pending_tail_call.take()
+    }
+
+    /// Interpretador do corpo de uma função: habilita o `?`.
+    pub fn enter_function(&mut self) {
+        self.in_function = true;
+    }
+
+    /// Valor que um `?` mandou retornar, se o erro recebido veio dele.
+    pub fn take_propagated(&mut self) -> Option<Value> {
+        self.propagating.take()
+    }
+
     pub fn tokenize(&self, src: String, filename: String) -> Vec<Token> {
+        self.tokenize_with_lines(src, filename).0
+    }
+
+    /// Como `tokenize`, mas também retorna a linha de cada token.
+    pub fn tokenize_with_lines(&self, src: String, filename: String) -> (Vec<Token>, Vec<usize>) {
         let mut tokens: Vec<Token> = vec![];
+        let mut lines: Vec<usize> = vec![];
         let mut lexer = LexerWithLocation::new(&src);
 
         while let Some((token, line, col)) = lexer.next() {
@@ -98,27 +189,64 @@ impl Interpreter {
                         panic!("Invalid char '{c}' at {filename}/:{line}:{col}");
                     }
                     tokens.push(token);
+                    lines.push(line);
                 }
                 Err(e) => panic!("some error occurred: {:?}", e),
             }
         }
-        tokens
+        (tokens, lines)
+    }
+
+    /// Lê e analisa um arquivo, sem executar.
+    pub fn parse_file(&self, filename: &str) -> Result<Vec<Stmt>, String> {
+        let src = fs::read_to_string(filename).map_err(|err| format!("{filename}: {err}"))?;
+        Ok(self.parser_for(src, filename.to_string()).parse())
+    }
+
+    /// Verifica as anotações de tipo de `filename` e dos módulos importados sem executá-los.
+    pub fn check(&mut self, filename: &str) -> Vec<Diagnostic> {
+        let mut prelude = vec![ClassGenerator::create_error_class()];
+        prelude.extend(ClassGenerator::create_option_enums());
+        prelude.extend(self.load_stdlib());
+        prelude.extend(
+            ErrorKind::NATIVE
+                .into_iter()
+                .map(ClassGenerator::create_native_error_class),
+        );
+
+        let mut checker = Checker::new(|path: &str| self.parse_file(path));
+        checker.declare(&prelude);
+        checker.check_module(filename);
+        let mut diagnostics = checker.into_diagnostics();
+        diagnostics.sort_by(|a, b| {
+            (&a.location.file, a.location.line).cmp(&(&b.location.file, b.location.line))
+        });
+        diagnostics
+    }
+
+    fn parser_for(&self, src: String, filename: String) -> Parser {
+        let (tokens, lines) = self.tokenize_with_lines(src, filename.clone());
+        Parser::new_with_lines(tokens, lines, filename)
     }
     pub fn interpret_from_file(&mut self, filename: String) -> Option<Value> {
         let show_ast = env::args().nth(2).unwrap_or("false".to_owned());
         let generate_classes = env::args().nth(3).unwrap_or(String::new());
         let src = fs::read_to_string(&filename).unwrap_or(self.source.clone());
 
-        let tokens = self.tokenize(src.clone(), filename.clone());
-        let mut parser = Parser::new(tokens);
+        let mut parser = self.parser_for(src.clone(), filename.clone());
 
-        // let error_class = ClassGenerator::create_error_class();
+        // Error nativo como base caso a stdlib não declare a própria classe
+        let error_class = ClassGenerator::create_error_class();
         let default_stdlib = self.load_stdlib();
-        // let default_classes = vec![error_class];
+        let native_errors = ErrorKind::NATIVE
+            .into_iter()
+            .map(ClassGenerator::create_native_error_class);
 
-        let mut ast = vec![];
+        let mut ast = vec![error_class];
 
+        ast.extend(ClassGenerator::create_option_enums());
         ast.extend(default_stdlib);
+        ast.extend(native_errors);
         ast.extend(parser.parse());
 
         // bench();
@@ -154,17 +282,25 @@ impl Interpreter {
         }
         // let mut env = self.env.clone();
         let mut env = Environment::new_rc();
+        let mut flow = ControlFlow::None;
         for stmt in ast {
             let val = self.eval_stmt(&stmt, &mut env);
-            match val {
-                ControlFlow::Error(err) => {
-                    panic!("{}", err.to_string());
-                }
-                _ => {
-                    continue;
-                }
+            if val.is_error() {
+                flow = val;
+                break;
             }
         }
+        // `defer` no nível do arquivo roda ao fim do programa, mesmo depois de um erro
+        let flow = self.exit_scope(&mut env, flow);
+        finalizer::run_pending();
+        if let ControlFlow::Error(err) = flow {
+            let stack = err
+                .own_entries()
+                .and_then(|entries| entries.into_iter().find(|(key, _)| key == "stack"))
+                .map(|(_, stack)| stack.to_string())
+                .unwrap_or_default();
+            panic!("{}\n{}", err.to_string(), stack);
+        }
         None
     }
 
@@ -177,8 +313,7 @@ impl Interpreter {
         for filename in files {
             let src = fs::read_to_string(&filename).unwrap_or(self.source.clone());
 
-            let tokens = self.tokenize(src.clone(), filename.clone());
-            let mut parser = Parser::new(tokens);
+            let mut parser = self.parser_for(src.clone(), filename.clone());
 
             ast.extend(parser.parse());
         }
@@ -195,6 +330,8 @@ impl Interpreter {
                 files.push(path.to_str().unwrap().to_string());
             }
         }
+        // Ordem estável: read_dir não garante ordem e classes dependem umas das outras
+        files.sort();
         files
     }
 
@@ -244,17 +381,27 @@ impl Interpreter {
         env: &mut Rc<RefCell<Environment>>,
     ) -> ControlFlow<Value> {
         let ret = match expr {
-            Expr::Identifier(name) => {
-                let value = self.resolve_variable(name, env);
-                if value.is_err() {
-                    return ControlFlow::new_error(env, value.unwrap_err());
+            Expr::Identifier(name) => match self.resolve_variable(name, env) {
+                Ok(value) => value,
+                Err(msg) => {
+                    return ControlFlow::new_typed_error(env, ErrorKind::ReferenceError, msg)
                 }
-                let value = value.unwrap();
-
-                value
-            }
+            },
             Expr::Literal(lit) => match lit {
                 Literal::Number(n) => Value::Number(n.into()),
+                Literal::Int(n) => Value::Number(n.into()),
+                Literal::BigInt(n) => match NativeNumberClass::parse_bigint(n) {
+                    Ok(n) => Value::Number(n),
+                    Err(err) => {
+                        return ControlFlow::new_typed_error(env, ErrorKind::SyntaxError, err)
+                    }
+                },
+                Literal::Decimal(n) => match NativeNumberClass::parse_decimal(n) {
+                    Ok(n) => Value::Number(n),
+                    Err(err) => {
+                        return ControlFlow::new_typed_error(env, ErrorKind::SyntaxError, err)
+                    }
+                },
                 Literal::Bool(b) => Value::Bool(*b),
                 Literal::String(s) => Value::String(s.clone().into()),
                 Literal::Null => Value::Null,
@@ -293,8 +440,9 @@ impl Interpreter {
                                     }
                                     _ => {
                                         // opcional: erro em tempo de execução
-                                        return ControlFlow::new_error(
+                                        return ControlFlow::new_typed_error(
                                             env,
+                                            ErrorKind::TypeError,
                                             format!("Spread operator must be used with an object"),
                                         );
                                     }
@@ -320,6 +468,7 @@ impl Interpreter {
             },
             Expr::Block(stmts) => {
                 let mut local_env = Rc::new(RefCell::new(Environment::new_enclosed(env)));
+                let mut flow = ControlFlow::None;
                 for stmt in stmts {
                     let ret = self.eval_stmt(stmt, &mut local_env);
                     match ret {
@@ -329,10 +478,13 @@ impl Interpreter {
                         // ControlFlow::Error(_) => {
                         //     return ret;
                         // }
-                        ret => return ret, // return
+                        ret => {
+                            flow = ret; // return
+                            break;
+                        }
                     };
                 }
-                return ControlFlow::None;
+                return self.exit_scope(&mut local_env, flow);
             }
             Expr::BinaryOp { op, left, right } => {
                 let l = self.eval_expr(left, env);
@@ -350,12 +502,22 @@ impl Interpreter {
 
                 match (op, l, r) {
                     (Operator::Binary(math_op), left, right) => {
-                        left.call_op(math_op.clone(), &right)
+                        match left.call_op(math_op.clone(), &right) {
+                            Ok(value) => return Self::operator_result(value),
+                            Err((kind, msg)) => {
+                                return ControlFlow::new_typed_error(env, kind, msg)
+                            }
+                        }
                     }
 
                     (Operator::Compare(comp_op), a, b) => match comp_op {
-                        CompareOperator::Eq => Value::Bool(a == b),
-                        CompareOperator::Ne => Value::Bool(a != b),
+                        _ if Self::is_overloaded_compare(comp_op, &a, &b) => {
+                            return Self::overloaded_compare(comp_op, &a, &b, env)
+                        }
+                        CompareOperator::Eq => Value::Bool(a.loose_equal(&b)),
+                        CompareOperator::Ne => Value::Bool(!a.loose_equal(&b)),
+                        CompareOperator::StrictEq => Value::Bool(a.strict_equal(&b)),
+                        CompareOperator::StrictNe => Value::Bool(!a.strict_equal(&b)),
                         CompareOperator::Gt => Value::Bool(a > b),
                         CompareOperator::Ge => Value::Bool(a >= b),
                         CompareOperator::Lt => Value::Bool(a < b),
@@ -363,6 +525,27 @@ impl Interpreter {
                         CompareOperator::InstanceOf => {
                             return ControlFlow::Return(Value::Bool(Class::is_instance_of(&a, &b)));
                         }
+                        CompareOperator::Implements => match (&a, &b) {
+                            (Value::Class(class), Value::Class(interface))
+                                if interface.is_interface() =>
+                            {
+                                Value::Bool(class.implements(interface))
+                            }
+                            (_, Value::Class(interface)) if interface.is_interface() => {
+                                Value::Bool(Class::is_instance_of(&a, &b))
+                            }
+                            _ => {
+                                let msg = format!(
+                                    "Right-hand side of 'implements' must be an interface. got: {}",
+                                    b.to_string()
+                                );
+                                return ControlFlow::new_typed_error(
+                                    env,
+                                    ErrorKind::TypeError,
+                                    msg,
+                                );
+                            }
+                        },
                         CompareOperator::In => match (&a, &b) {
                             (Value::String(a), Value::Object(b)) => {
                                 let b = b.borrow();
@@ -376,8 +559,9 @@ impl Interpreter {
                                 Value::Bool(item.is_some())
                             }
                             _ => {
-                                return ControlFlow::new_error(
+                                return ControlFlow::new_typed_error(
                                     env,
+                                    ErrorKind::TypeError,
                                     format!("Invalid operands for 'in': {:?} and {:?}", a, b),
                                 )
                             }
@@ -396,8 +580,9 @@ impl Interpreter {
                     },
 
                     _ => {
-                        return ControlFlow::new_error(
+                        return ControlFlow::new_typed_error(
                             env,
+                            ErrorKind::TypeError,
                             format!("Operation not suported: {op:?}"),
                         )
                     } // fallback
@@ -410,26 +595,40 @@ impl Interpreter {
                 }
                 let val = val.unwrap();
                 match op {
+                    crate::ast::ast::UnaryOperator::Negative if val.has_operator("neg") => {
+                        return Self::operator_result(val.call_operator("neg", vec![]).unwrap())
+                    }
+                    crate::ast::ast::UnaryOperator::Not if val.has_operator("not") => {
+                        return Self::operator_result(val.call_operator("not", vec![]).unwrap())
+                    }
                     crate::ast::ast::UnaryOperator::Negative => {
                         Value::Number((-val.to_number()).into())
                     }
                     crate::ast::ast::UnaryOperator::Not => Value::Bool(!val.to_bool()),
                     crate::ast::ast::UnaryOperator::Typeof => Value::String(val.type_of().into()),
                     crate::ast::ast::UnaryOperator::Increment => {
-                        let new_val = val.to_number() + 1.0;
+                        let new_val = match val
+                            .to_native_number()
+                            .checked_binary(&BinaryOperator::Add, &1.into())
+                        {
+                            Ok(value) => value,
+                            Err((kind, msg)) => {
+                                return ControlFlow::new_typed_error(env, kind, msg)
+                            }
+                        };
                         match expr.as_ref() {
                             Expr::Identifier(name) => {
                                 let name = name.clone();
                                 let previous_val = env.borrow().get(&name).unwrap();
 
                                 env.borrow_mut()
-                                    .assign(&name, Value::Number(new_val.into()))
+                                    .assign(&name, Value::Number(new_val.clone()))
                                     .unwrap();
 
                                 if *postfix {
                                     previous_val
                                 } else {
-                                    Value::Number(new_val.into())
+                                    Value::Number(new_val.clone())
                                 }
                             }
                             Expr::Literal(literal) => match literal {
@@ -443,8 +642,9 @@ impl Interpreter {
                                     }
                                 }
                                 _ => {
-                                    return ControlFlow::new_error(
+                                    return ControlFlow::new_typed_error(
                                         env,
+                                        ErrorKind::TypeError,
                                         format!("Invalid value for increment: {:?}", expr).into(),
                                     )
                                 }
@@ -466,7 +666,7 @@ impl Interpreter {
                                         })
                                     },
                                     op: crate::ast::ast::AssignOperator::AddAssign,
-                                    value: Box::new(Expr::Literal(Literal::Number(new_val))),
+                                    value: Box::new(Expr::Literal(Literal::Int(1))),
                                 };
 
                                 let _ = self.eval_expr(expr, env);
@@ -475,31 +675,40 @@ impl Interpreter {
                                     return previous_val;
                                 }
 
-                                return ControlFlow::Return(Value::Number((new_val).into()));
+                                return ControlFlow::Return(Value::Number(new_val));
                             }
                             _ => {
-                                return ControlFlow::new_error(
+                                return ControlFlow::new_typed_error(
                                     env,
+                                    ErrorKind::TypeError,
                                     format!("Invalid operand for increment: {:?}", expr).into(),
                                 )
                             }
                         }
                     }
                     crate::ast::ast::UnaryOperator::Decrement => {
-                        let new_val = val.to_number() - 1.0;
+                        let new_val = match val
+                            .to_native_number()
+                            .checked_binary(&BinaryOperator::Subtract, &1.into())
+                        {
+                            Ok(value) => value,
+                            Err((kind, msg)) => {
+                                return ControlFlow::new_typed_error(env, kind, msg)
+                            }
+                        };
 
                         match expr.as_ref() {
                             Expr::Identifier(name) => {
                                 let name = name.clone();
                                 let previous_val = env.borrow().get(&name).unwrap();
                                 env.borrow_mut()
-                                    .assign(&name, Value::Number(new_val.into()))
+                                    .assign(&name, Value::Number(new_val.clone()))
                                     .unwrap();
 
                                 if *postfix {
                                     previous_val
                                 } else {
-                                    Value::Number(new_val.into())
+                                    Value::Number(new_val.clone())
                                 }
                             }
                             Expr::Literal(literal) => match literal {
@@ -513,8 +722,9 @@ impl Interpreter {
                                     }
                                 }
                                 _ => {
-                                    return ControlFlow::new_error(
+                                    return ControlFlow::new_typed_error(
                                         env,
+                                        ErrorKind::TypeError,
                                         format!("Invalid value for increment: {:?}", expr).into(),
                                     )
                                 }
@@ -536,7 +746,7 @@ impl Interpreter {
                                         })
                                     },
                                     op: crate::ast::ast::AssignOperator::SubAssign,
-                                    value: Box::new(Expr::Literal(Literal::Number(new_val))),
+                                    value: Box::new(Expr::Literal(Literal::Int(1))),
                                 };
 
                                 let _ = self.eval_expr(expr, env);
@@ -545,11 +755,12 @@ impl Interpreter {
                                     return previous_val;
                                 }
 
-                                return ControlFlow::Return(Value::Number((new_val).into()));
+                                return ControlFlow::Return(Value::Number(new_val));
                             }
                             _ => {
-                                return ControlFlow::new_error(
+                                return ControlFlow::new_typed_error(
                                     env,
+                                    ErrorKind::TypeError,
                                     format!("Invalid operand for increment: {:?}", expr).into(),
                                 )
                             }
@@ -560,55 +771,24 @@ impl Interpreter {
                     }
                 }
             }
-            Expr::Call { callee, args } => {
+            Expr::Call {
+                callee,
+                args,
+                location,
+            } => {
+                call_stack::set_location(location);
+                // Consumido antes de avaliar callee e argumentos, que podem conter outras chamadas
+                let is_tail_call = std::mem::take(&mut self.tail_position);
                 let evaluated_callee = self.eval_expr(callee, env);
 
                 if evaluated_callee.is_error() {
                     return evaluated_callee;
                 }
 
-                let mut evaluated_args = vec![];
-
-                for arg_expr in args {
-                    match arg_expr {
-                        Expr::Spread(inner_expr) => {
-                            let val = self.eval_expr(&inner_expr, env);
-                            if val.is_error() {
-                                return val;
-                            }
-                            let val = val.unwrap();
-
-                            match val {
-                                Value::Array(arr) => {
-                                    let arr = arr.get_value().clone();
-                                    evaluated_args.extend(arr.borrow().clone());
-                                }
-                                Value::Object(map) => {
-                                    let map = map.borrow().clone();
-                                    for (_, v) in map {
-                                        evaluated_args.push(v);
-                                    }
-                                }
-                                _ => {
-                                    return ControlFlow::new_error(
-                                        env,
-                                        format!("Cannot spread {:?}", val.type_of()).into(),
-                                    )
-                                }
-                            }
-                        }
-                        _ => {
-                            let val = self.eval_expr(&arg_expr, env);
-
-                            if val.is_err() {
-                                return val;
-                            }
-
-                            let val = val.unwrap();
-                            evaluated_args.push(val);
-                        }
-                    }
-                }
+                let (evaluated_args, named_args) = match self.eval_call_args(args, env) {
+                    Ok(args) => args,
+                    Err(err) => return err,
+                };
 
                 if evaluated_callee.is_err() {
                     return evaluated_callee;
@@ -616,17 +796,94 @@ impl Interpreter {
                 let evaluated_callee = evaluated_callee.unwrap();
                 match evaluated_callee {
                     Value::Function(func) => {
+                        let evaluated_args = match bind_named_args(
+                            &func.name,
+                            func.explicit_params(),
+                            evaluated_args,
+                            named_args,
+                        ) {
+                            Ok(args) => args,
+                            Err(err) => {
+                                return ControlFlow::new_typed_error(env, ErrorKind::TypeError, err)
+                            }
+                        };
+                        if is_tail_call {
+                            self.pending_tail_call = Some(TailCall {
+                                function: func,
+                                args: evaluated_args,
+                                location: location.clone(),
+                            });
+                            return ControlFlow::Return(Value::Void);
+                        }
+                        call_stack::push(func.qualified_name(), location.clone());
                         let call = func.call(evaluated_args);
+                        call_stack::pop();
 
-                        call
+                        match call {
+                            Value::Error(error) => {
+                                return ControlFlow::Error(error.borrow().clone())
+                            }
+                            call => call,
+                        }
                     }
 
-                    Value::Builtin(func) => func(evaluated_args),
+                    // Nomes só são aceitos por funções do script, variantes e métodos nativos
+                    Value::Builtin(_) | Value::Instance(_) | Value::InternalClass(_)
+                        if !named_args.is_empty() =>
+                    {
+                        return ControlFlow::new_typed_error(
+                            env,
+                            ErrorKind::TypeError,
+                            format!(
+                                "'{}' does not accept named arguments",
+                                self.resolve_calle_name(callee)
+                            ),
+                        )
+                    }
+                    Value::Builtin(func) => match func(evaluated_args) {
+                        // Builtins sinalizam argumentos inválidos com a mensagem em `Value::Error`
+                        Value::Error(msg) => {
+                            let msg = msg.borrow().to_string();
+                            return ControlFlow::new_typed_error(env, ErrorKind::TypeError, msg);
+                        }
+                        value => value,
+                    },
+                    // Instância chamável: obj(args) executa `operator call(self, ...args)`
+                    instance @ Value::Instance(_) if instance.has_operator("call") => {
+                        return Self::operator_result(
+                            instance.call_operator("call", evaluated_args).unwrap(),
+                        );
+                    }
+                    // Construtor de variante de enum: Color.Rgb(1, 2, 3)
+                    Value::Class(class) if class.variant_fields().is_some() => {
+                        let fields = class.variant_fields().unwrap();
+                        let name = class.qualified_name();
+                        let args = bind_named_args(&name, fields, evaluated_args, named_args);
+                        match args.and_then(|args| Class::instantiate_variant(&class, args)) {
+                            Ok(value) => value,
+                            Err(err) => {
+                                return ControlFlow::new_typed_error(env, ErrorKind::TypeError, err)
+                            }
+                        }
+                    }
+                    // Classes nativas podem ser chamadas como funções de conversão: BigInt("10")
+                    Value::InternalClass(native_class) => {
+                        match native_class.borrow().instantiate(evaluated_args) {
+                            Ok(value) => value,
+                            Err(err) => return ControlFlow::new_error(env, err),
+                        }
+                    }
                     Value::InternalFunction((name, native_class)) => {
                         let mut new_args = native_class.borrow().get_args();
                         let is_static = native_class.borrow().is_static();
 
-                        let call = if is_static {
+                        let call = if !named_args.is_empty() {
+                            native_class.borrow().call_with_named_args(
+                                &name,
+                                evaluated_args,
+                                named_args,
+                            )
+                        } else if is_static {
                             native_class.borrow().call_with_args(&name, evaluated_args)
                         } else {
                             // concat new_args and arg_values
@@ -641,18 +898,28 @@ impl Interpreter {
                             native_method
                         };
 
+                        let kind = native_class.borrow().error_kind();
+                        if kind == ErrorKind::IoError && option::io_results() {
+                            return Self::io_result(call, env);
+                        }
+
                         match call {
+                            // Erro lançado por código do usuário chamado pelo método nativo
+                            ControlFlow::Return(Value::Error(error)) => {
+                                return ControlFlow::Error(error.borrow().clone())
+                            }
                             ControlFlow::Return(_) => return call,
                             ControlFlow::Error(val) => {
-                                return ControlFlow::new_error(env, val.into());
+                                return ControlFlow::new_typed_error(env, kind, val.into());
                             }
                             ControlFlow::None => return ControlFlow::None,
                             _ => return ControlFlow::new_error(env, "Not allowed".into()),
                         }
                     }
                     _ => {
-                        return ControlFlow::new_error(
+                        return ControlFlow::new_typed_error(
                             env,
+                            ErrorKind::TypeError,
                             format!("'{}' is not a function", self.resolve_calle_name(callee))
                                 .into(),
                         )
@@ -682,8 +949,15 @@ impl Interpreter {
 
                                 match (&old_value, &new_value) {
                                     (Value::Number(a), Value::Number(b)) => {
+                                        let result = match a.checked_binary(&BinaryOperator::Add, b)
+                                        {
+                                            Ok(value) => value,
+                                            Err((kind, msg)) => {
+                                                return ControlFlow::new_typed_error(env, kind, msg)
+                                            }
+                                        };
                                         env.borrow_mut()
-                                            .assign(name, Value::Number((a + b).into()))
+                                            .assign(name, Value::Number(result))
                                             .unwrap();
                                     }
                                     (Value::Array(a), Value::Array(b)) => {
@@ -713,19 +987,18 @@ impl Interpreter {
                                     }
                                     (Value::Instance(instance), b) => {
                                         let class_name = instance.borrow().class.name.clone();
-                                        let operator_plus_method =
-                                            instance.borrow().find_operation("plus");
-                                        if operator_plus_method.is_some() {
-                                            let method = operator_plus_method.unwrap();
-                                            let call = method.call(vec![b.clone()]);
-                                            if call.is_err() {
-                                                let error = call;
-                                                return ControlFlow::Error(error);
+                                        if let Some(result) =
+                                            old_value.call_operator("add", vec![b.clone()])
+                                        {
+                                            if let Value::Error(error) = result {
+                                                return ControlFlow::Error(error.borrow().clone());
                                             }
-                                            return ControlFlow::Return(call);
+                                            env.borrow_mut().assign(name, result).unwrap();
+                                            return ControlFlow::None;
                                         }
-                                        return ControlFlow::new_error(
+                                        return ControlFlow::new_typed_error(
                                             env,
+                                            ErrorKind::TypeError,
                                             format!(
                                                 "operator not implemented for '{class_name}' class {op}",
                                             )
@@ -742,8 +1015,9 @@ impl Interpreter {
                                             .unwrap();
                                     }
                                     _ => {
-                                        return ControlFlow::new_error(
+                                        return ControlFlow::new_typed_error(
                                             env,
+                                            ErrorKind::TypeError,
                                             format!(
                                                 "Invalid operation: {:?} {} {:?}",
                                                 old_value.type_of(),
@@ -761,24 +1035,32 @@ impl Interpreter {
                                 let new_value = val;
                                 match (&old_value, &new_value) {
                                     (Value::Number(a), Value::Number(b)) => {
+                                        let result = match a
+                                            .checked_binary(&BinaryOperator::Subtract, b)
+                                        {
+                                            Ok(value) => value,
+                                            Err((kind, msg)) => {
+                                                return ControlFlow::new_typed_error(env, kind, msg)
+                                            }
+                                        };
                                         env.borrow_mut()
-                                            .assign(name, Value::Number((a - b).into()))
+                                            .assign(name, Value::Number(result))
                                             .unwrap();
                                     }
                                     (a, b) => {
-                                        env.borrow_mut()
-                                            .assign(
-                                                name,
-                                                Value::Number(
-                                                    (a.to_number() - b.to_number()).into(),
-                                                ),
-                                            )
-                                            .unwrap();
+                                        let result = match a.call_op(BinaryOperator::Subtract, b) {
+                                            Ok(value) => value,
+                                            Err((kind, msg)) => {
+                                                return ControlFlow::new_typed_error(env, kind, msg)
+                                            }
+                                        };
+                                        env.borrow_mut().assign(name, result).unwrap();
                                     }
 
                                     _ => {
-                                        return ControlFlow::new_error(
+                                        return ControlFlow::new_typed_error(
                                             env,
+                                            ErrorKind::TypeError,
                                             format!(
                                                 "Invalid operation: {:?} {} {:?}",
                                                 old_value.type_of(),
@@ -797,18 +1079,18 @@ impl Interpreter {
 
                                 match (&old_value, &new_value) {
                                     (a, b) => {
-                                        env.borrow_mut()
-                                            .assign(
-                                                name,
-                                                Value::Number(
-                                                    (a.to_number() * b.to_number()).into(),
-                                                ),
-                                            )
-                                            .unwrap();
+                                        let result = match a.call_op(BinaryOperator::Multiply, b) {
+                                            Ok(value) => value,
+                                            Err((kind, msg)) => {
+                                                return ControlFlow::new_typed_error(env, kind, msg)
+                                            }
+                                        };
+                                        env.borrow_mut().assign(name, result).unwrap();
                                     }
                                     _ => {
-                                        return ControlFlow::new_error(
+                                        return ControlFlow::new_typed_error(
                                             env,
+                                            ErrorKind::TypeError,
                                             format!(
                                                 "Invalid operation: {:?} {} {:?}",
                                                 old_value.type_of(),
@@ -827,18 +1109,18 @@ impl Interpreter {
 
                                 match (&old_value, &new_value) {
                                     (a, b) => {
-                                        env.borrow_mut()
-                                            .assign(
-                                                name,
-                                                Value::Number(
-                                                    (a.to_number() / b.to_number()).into(),
-                                                ),
-                                            )
-                                            .unwrap();
+                                        let result = match a.call_op(BinaryOperator::Divide, b) {
+                                            Ok(value) => value,
+                                            Err((kind, msg)) => {
+                                                return ControlFlow::new_typed_error(env, kind, msg)
+                                            }
+                                        };
+                                        env.borrow_mut().assign(name, result).unwrap();
                                     }
                                     _ => {
-                                        return ControlFlow::new_error(
+                                        return ControlFlow::new_typed_error(
                                             env,
+                                            ErrorKind::TypeError,
                                             format!(
                                                 "Invalid operation: {:?} {} {:?}",
                                                 old_value.type_of(),
@@ -857,18 +1139,18 @@ impl Interpreter {
 
                                 match (&old_value, &new_value) {
                                     (a, b) => {
-                                        env.borrow_mut()
-                                            .assign(
-                                                name,
-                                                Value::Number(
-                                                    (a.to_number() % b.to_number()).into(),
-                                                ),
-                                            )
-                                            .unwrap();
+                                        let result = match a.call_op(BinaryOperator::Modulo, b) {
+                                            Ok(value) => value,
+                                            Err((kind, msg)) => {
+                                                return ControlFlow::new_typed_error(env, kind, msg)
+                                            }
+                                        };
+                                        env.borrow_mut().assign(name, result).unwrap();
                                     }
                                     _ => {
-                                        return ControlFlow::new_error(
+                                        return ControlFlow::new_typed_error(
                                             env,
+                                            ErrorKind::TypeError,
                                             format!(
                                                 "Invalid operation: {:?} {} {:?}",
                                                 old_value.type_of(),
@@ -887,18 +1169,20 @@ impl Interpreter {
 
                                 match (&old_value, &new_value) {
                                     (a, b) => {
-                                        env.borrow_mut()
-                                            .assign(
-                                                name,
-                                                Value::Number(
-                                                    (a.to_number().powf(b.to_number())).into(),
-                                                ),
-                                            )
-                                            .unwrap();
+                                        let result = match a
+                                            .call_op(BinaryOperator::Exponentiate, b)
+                                        {
+                                            Ok(value) => value,
+                                            Err((kind, msg)) => {
+                                                return ControlFlow::new_typed_error(env, kind, msg)
+                                            }
+                                        };
+                                        env.borrow_mut().assign(name, result).unwrap();
                                     }
                                     _ => {
-                                        return ControlFlow::new_error(
+                                        return ControlFlow::new_typed_error(
                                             env,
+                                            ErrorKind::TypeError,
                                             format!(
                                                 "Invalid operation: {:?} {} {:?}",
                                                 old_value.type_of(),
@@ -938,6 +1222,11 @@ impl Interpreter {
                                 }
 
                                 Value::Instance(instance) => {
+                                    if let Some(error) =
+                                        Self::check_field_type(&instance.borrow(), &key, &val, env)
+                                    {
+                                        return error;
+                                    }
                                     let set_result = instance.borrow_mut().set(&key, val.clone());
                                     if set_result.is_err() {
                                         let error = set_result.unwrap_err();
@@ -962,15 +1251,17 @@ impl Interpreter {
                                         return ControlFlow::None;
                                     }
 
-                                    return ControlFlow::new_error(
+                                    return ControlFlow::new_typed_error(
                                         env,
+                                        ErrorKind::TypeError,
                                         format!("'{}' not found in '{}'", key, obj.type_of())
                                             .into(),
                                     );
                                 }
                                 ret => {
-                                    return ControlFlow::new_error(
+                                    return ControlFlow::new_typed_error(
                                         env,
+                                        ErrorKind::TypeError,
                                         format!("'{}' not found in '{}'", key, ret.type_of())
                                             .into(),
                                     );
@@ -983,18 +1274,20 @@ impl Interpreter {
 
                                 match (&old_value, &new_value) {
                                     (a, b) => {
-                                        env.borrow_mut()
-                                            .assign(
-                                                &key,
-                                                Value::Number(
-                                                    (a.to_number().powf(b.to_number())).into(),
-                                                ),
-                                            )
-                                            .unwrap();
+                                        let result = match a
+                                            .call_op(BinaryOperator::Exponentiate, b)
+                                        {
+                                            Ok(value) => value,
+                                            Err((kind, msg)) => {
+                                                return ControlFlow::new_typed_error(env, kind, msg)
+                                            }
+                                        };
+                                        env.borrow_mut().assign(&key, result).unwrap();
                                     }
                                     _ => {
-                                        return ControlFlow::new_error(
+                                        return ControlFlow::new_typed_error(
                                             env,
+                                            ErrorKind::TypeError,
                                             format!(
                                                 "Invalid operation: {:?} {} {:?}",
                                                 old_value.type_of(),
@@ -1013,18 +1306,18 @@ impl Interpreter {
 
                                 match (&old_value, &new_value) {
                                     (a, b) => {
-                                        env.borrow_mut()
-                                            .assign(
-                                                &key,
-                                                Value::Number(
-                                                    (a.to_number() * b.to_number()).into(),
-                                                ),
-                                            )
-                                            .unwrap();
+                                        let result = match a.call_op(BinaryOperator::Multiply, b) {
+                                            Ok(value) => value,
+                                            Err((kind, msg)) => {
+                                                return ControlFlow::new_typed_error(env, kind, msg)
+                                            }
+                                        };
+                                        env.borrow_mut().assign(&key, result).unwrap();
                                     }
                                     _ => {
-                                        return ControlFlow::new_error(
+                                        return ControlFlow::new_typed_error(
                                             env,
+                                            ErrorKind::TypeError,
                                             format!(
                                                 "Invalid operation: {:?} {} {:?}",
                                                 old_value.type_of(),
@@ -1036,7 +1329,36 @@ impl Interpreter {
                                     }
                                 }
                             }
-                            _ => todo!("Not supported {expr}"),
+                            compound => {
+                                // obj.prop op= val -> obj.prop = obj.prop op val
+                                let math_op = compound.binary_operator().unwrap();
+                                let old_value = self.eval_expr(
+                                    &Expr::GetProperty {
+                                        object: object.clone(),
+                                        property: property.clone(),
+                                    },
+                                    env,
+                                );
+                                if old_value.is_error() {
+                                    return old_value;
+                                }
+                                let result = match old_value.unwrap().call_op(math_op, &val) {
+                                    Ok(value) => value,
+                                    Err((kind, msg)) => {
+                                        return ControlFlow::new_typed_error(env, kind, msg)
+                                    }
+                                };
+                                return self.eval_expr(
+                                    &Expr::Assign {
+                                        target: target.clone(),
+                                        op: crate::ast::ast::AssignOperator::Assign,
+                                        value: Box::new(Expr::Literal(Literal::from_value(
+                                            &result,
+                                        ))),
+                                    },
+                                    env,
+                                );
+                            }
                         }
                     }
                     Expr::BracketAccess { object, property } => {
@@ -1056,6 +1378,35 @@ impl Interpreter {
                         let arr = arr.unwrap();
                         let index = index.unwrap();
 
+                        // obj[k] = v chama `operator set(self, k, v)`; compostas leem com `get`
+                        if arr.has_operator("set") {
+                            let new_value = match op.binary_operator() {
+                                None => val,
+                                Some(math_op) => {
+                                    let current = arr
+                                        .call_operator("get", vec![index.clone()])
+                                        .unwrap_or(Value::Null);
+                                    if let Value::Error(error) = current {
+                                        return ControlFlow::Error(error.borrow().clone());
+                                    }
+                                    match current.call_op(math_op, &val) {
+                                        Ok(Value::Error(error)) => {
+                                            return ControlFlow::Error(error.borrow().clone())
+                                        }
+                                        Ok(value) => value,
+                                        Err((kind, msg)) => {
+                                            return ControlFlow::new_typed_error(env, kind, msg)
+                                        }
+                                    }
+                                }
+                            };
+                            let result = arr.call_operator("set", vec![index, new_value]).unwrap();
+                            if let Value::Error(error) = result {
+                                return ControlFlow::Error(error.borrow().clone());
+                            }
+                            return ControlFlow::None;
+                        }
+
                         match op {
                             crate::ast::ast::AssignOperator::Assign => match (arr, index) {
                                 (Value::Array(array), Value::Number(n)) => {
@@ -1169,6 +1520,7 @@ impl Interpreter {
                 let prop = match property.as_ref() {
                     Expr::Identifier(name) => Value::String(name.to_string().into()),
                     Expr::Literal(Literal::Number(n)) => Value::Number(n.into()),
+                    Expr::Literal(Literal::Int(n)) => Value::Number(n.into()),
                     Expr::Literal(Literal::String(s)) => Value::String(s.clone().into()),
                     _ => return ControlFlow::Return(Value::Null),
                 };
@@ -1183,6 +1535,30 @@ impl Interpreter {
                     obj = err_value;
                 }
 
+                // Combinadores de Option e Result: opt.map(f), res.unwrapOr(0)...
+                if let Value::String(name) = &prop {
+                    if NativeOptionClass::METHODS.contains(&name.as_str())
+                        && option::unpack(&obj).is_some()
+                    {
+                        let native = NativeOptionClass::new_with_value(obj.clone());
+                        return ControlFlow::Return(Value::InternalFunction((
+                            name.to_string(),
+                            Rc::new(RefCell::new(native)),
+                        )));
+                    }
+                }
+
+                // Métodos nativos de números (toString(radix)...) têm precedência sobre a classe Number
+                if let (Value::Number(n), Value::String(name)) = (&obj, &prop) {
+                    let native = NativeNumberClass::new_with_number(n.get_number());
+                    if native.methods_names().contains(&name.to_string()) {
+                        return ControlFlow::Return(Value::InternalFunction((
+                            name.to_string(),
+                            Rc::new(RefCell::new(native)),
+                        )));
+                    }
+                }
+
                 if obj.is_primitive() {
                     let class = obj.get_primitive_class(env);
                     if class.is_some() {
@@ -1196,7 +1572,7 @@ impl Interpreter {
                             format!("Property '{prop}' not  in found '{}'", object.to_string());
                         let prop = obj.borrow().get_prop(&prop);
                         if prop.is_none() {
-                            return ControlFlow::new_error(env, msg);
+                            return ControlFlow::new_typed_error(env, ErrorKind::TypeError, msg);
                         }
                         let prop = prop.unwrap();
                         return ControlFlow::Return(prop);
@@ -1206,10 +1582,19 @@ impl Interpreter {
 
                         let msg = format!("Cannot find '{prop}' in class {class_name}");
 
+                        if prop == "super" {
+                            if let Some(Value::Function(constructor)) = env.borrow().get("super") {
+                                let mut constructor = Function::from(constructor);
+                                constructor.this = obj.clone();
+                                return ControlFlow::Return(Value::Function(constructor.into()));
+                            }
+                        }
+
                         let value = instance.borrow().get(&prop.to_string());
 
                         if value.is_none() {
-                            let error = ControlFlow::new_error(env, msg);
+                            let error =
+                                ControlFlow::new_typed_error(env, ErrorKind::TypeError, msg);
                             return error;
                         }
                         let value = value.unwrap();
@@ -1234,8 +1619,16 @@ impl Interpreter {
                         if let Some(val) = class.find_static_method(&name) {
                             return ControlFlow::Return(Value::Function(val));
                         }
+                        // Classes da stdlib (Number, Array...) complementam o módulo nativo de mesmo nome
+                        if let Some(native) = stdlib::get_module_by_name(&class.name) {
+                            if native.borrow().methods_names().contains(&name) {
+                                return ControlFlow::Return(Value::InternalFunction((
+                                    name, native,
+                                )));
+                            }
+                        }
 
-                        return ControlFlow::new_error(env, format!("{}", msg).into());
+                        return ControlFlow::new_typed_error(env, ErrorKind::TypeError, msg);
                     }
                     (Value::InternalClass(native), Value::String(prop)) => {
                         return ControlFlow::Return(Value::InternalFunction((
@@ -1243,15 +1636,26 @@ impl Interpreter {
                             native.clone(),
                         )));
                     }
-                    (Value::Function(_func), Value::String(prop)) => {
-                        let prop = prop;
-
-                        let msg = format!("Property {prop} not found");
-                        todo!("A fazer {prop} {msg}");
-                    }
+                    (Value::Function(func), Value::String(prop)) => match prop.as_str() {
+                        "name" => return ControlFlow::Return(Value::String(func.name.clone())),
+                        "arity" => return ControlFlow::Return(Value::Number(func.arity().into())),
+                        name if NativeFunctionClass::METHODS.contains(&name) => {
+                            let native = NativeFunctionClass::new_with_function(func.clone());
+                            return ControlFlow::Return(Value::InternalFunction((
+                                name.to_string(),
+                                Rc::new(RefCell::new(native)),
+                            )));
+                        }
+                        _ => {
+                            let msg =
+                                format!("Property '{prop}' not found in function '{}'", func.name);
+                            return ControlFlow::new_typed_error(env, ErrorKind::TypeError, msg);
+                        }
+                    },
                     _ => {
-                        return ControlFlow::new_error(
+                        return ControlFlow::new_typed_error(
                             env,
+                            ErrorKind::TypeError,
                             format!(
                                 "Cannot access property {:?} of {:?} (type: {:?}, {:?}) GetProperty",
                                 prop.to_string(),
@@ -1278,6 +1682,9 @@ impl Interpreter {
                 }
                 let obj = obj.unwrap();
                 let prop = prop.unwrap();
+                if let Some(result) = obj.call_operator("get", vec![prop.clone()]) {
+                    return Self::operator_result(result);
+                }
                 match (&obj, &prop) {
                     (Value::Object(obj), Value::String(prop)) => {
                         let prop = prop.to_string();
@@ -1312,7 +1719,11 @@ impl Interpreter {
                         let collection_class = collection_class.unwrap();
 
                         if !instance.borrow().is_instance_of(&collection_class) {
-                            return ControlFlow::new_error(env, "Not a collection".into());
+                            return ControlFlow::new_typed_error(
+                                env,
+                                ErrorKind::TypeError,
+                                "Not a collection".into(),
+                            );
                         }
                         let iter_method = instance.borrow().get("iter");
 
@@ -1324,8 +1735,9 @@ impl Interpreter {
                             .unwrap_or(Value::Null)
                     }
                     _ => {
-                        return ControlFlow::new_error(
+                        return ControlFlow::new_typed_error(
                             env,
+                            ErrorKind::TypeError,
                             format!(
                             "Cannot access property {:?} of {:?} (type: {:?}, {:?}) BracketAccess",
                             prop.to_string(),
@@ -1346,6 +1758,7 @@ impl Interpreter {
                 let prop = match property.as_ref() {
                     Expr::Identifier(name) => Value::String(name.to_string().into()),
                     Expr::Literal(Literal::Number(n)) => Value::Number(n.into()),
+                    Expr::Literal(Literal::Int(n)) => Value::Number(n.into()),
                     Expr::Literal(Literal::String(s)) => Value::String(s.clone().into()),
                     _ => return ControlFlow::Return(Value::Null),
                 };
@@ -1392,8 +1805,9 @@ impl Interpreter {
                             .unwrap_or(Value::Null);
                     }
                     _ => {
-                        return ControlFlow::new_error(
+                        return ControlFlow::new_typed_error(
                             env,
+                            ErrorKind::TypeError,
                             format!(
                             "Cannot access property {:?} of {:?} (type: {:?}, {:?}) SetProperty",
                             prop.to_string(),
@@ -1411,33 +1825,36 @@ impl Interpreter {
                 let empty_args: Vec<Expr> = vec![];
                 // Se for uma chamada, separa callee e args
                 let (class_callee, args) = match &**class_expr {
-                    Expr::Call { callee, args } => (callee, args),
+                    Expr::Call {
+                        callee,
+                        args,
+                        location,
+                    } => {
+                        call_stack::set_location(location);
+                        (callee, args)
+                    }
                     Expr::Identifier(name) => {
                         let callee = Expr::Identifier(name.clone());
                         (&Box::new(callee), &empty_args)
                     }
                     _ => {
-                        return ControlFlow::new_error(
+                        return ControlFlow::new_typed_error(
                             env,
+                            ErrorKind::TypeError,
                             format!("Expected a call expression after 'new'"),
                         )
                     }
                 };
 
-                let mut arg_values = vec![];
-
                 // Avalia os argumentos
-                for arg in args.iter() {
-                    let val = self.eval_expr(arg, env);
-                    if val.is_error() {
-                        return val;
-                    }
-                    let mut val = val.unwrap();
-                    if val.is_void() {
-                        val = Value::Null;
-                    }
-                    arg_values.push(val);
-                }
+                let (arg_values, named_args) = match self.eval_call_args(args, env) {
+                    Ok(args) => args,
+                    Err(err) => return err,
+                };
+                let arg_values: Vec<Value> = arg_values
+                    .into_iter()
+                    .map(|val| if val.is_void() { Value::Null } else { val })
+                    .collect();
 
                 // Avalia o callee (pode ser Identifier, MemberAccess, etc.)
                 let value = self.eval_expr(class_callee, env);
@@ -1448,14 +1865,53 @@ impl Interpreter {
                 let value = value.unwrap();
 
                 match value {
+                    Value::Class(class) if class.is_interface() => {
+                        return ControlFlow::new_typed_error(
+                            env,
+                            ErrorKind::TypeError,
+                            format!("Cannot instantiate interface '{}'", class.name),
+                        )
+                    }
                     Value::Class(class) => {
-                        let instance = Class::instantiate(&class, arg_values);
-                        return ControlFlow::Return(instance);
+                        // O `self` do construtor é a instância, nunca um argumento nomeado
+                        let constructor = class.get_constructor();
+                        let params = constructor
+                            .as_ref()
+                            .and_then(|constructor| constructor.params.get(1..))
+                            .unwrap_or_default();
+                        let arg_values =
+                            match bind_named_args(&class.name, params, arg_values, named_args) {
+                                Ok(args) => args,
+                                Err(err) => {
+                                    return ControlFlow::new_typed_error(
+                                        env,
+                                        ErrorKind::TypeError,
+                                        err,
+                                    )
+                                }
+                            };
+                        match Class::instantiate(&class, arg_values) {
+                            Value::Error(error) => {
+                                return ControlFlow::Error(error.borrow().clone())
+                            }
+                            instance => return ControlFlow::Return(instance),
+                        }
+                    }
+                    Value::InternalClass(native) if !named_args.is_empty() => {
+                        return ControlFlow::new_typed_error(
+                            env,
+                            ErrorKind::TypeError,
+                            format!(
+                                "'{}' does not accept named arguments",
+                                native.borrow().get_name()
+                            ),
+                        )
                     }
                     Value::InternalClass(native) => {
-                        let instance = native.borrow_mut().instantiate(arg_values).unwrap();
-
-                        return ControlFlow::Return(instance);
+                        return match native.borrow_mut().instantiate(arg_values) {
+                            Ok(instance) => ControlFlow::Return(instance),
+                            Err(err) => ControlFlow::new_error(env, err),
+                        };
                     }
                     _ => Value::Error(Rc::new(RefCell::new(value))),
                 }
@@ -1465,43 +1921,196 @@ impl Interpreter {
                 this
             }
             Expr::Spread(expr) => Value::Expr(expr.as_ref().clone()),
-            _ => {
-                todo!("Cannot evaluate expression: {:?}", expr)
-            }
-        };
-
-        ControlFlow::Return(ret)
-    }
-
-    pub fn eval_stmt(
-        &mut self,
-        stmt: &Stmt,
-        env: &mut Rc<RefCell<Environment>>,
-    ) -> ControlFlow<Value> {
-        match stmt {
-            Stmt::Let { name, value } => {
-                let name = name.clone();
-                if env.borrow().exist(&name) {
-                    return ControlFlow::new_error(
+            Expr::Pipe {
+                value,
+                target,
+                location,
+            } => return self.eval_pipe(value, target, location, env),
+            Expr::Propagate(inner) => {
+                let value = self.eval_expr(inner, env);
+                if value.is_error() {
+                    return value;
+                }
+                let value = value.unwrap();
+                let Some((variant, field)) = option::unpack(&value) else {
+                    return ControlFlow::new_typed_error(
                         env,
-                        format!("Cannot redeclare block-scoped variable '{}'", name),
+                        ErrorKind::TypeError,
+                        format!(
+                            "'?' expects an Option or Result, got {}",
+                            typecheck::describe(&value)
+                        ),
+                    );
+                };
+                if !self.in_function {
+                    return ControlFlow::new_typed_error(
+                        env,
+                        ErrorKind::SyntaxError,
+                        "'?' can only be used inside a function".to_string(),
                     );
                 }
-                let expr = value.clone();
-                let val = self.eval_expr(&expr, env);
-                if val.is_err() {
-                    return val;
+                if option::has_value(&variant) {
+                    field
+                } else {
+                    // Sobe como erro para atravessar blocos e laços; `Function::call` o
+                    // transforma no valor de retorno
+                    self.propagating = Some(value.clone());
+                    return ControlFlow::Error(value);
                 }
-                let val = val.unwrap();
-
-                env.borrow_mut().define(name, val);
-                ControlFlow::None
             }
-            Stmt::FuncDecl(FunctionStmt {
-                name,
-                params,
+            Expr::ArrayComprehension { element, clauses } => {
+                let mut items = vec![];
+                let mut scope = Environment::new_rc_enclosed(env);
+                let mut emit = |this: &mut Self, env: &mut Rc<RefCell<Environment>>| {
+                    let item = this.eval_expr(element, env);
+                    if item.is_error() {
+                        return Some(item);
+                    }
+                    items.push(item.unwrap());
+                    None
+                };
+                if let Some(err) = self.eval_clauses(clauses, &mut scope, &mut emit) {
+                    return err;
+                }
+                Value::array(items)
+            }
+            Expr::ObjectComprehension {
+                key,
+                value,
+                clauses,
+            } => {
+                let mut entries: Vec<(String, Value)> = vec![];
+                let mut scope = Environment::new_rc_enclosed(env);
+                let mut emit = |this: &mut Self, env: &mut Rc<RefCell<Environment>>| {
+                    let key = this.eval_expr(key, env);
+                    if key.is_error() {
+                        return Some(key);
+                    }
+                    let value = this.eval_expr(value, env);
+                    if value.is_error() {
+                        return Some(value);
+                    }
+                    // Chaves repetidas ficam com o último valor, na posição original
+                    let key = key.unwrap().to_string();
+                    let value = value.unwrap();
+                    match entries.iter_mut().find(|(existing, _)| *existing == key) {
+                        Some(entry) => entry.1 = value,
+                        None => entries.push((key, value)),
+                    }
+                    None
+                };
+                if let Some(err) = self.eval_clauses(clauses, &mut scope, &mut emit) {
+                    return err;
+                }
+                Value::object(entries)
+            }
+            Expr::NamedArg { name, .. } => {
+                return ControlFlow::new_typed_error(
+                    env,
+                    ErrorKind::SyntaxError,
+                    format!("Named argument '{name}' is only allowed in a call"),
+                )
+            }
+            Expr::Delete(target) => {
+                let (object, key) = match target.as_ref() {
+                    Expr::GetProperty { object, property } => match property.as_ref() {
+                        Expr::Identifier(name) => (object, Value::String(name.clone())),
+                        _ => return ControlFlow::new_error(env, "Propriedade inválida".into()),
+                    },
+                    Expr::BracketAccess { object, property } => {
+                        let key = self.eval_expr(property, env);
+                        if key.is_error() {
+                            return key;
+                        }
+                        (object, key.unwrap())
+                    }
+                    _ => {
+                        return ControlFlow::new_typed_error(
+                            env,
+                            ErrorKind::TypeError,
+                            format!("Cannot delete '{}': not a property", target),
+                        )
+                    }
+                };
+                let obj = self.eval_expr(object, env);
+                if obj.is_error() {
+                    return obj;
+                }
+                let key = key.to_string();
+
+                match obj.unwrap() {
+                    Value::Object(map) => Value::Bool(map.borrow_mut().remove_prop(&key)),
+                    Value::Instance(instance) => {
+                        Value::Bool(instance.borrow_mut().remove_field(&key))
+                    }
+                    other => {
+                        return ControlFlow::new_typed_error(
+                            env,
+                            ErrorKind::TypeError,
+                            format!("Cannot delete property '{key}' of {}", other.type_of()),
+                        )
+                    }
+                }
+            }
+            _ => {
+                todo!("Cannot evaluate expression: {:?}", expr)
+            }
+        };
+
+        ControlFlow::Return(ret)
+    }
+
+    pub fn eval_stmt(
+        &mut self,
+        stmt: &Stmt,
+        env: &mut Rc<RefCell<Environment>>,
+    ) -> ControlFlow<Value> {
+        // Destrutores de objetos liberados pelo statement anterior
+        finalizer::run_pending();
+        if let Some(location) = stmt.location() {
+            call_stack::set_location(location);
+        }
+        match stmt {
+            Stmt::Let {
+                name, ty, value, ..
+            } => {
+                let name = name.clone();
+                if env.borrow().exist(&name) {
+                    return ControlFlow::new_typed_error(
+                        env,
+                        ErrorKind::SyntaxError,
+                        format!("Cannot redeclare block-scoped variable '{}'", name),
+                    );
+                }
+                let expr = value.clone();
+                let val = self.eval_expr(&expr, env);
+                if val.is_err() {
+                    return val;
+                }
+                let val = val.unwrap();
+
+                if let Some(ty) = ty.as_ref().filter(|_| typecheck::is_strict()) {
+                    if !typecheck::value_matches(&val, ty, env) {
+                        return ControlFlow::new_typed_error(
+                            env,
+                            ErrorKind::TypeError,
+                            format!(
+                                "'{name}' is declared as {ty}, got {}",
+                                typecheck::describe(&val)
+                            ),
+                        );
+                    }
+                }
+
+                env.borrow_mut().define(name, val);
+                ControlFlow::None
+            }
+            Stmt::FuncDecl(FunctionStmt {
+                name,
+                params,
                 vararg,
                 body,
+                signature,
             }) => {
                 let func_env = Environment::new_rc_enclosed(env);
                 let function = Function::new(
@@ -1511,21 +2120,48 @@ impl Interpreter {
                     body.clone(),
                     func_env,
                     vec![],
-                );
+                )
+                .with_signature(signature.clone());
 
                 env.borrow_mut()
                     .define(name.clone(), Value::Function(function.into()));
                 ControlFlow::None
             }
-            Stmt::Return(expr) => {
+            Stmt::Decorated { decorators, target } => {
+                let declared = self.eval_stmt(target, env);
+                if declared.is_error() {
+                    return declared;
+                }
+                let Some(name) = self.get_export_name(target) else {
+                    return declared;
+                };
+
+                let value = env.borrow().get(&name).unwrap_or(Value::Null);
+                let decorated = self.apply_decorators(decorators, value, env);
+                if decorated.is_error() {
+                    return decorated;
+                }
+                let decorated = decorated.unwrap();
+                env.borrow_mut().define(name, decorated.clone());
+
+                match declared {
+                    ControlFlow::Return(_) => ControlFlow::Return(decorated),
+                    _ => ControlFlow::None,
+                }
+            }
+            Stmt::Return(expr, _) => {
                 if expr.is_none() {
                     return ControlFlow::Return(Value::Void);
                 }
+                if let Some(Expr::Call { .. }) = expr {
+                    self.tail_position =
+                        self.tail_calls && self.try_depth == 0 && self.pending_defers == 0;
+                }
                 let val = self.eval_expr(&expr.clone().unwrap(), env);
 
                 val
             }
-            Stmt::ExprStmt(expr) => {
+            Stmt::ExprStmt(expr, _) => {
                 // Não retorna valor pois não suporta REPL
                 let result = self.eval_expr(expr, env);
 
@@ -1547,10 +2183,10 @@ impl Interpreter {
 
                 let condition = condition.unwrap();
                 if condition.to_bool() {
-                    let inner = Environment::new_rc_enclosed(env);
+                    let mut inner = Environment::new_rc_enclosed(env);
 
-                    let flow = self.if_block(then_branch, inner);
-                    return flow;
+                    let flow = self.if_block(then_branch, inner.clone());
+                    return self.exit_scope(&mut inner, flow);
                 } else {
                     for (cond, branch) in else_ifs {
                         let conditon = self.eval_expr(cond, env);
@@ -1562,25 +2198,28 @@ impl Interpreter {
                         if conditon.to_bool() {
                             let mut local_env =
                                 Rc::new(RefCell::new(Environment::new_enclosed(env)));
+                            let mut flow = ControlFlow::None;
                             if let Some(branch) = branch {
                                 for stmt in branch {
-                                    match self.eval_stmt(&stmt, &mut local_env) {
-                                        ControlFlow::None => {}
-                                        other => return other,
-                                    };
+                                    flow = self.eval_stmt(stmt, &mut local_env);
+                                    if !matches!(flow, ControlFlow::None) {
+                                        break;
+                                    }
                                 }
                             }
-                            return ControlFlow::None;
+                            return self.exit_scope(&mut local_env, flow);
                         }
                     }
                     if let Some(else_branch) = else_branch {
                         let mut local_env = Rc::new(RefCell::new(Environment::new_enclosed(env)));
+                        let mut flow = ControlFlow::None;
                         for stmt in else_branch {
-                            match self.eval_stmt(&stmt, &mut local_env) {
-                                ControlFlow::None => {}
-                                other => return other,
-                            };
+                            flow = self.eval_stmt(stmt, &mut local_env);
+                            if !matches!(flow, ControlFlow::None) {
+                                break;
+                            }
                         }
+                        return self.exit_scope(&mut local_env, flow);
                     }
                 }
                 ControlFlow::None
@@ -1617,9 +2256,10 @@ impl Interpreter {
                         break;
                     }
 
-                    let inner = Rc::new(RefCell::new(Environment::new_enclosed(&mut loop_env)));
+                    let mut inner = Rc::new(RefCell::new(Environment::new_enclosed(&mut loop_env)));
 
-                    let flow = self.loop_block(body, inner);
+                    let flow = self.loop_block(body, inner.clone());
+                    let flow = self.exit_scope(&mut inner, flow);
 
                     match flow {
                         ControlFlow::Break => break,
@@ -1650,29 +2290,9 @@ impl Interpreter {
 
                 let iterable_val = iterable_val.unwrap();
 
-                let iter: Box<dyn Iterator<Item = Value>> = match &iterable_val {
-                    Value::Array(arr) => Box::new(arr.get_value().borrow().clone().into_iter()),
-                    Value::String(s) => {
-                        let s = s.to_string();
-                        let chars: Vec<_> = s
-                            .chars()
-                            .map(|c| Value::String(c.to_string().into()))
-                            .collect();
-                        Box::new(chars.into_iter())
-                    }
-                    Value::Instance(instance) => {
-                        let collection_class = env.borrow().get("Collection");
-                        let collection_class = collection_class.unwrap();
-
-                        if !instance.borrow().is_instance_of(&collection_class) {
-                            return ControlFlow::new_error(env, "Not a collection".into());
-                        }
-                        let iter_method = instance.borrow().get("iter");
-
-                        let arr = iter_method.unwrap().to_method().call(vec![iterable_val]);
-                        Box::new(arr.to_array().into_iter())
-                    }
-                    _ => panic!("Expected array or string in for-of"),
+                let iter = match self.iterate(&iterable_val, env) {
+                    Ok(items) => items,
+                    Err(err) => return err,
                 };
 
                 for val in iter {
@@ -1681,7 +2301,8 @@ impl Interpreter {
                     // Aplicar o padrão de atribuição (identificador ou destructuring)
                     self.destructure(&target, val, &mut inner);
 
-                    let flow = self.loop_block(body, inner);
+                    let flow = self.loop_block(body, inner.clone());
+                    let flow = self.exit_scope(&mut inner, flow);
 
                     match flow {
                         ControlFlow::Break => break,
@@ -1741,7 +2362,8 @@ impl Interpreter {
                     // Aplicar o padrão de atribuição (identificador ou destructuring)
                     self.destructure(&target, val, &mut inner);
 
-                    let flow = self.loop_block(body, inner);
+                    let flow = self.loop_block(body, inner.clone());
+                    let flow = self.exit_scope(&mut inner, flow);
 
                     match flow {
                         ControlFlow::Break => break,
@@ -1759,6 +2381,9 @@ impl Interpreter {
                 methods,
                 static_fields,
                 instance_fields,
+                field_types,
+                interfaces,
+                modifiers,
             } => {
                 // Primeiro definimos a classe com valor `null` para permitir referências recursivas
                 env.borrow_mut().define(name.clone(), Value::Null);
@@ -1782,6 +2407,22 @@ impl Interpreter {
                     None
                 };
 
+                // `self.super(...)` é resolvido lexicamente: os métodos da classe enxergam o
+                // construtor da superclasse direta, permitindo encadear vários níveis
+                if let Some(parent) = &super_class_value {
+                    let parent_constructor = parent
+                        .methods
+                        .iter()
+                        .find(|method| method.name == "constructor")
+                        .map(|method| Value::Function(method.clone()))
+                        .or_else(|| parent.closure.borrow().get("super"));
+                    if let Some(constructor) = parent_constructor {
+                        class_env
+                            .borrow_mut()
+                            .define("super".to_string(), constructor);
+                    }
+                }
+
                 let instance_variables = Rc::new(RefCell::new(HashMap::new()));
 
                 let instace_env = Environment::new_rc();
@@ -1800,7 +2441,8 @@ impl Interpreter {
                             method.body.clone(),
                             env.clone(),
                             method.modifiers.clone(),
-                        );
+                        )
+                        .with_signature(method.signature.clone());
 
                         super_class_static_methods.push(Rc::new(method));
                     }
@@ -1817,7 +2459,8 @@ impl Interpreter {
                             method.body.clone(),
                             class_closure.clone(),
                             method.modifiers.clone(),
-                        );
+                        )
+                        .with_signature(method.signature.clone());
 
                         super_class_methods.push(Rc::new(method));
                     }
@@ -1865,21 +2508,31 @@ impl Interpreter {
                 let mut is_constructor_declared = false;
                 let mut method_array: Vec<Rc<Function>> = vec![];
                 let mut static_method_array: Vec<Rc<Function>> = vec![];
+                let mut abstract_methods: Vec<RequiredMethod> = vec![];
 
                 for method in methods {
                     let method_name = method.name.clone();
 
-                    if method.modifiers.contains_str("static") {
-                        let method = Function::new(
+                    if method.modifiers.contains_str("abstract") {
+                        abstract_methods.push(RequiredMethod {
+                            name: method_name,
+                            operator: method.modifiers.contains_str("operator"),
+                        });
+                    } else if method.modifiers.contains_str("static") {
+                        let function = Function::new(
                             method.name.clone(),
                             method.params.clone(),
                             method.vararg.clone(),
                             method.body.clone(),
                             env.clone(),
                             method.modifiers.clone(),
-                        );
+                        )
+                        .with_signature(method.signature.clone());
 
-                        static_method_array.push(Rc::new(method))
+                        match self.decorate_method(method, function, env) {
+                            Ok(function) => static_method_array.push(function),
+                            Err(error) => return error,
+                        }
                     } else {
                         if method_name == "constructor" {
                             if is_constructor_declared {
@@ -1888,15 +2541,19 @@ impl Interpreter {
                             is_constructor_declared = true;
                         }
 
-                        let method = Function::new(
+                        let function = Function::new(
                             method_name,
                             method.params.clone(),
                             method.vararg.clone(),
                             method.body.clone(),
-                            env.clone(),
+                            class_closure.clone(),
                             method.modifiers.clone(),
-                        );
-                        method_array.push(Rc::new(method));
+                        )
+                        .with_signature(method.signature.clone());
+                        match self.decorate_method(method, function, env) {
+                            Ok(function) => method_array.push(function),
+                            Err(error) => return error,
+                        }
                     }
                 }
 
@@ -1906,10 +2563,76 @@ impl Interpreter {
                     None
                 };
 
+                let mut all_field_types = super_class
+                    .as_ref()
+                    .map(|parent| parent.field_types.clone())
+                    .unwrap_or_default();
+                all_field_types.extend(field_types.clone());
+
                 // add super statics to static_method_array
                 static_method_array.extend(super_class_static_methods);
                 method_array.extend(super_class_methods);
 
+                let interfaces = match self.resolve_interfaces(interfaces, &mut class_env) {
+                    Ok(interfaces) => interfaces,
+                    Err(error) => return error,
+                };
+                // Métodos padrão das interfaces entram só onde a classe não define o seu
+                for interface in &interfaces {
+                    for default in &interface.methods {
+                        if method_array
+                            .iter()
+                            .any(|method| method.name == default.name)
+                        {
+                            continue;
+                        }
+                        let method = Function::new(
+                            default.name.clone(),
+                            default.params.clone(),
+                            default.vararg.clone(),
+                            default.body.clone(),
+                            class_closure.clone(),
+                            default.modifiers.clone(),
+                        )
+                        .with_signature(default.signature.clone());
+                        method_array.push(Rc::new(method));
+                    }
+                }
+                let is_abstract = modifiers.contains_str("abstract");
+                // Pendências herdadas da superclasse abstrata, mais as abstratas desta classe
+                let mut required = super_class
+                    .as_ref()
+                    .map(|parent| parent.required_methods().to_vec())
+                    .unwrap_or_default();
+                required.extend(abstract_methods);
+                for interface in &interfaces {
+                    let missing =
+                        Self::missing_methods(interface.required_methods(), &method_array);
+                    // Uma classe abstrata pode deixar a implementação para as subclasses
+                    if !missing.is_empty() && !is_abstract {
+                        return ControlFlow::new_typed_error(
+                            env,
+                            ErrorKind::TypeError,
+                            format!(
+                                "Class '{name}' does not implement {} required by interface '{}'",
+                                Self::quote_methods(&missing),
+                                interface.name
+                            ),
+                        );
+                    }
+                    required.extend(missing);
+                }
+                let mut remaining = Self::missing_methods(&required, &method_array);
+                let mut seen = HashSet::new();
+                remaining.retain(|method| seen.insert(method.name.clone()));
+                if !remaining.is_empty() && !is_abstract {
+                    let msg = format!(
+                        "Class '{name}' must implement abstract method(s) {} or be declared abstract",
+                        Self::quote_methods(&remaining)
+                    );
+                    return ControlFlow::new_typed_error(env, ErrorKind::TypeError, msg);
+                }
+
                 let class = Class {
                     name: name.clone(),
                     superclass: super_class,
@@ -1921,6 +2644,15 @@ impl Interpreter {
                     instance_variables,
                     static_variables,
                     closure: class_closure.clone(),
+                    kind: match is_abstract {
+                        true => ClassKind::Abstract {
+                            required: remaining,
+                        },
+                        false => ClassKind::Class,
+                    },
+                    field_types: all_field_types,
+                    interfaces,
+                    decorations: RefCell::new(vec![]),
                 };
 
                 let class = Value::Class(class.into());
@@ -2020,8 +2752,12 @@ impl Interpreter {
                     // let loop_env = Rc::clone(&loop_env);
                     for stmt in body.iter() {
                         match self.eval_stmt(stmt, &mut loop_env) {
-                            ControlFlow::Return(v) => return ControlFlow::Return(v),
-                            ControlFlow::Break => return ControlFlow::None,
+                            ControlFlow::Return(v) => {
+                                return self.exit_scope(&mut loop_env, ControlFlow::Return(v))
+                            }
+                            ControlFlow::Break => {
+                                return self.exit_scope(&mut loop_env, ControlFlow::None)
+                            }
                             ControlFlow::Continue => break,
                             ControlFlow::None => {}
                             ControlFlow::Error(err) => {
@@ -2029,69 +2765,271 @@ impl Interpreter {
                             }
                         }
                     }
+                    // Fim da iteração: o corpo compartilha `loop_env`
+                    let flow = self.exit_scope(&mut loop_env, ControlFlow::None);
+                    if flow.is_error() {
+                        return flow;
+                    }
                 }
 
                 ControlFlow::None
             }
             Stmt::TryCatchFinally {
                 try_block,
-                catch_block,
+                catch_clauses,
                 finally_block,
             } => {
+                self.try_depth += 1;
                 let mut try_env = Environment::new_rc_enclosed(env);
                 // Tenta executar o bloco `try`
                 let result = self.execute_try_block(try_block, &mut try_env);
+                // Os `defer` do try rodam antes do catch; um erro neles também é capturado
+                let flow = match result {
+                    Ok(_) => ControlFlow::None,
+                    Err(error) => ControlFlow::Error(error),
+                };
+                let result: Result<Value, Value> = match self.exit_scope(&mut try_env, flow) {
+                    ControlFlow::Error(error) => Err(error),
+                    _ => Ok(Value::Void),
+                };
 
-                let mut catch_env = Environment::new_rc_enclosed(env);
+                let mut outcome = ControlFlow::None;
 
                 if let Err(error) = result {
-                    // Se houve erro e temos um bloco catch
-                    if let Some((err_name, catch_stmts)) = catch_block {
-                        let error = if error.is_err() {
-                            error
-                        } else {
-                            Value::Error(Rc::new(RefCell::new(error)))
-                        };
-                        catch_env.borrow_mut().define(err_name.to_string(), error);
+                    let caught = match &error {
+                        Value::Error(inner) => inner.borrow().clone(),
+                        other => other.clone(),
+                    };
 
-                        for stmt in catch_stmts {
-                            let val = self.eval_stmt(stmt, &mut catch_env);
-                            match val {
-                                ControlFlow::Return(val) => return ControlFlow::Return(val),
-                                ControlFlow::Break => {
-                                    return ControlFlow::new_error(
-                                        env,
-                                        format!("Break not allowed catch block").into(),
-                                    )
-                                }
-                                ControlFlow::Continue => {
-                                    return ControlFlow::new_error(
-                                        env,
-                                        format!("Continue not allowed catch block ").into(),
-                                    )
-                                }
-                                ControlFlow::None => {}
-                                error => return error,
-                            }
-                        }
+                    // Sem cláusula compatível, o erro é propagado depois do finally
+                    outcome = ControlFlow::Error(error);
+
+                    // `?` não é capturado: o `None`/`Err` segue até o fim da função
+                    let clauses = if self.propagating.is_some() {
+                        &[][..]
                     } else {
-                        // Sem catch, propaga o erro depois do finally
-                        if let Some(finally_stmts) = finally_block {
-                            let _ = self.execute_block(finally_stmts, env);
+                        &catch_clauses[..]
+                    };
+                    for clause in clauses {
+                        let mut catch_env = Environment::new_rc_enclosed(env);
+                        match self.catch_clause_matches(clause, &caught, &mut catch_env) {
+                            Ok(true) => {
+                                outcome = self.execute_catch_body(&clause.body, &mut catch_env);
+                                outcome = self.exit_scope(&mut catch_env, outcome);
+                                break;
+                            }
+                            Ok(false) => continue,
+                            Err(error) => {
+                                outcome = error;
+                                break;
+                            }
                         }
-
-                        return ControlFlow::Error(error);
                     }
                 }
 
                 // Executa o bloco finally sempre
                 if let Some(finally_stmts) = finally_block {
-                    let _ = self.execute_block(finally_stmts, env);
+                    let mut finally_env = Environment::new_rc_enclosed(env);
+                    let _ = self.execute_block(finally_stmts, &mut finally_env);
+                    let _ = self.exit_scope(&mut finally_env, ControlFlow::None);
+                }
+                self.try_depth -= 1;
+
+                outcome
+            }
+            Stmt::InterfaceDecl {
+                name,
+                extends,
+                methods,
+            } => {
+                let mut interface_env = Environment::new_rc_enclosed(env);
+                let parents = match self.resolve_interfaces(extends, &mut interface_env) {
+                    Ok(parents) => parents,
+                    Err(error) => return error,
+                };
+
+                let mut required: Vec<RequiredMethod> = vec![];
+                let mut defaults: Vec<Rc<Function>> = vec![];
+                for method in methods {
+                    if method.modifiers.contains_str("abstract") {
+                        required.push(RequiredMethod {
+                            name: method.name.clone(),
+                            operator: method.modifiers.contains_str("operator"),
+                        });
+                        continue;
+                    }
+                    let function = Function::new(
+                        method.name.clone(),
+                        method.params.clone(),
+                        method.vararg.clone(),
+                        method.body.clone(),
+                        interface_env.clone(),
+                        method.modifiers.clone(),
+                    )
+                    .with_signature(method.signature.clone());
+                    defaults.push(Rc::new(function));
+                }
+                // Herda exigências e métodos padrão; os declarados aqui têm prioridade
+                for parent in &parents {
+                    if let ClassKind::Interface {
+                        required: parent_required,
+                    } = &parent.kind
+                    {
+                        for method in parent_required {
+                            let declared = required.iter().any(|own| own.name == method.name)
+                                || defaults.iter().any(|own| own.name == method.name);
+                            if !declared {
+                                required.push(method.clone());
+                            }
+                        }
+                    }
+                    for method in &parent.methods {
+                        if !defaults.iter().any(|own| own.name == method.name) {
+                            defaults.push(method.clone());
+                        }
+                    }
                 }
 
+                let mut interface = Class::new(
+                    name.clone(),
+                    defaults,
+                    None,
+                    Environment::new_rc(),
+                    vec![],
+                    Rc::new(RefCell::new(HashMap::new())),
+                    HashMap::new(),
+                    interface_env,
+                );
+                interface.kind = ClassKind::Interface { required };
+                interface.interfaces = parents;
+                env.borrow_mut()
+                    .define(name.clone(), Value::Class(Rc::new(interface)));
+
                 ControlFlow::None
             }
-            Stmt::Throw(expr) => {
+            Stmt::EnumDecl { name, variants } => {
+                let mut static_variables = HashMap::new();
+                let names = variants
+                    .iter()
+                    .map(|variant| variant.name.clone())
+                    .collect::<Vec<_>>();
+
+                for variant in variants {
+                    let class = Rc::new(Class::new_variant(
+                        name,
+                        &variant.name,
+                        variant.fields.clone(),
+                        env.clone(),
+                    ));
+                    // Variantes sem campos são valores únicos; as demais são construtores
+                    let value = if variant.fields.is_empty() {
+                        Class::instantiate(&class, vec![])
+                    } else {
+                        Value::Class(class)
+                    };
+                    static_variables.insert(variant.name.clone(), value);
+                }
+                let variant_names = names.iter().cloned().map(Value::String).collect();
+                static_variables.insert("variants".to_string(), Value::array(variant_names));
+
+                let mut class = Class::new(
+                    name.clone(),
+                    vec![],
+                    None,
+                    Environment::new_rc(),
+                    vec![],
+                    Rc::new(RefCell::new(HashMap::new())),
+                    static_variables,
+                    env.clone(),
+                );
+                class.kind = ClassKind::Enum(names);
+                env.borrow_mut()
+                    .define(name.clone(), Value::Class(Rc::new(class)));
+
+                ControlFlow::None
+            }
+            Stmt::Match {
+                subject,
+                arms,
+                location,
+            } => {
+                let value = self.eval_expr(subject, env);
+                if value.is_error() {
+                    return value;
+                }
+                let value = value.unwrap();
+
+                self.check_match_exhaustive(&value, arms, location);
+
+                for arm in arms {
+                    let mut arm_env = Environment::new_rc_enclosed(env);
+                    match self.match_pattern(&arm.pattern, &value, &mut arm_env) {
+                        Ok(true) => {
+                            let flow = self.execute_match_body(&arm.body, &mut arm_env);
+                            return self.exit_scope(&mut arm_env, flow);
+                        }
+                        Ok(false) => continue,
+                        Err(error) => return error,
+                    }
+                }
+
+                ControlFlow::new_typed_error(
+                    env,
+                    ErrorKind::TypeError,
+                    format!("No match arm for {}", value.to_string()),
+                )
+            }
+            Stmt::Defer(expr) => {
+                env.borrow_mut().deferred.push(expr.clone());
+                self.pending_defers += 1;
+                ControlFlow::None
+            }
+            Stmt::Using { name, value, body } => {
+                let resource = self.eval_expr(value, env);
+                if resource.is_error() {
+                    return resource;
+                }
+                let resource = resource.unwrap();
+
+                let mut using_env = Environment::new_rc_enclosed(env);
+                // `null` é aceito e não tem o que fechar
+                if !resource.is_null() {
+                    let Some(method) = resource.disposer() else {
+                        return ControlFlow::new_typed_error(
+                            env,
+                            ErrorKind::TypeError,
+                            format!(
+                                "'{}' has no close() or dispose() method",
+                                typecheck::describe(&resource)
+                            ),
+                        );
+                    };
+                    // Guardado à parte para fechar o valor original mesmo se `name` for reatribuído
+                    using_env
+                        .borrow_mut()
+                        .define("%using".to_string(), resource.clone());
+                    using_env.borrow_mut().deferred.push(Expr::Call {
+                        callee: Box::new(Expr::GetProperty {
+                            object: Box::new(Expr::Identifier("%using".to_string())),
+                            property: Box::new(Expr::Identifier(method.to_string())),
+                        }),
+                        args: vec![],
+                        location: SourceLocation::default(),
+                    });
+                    self.pending_defers += 1;
+                }
+                using_env.borrow_mut().define(name.clone(), resource);
+
+                let mut flow = ControlFlow::None;
+                for stmt in body {
+                    flow = self.eval_stmt(stmt, &mut using_env);
+                    if !matches!(flow, ControlFlow::None) {
+                        break;
+                    }
+                }
+                self.exit_scope(&mut using_env, flow)
+            }
+            Stmt::Throw(expr, _) => {
                 let value = self.eval_expr(expr, env);
                 // let error_class = env.borrow().get("Error");
                 // let error_class = error_class.unwrap();
@@ -2141,7 +3079,7 @@ impl Interpreter {
                 ControlFlow::Break => return ControlFlow::Break,
                 ControlFlow::Continue => break,
                 ControlFlow::Return(v) => {
-                    if matches!(stmt, &Stmt::Return(_)) {
+                    if matches!(stmt, &Stmt::Return(_, _)) {
                         return ControlFlow::Return(v);
                     }
                 }
@@ -2152,6 +3090,722 @@ impl Interpreter {
         ControlFlow::None
     }
 
+    /// Define o binding da cláusula em `catch_env` e verifica tipo e guarda.
+    fn catch_clause_matches(
+        &mut self,
+        clause: &CatchClause,
+        caught: &Value,
+        catch_env: &mut Rc<RefCell<Environment>>,
+    ) -> Result<bool, ControlFlow<Value>> {
+        if let Some(error_type) = &clause.error_type {
+            let class = self.eval_expr(error_type, catch_env);
+            if class.is_error() {
+                return Err(class);
+            }
+            let class = class.unwrap();
+            if !class.is_class() {
+                return Err(ControlFlow::new_typed_error(
+                    catch_env,
+                    ErrorKind::TypeError,
+                    format!("Catch type must be a class. got: {}", class.type_of()),
+                ));
+            }
+            if !Class::is_instance_of(caught, &class) {
+                return Ok(false);
+            }
+        }
+
+        match &clause.binding {
+            Some(CatchBinding::Identifier(name)) => {
+                catch_env.borrow_mut().define(name.clone(), caught.clone());
+            }
+            Some(CatchBinding::Object(fields)) => {
+                let entries = caught.own_entries().unwrap_or_default();
+                for (prop, local) in fields {
+                    let value = entries
+                        .iter()
+                        .find(|(key, _)| key == prop)
+                        .map(|(_, value)| value.clone())
+                        .unwrap_or(Value::Null);
+                    catch_env.borrow_mut().define(local.clone(), value);
+                }
+            }
+            None => {}
+        }
+
+        if let Some(guard) = &clause.guard {
+            let condition = self.eval_expr(guard, catch_env);
+            if condition.is_error() {
+                return Err(condition);
+            }
+            return Ok(condition.unwrap().is_truthy());
+        }
+        Ok(true)
+    }
+
+    fn execute_catch_body(
+        &mut self,
+        stmts: &[Stmt],
+        catch_env: &mut Rc<RefCell<Environment>>,
+    ) -> ControlFlow<Value> {
+        for stmt in stmts {
+            match self.eval_stmt(stmt, catch_env) {
+                ControlFlow::Return(val) => return ControlFlow::Return(val),
+                ControlFlow::Break => {
+                    return ControlFlow::new_error(
+                        catch_env,
+                        "Break not allowed catch block".to_string(),
+                    )
+                }
+                ControlFlow::Continue => {
+                    return ControlFlow::new_error(
+                        catch_env,
+                        "Continue not allowed catch block".to_string(),
+                    )
+                }
+                ControlFlow::None => {}
+                error => return error,
+            }
+        }
+        ControlFlow::None
+    }
+
+    /// Avalia a lista de `implements`/`extends` de interfaces.
+    fn resolve_interfaces(
+        &mut self,
+        exprs: &[Expr],
+        env: &mut Rc<RefCell<Environment>>,
+    ) -> Result<Vec<Rc<Class>>, ControlFlow<Value>> {
+        let mut interfaces = vec![];
+        for expr in exprs {
+            let value = self.eval_expr(expr, env);
+            if value.is_error() {
+                return Err(value);
+            }
+            match value.unwrap() {
+                Value::Class(class) if class.is_interface() => interfaces.push(class),
+                other => {
+                    return Err(ControlFlow::new_typed_error(
+                        env,
+                        ErrorKind::TypeError,
+                        format!("'{}' is not an interface", other.to_string()),
+                    ))
+                }
+            }
+        }
+        Ok(interfaces)
+    }
+
+    /// Avalia os argumentos de uma chamada: posicionais (com `...spread`) e nomeados.
+    fn eval_call_args(
+        &mut self,
+        args: &[Expr],
+        env: &mut Rc<RefCell<Environment>>,
+    ) -> Result<CallArgs, ControlFlow<Value>> {
+        let mut evaluated_args = vec![];
+        let mut named_args = HashMap::new();
+
+        for arg_expr in args {
+            match arg_expr {
+                Expr::Spread(inner_expr) => {
+                    let val = self.eval_expr(inner_expr, env);
+                    if val.is_error() {
+                        return Err(val);
+                    }
+                    let val = val.unwrap();
+
+                    match val {
+                        Value::Array(arr) => {
+                            let arr = arr.get_value().clone();
+                            evaluated_args.extend(arr.borrow().clone());
+                        }
+                        Value::Object(map) => {
+                            let map = map.borrow().clone();
+                            for (_, v) in map {
+                                evaluated_args.push(v);
+                            }
+                        }
+                        _ => {
+                            return Err(ControlFlow::new_typed_error(
+                                env,
+                                ErrorKind::TypeError,
+                                format!("Cannot spread {:?}", val.type_of()),
+                            ))
+                        }
+                    }
+                }
+                Expr::NamedArg { name, value } => {
+                    let val = self.eval_expr(value, env);
+                    if val.is_err() {
+                        return Err(val);
+                    }
+                    named_args.insert(name.clone(), val.unwrap());
+                }
+                _ => {
+                    let val = self.eval_expr(arg_expr, env);
+
+                    if val.is_err() {
+                        return Err(val);
+                    }
+
+                    let val = val.unwrap();
+                    evaluated_args.push(val);
+                }
+            }
+        }
+        Ok((evaluated_args, named_args))
+    }
+
+    /// Itens percorridos por `for ... of` e pelas compreensões: arrays, caracteres de
+    /// strings e o `iter()` de instâncias de `Collection`.
+    fn iterate(
+        &mut self,
+        iterable: &Value,
+        env: &mut Rc<RefCell<Environment>>,
+    ) -> Result<Vec<Value>, ControlFlow<Value>> {
+        match iterable {
+            Value::Array(arr) => Ok(arr.get_value().borrow().clone()),
+            Value::String(s) => Ok(s.chars().map(|c| Value::String(c.to_string())).collect()),
+            Value::Instance(instance) => {
+                let collection_class = env.borrow().get("Collection");
+                let collection_class = collection_class.unwrap();
+
+                if !instance.borrow().is_instance_of(&collection_class) {
+                    return Err(ControlFlow::new_typed_error(
+                        env,
+                        ErrorKind::TypeError,
+                        "Not a collection".into(),
+                    ));
+                }
+                let iter_method = instance.borrow().get("iter");
+
+                let arr = iter_method
+                    .unwrap()
+                    .to_method()
+                    .call(vec![iterable.clone()]);
+                Ok(arr.to_array())
+            }
+            other => Err(ControlFlow::new_typed_error(
+                env,
+                ErrorKind::TypeError,
+                format!("'{}' is not iterable", other.type_of()),
+            )),
+        }
+    }
+
+    /// Executa as cláusulas de uma compreensão a partir de `clauses[0]`, chamando
+    /// `emit` no escopo mais interno para cada combinação que passa pelos `if`.
+    fn eval_clauses(
+        &mut self,
+        clauses: &[ComprehensionClause],
+        env: &mut Rc<RefCell<Environment>>,
+        emit: &mut ComprehensionEmit,
+    ) -> Option<ControlFlow<Value>> {
+        let Some((clause, rest)) = clauses.split_first() else {
+            return emit(self, env);
+        };
+        match clause {
+            ComprehensionClause::For { target, iterable } => {
+                let iterable = self.eval_expr(iterable, env);
+                if iterable.is_error() {
+                    return Some(iterable);
+                }
+                let items = match self.iterate(&iterable.unwrap(), env) {
+                    Ok(items) => items,
+                    Err(err) => return Some(err),
+                };
+                for item in items {
+                    // Cada item tem o próprio escopo, como no `for ... of`
+                    let mut inner = Environment::new_rc_enclosed(env);
+                    self.destructure(target, item, &mut inner);
+                    if let Some(err) = self.eval_clauses(rest, &mut inner, emit) {
+                        return Some(err);
+                    }
+                }
+                None
+            }
+            ComprehensionClause::If(condition) => {
+                let condition = self.eval_expr(condition, env);
+                if condition.is_error() {
+                    return Some(condition);
+                }
+                if condition.unwrap().to_bool() {
+                    return self.eval_clauses(rest, env, emit);
+                }
+                None
+            }
+        }
+    }
+
+    /// `valor |> alvo`: o valor é avaliado uma vez, guardado numa variável oculta e
+    /// passado no lugar dos `_` da chamada alvo, ou como primeiro argumento.
+    fn eval_pipe(
+        &mut self,
+        value: &Expr,
+        target: &Expr,
+        location: &SourceLocation,
+        env: &mut Rc<RefCell<Environment>>,
+    ) -> ControlFlow<Value> {
+        // A chamada gerada não é de cauda: o `return` vale para o encadeamento inteiro
+        self.tail_position = false;
+        let value = self.eval_expr(value, env);
+        if value.is_error() {
+            return value;
+        }
+
+        let mut call_env = Environment::new_rc_enclosed(env);
+        call_env
+            .borrow_mut()
+            .define("%pipe".to_string(), value.unwrap());
+        let piped = Expr::Identifier("%pipe".to_string());
+        let is_placeholder = |arg: &Expr| matches!(arg, Expr::Identifier(name) if name == "_");
+
+        let call = match target {
+            Expr::Call {
+                callee,
+                args,
+                location,
+            } => {
+                let mut placed = false;
+                let mut args: Vec<Expr> = args
+                    .iter()
+                    .map(|arg| match arg {
+                        arg if is_placeholder(arg) => {
+                            placed = true;
+                            piped.clone()
+                        }
+                        Expr::NamedArg { name, value } if is_placeholder(value) => {
+                            placed = true;
+                            Expr::NamedArg {
+                                name: name.clone(),
+                                value: Box::new(piped.clone()),
+                            }
+                        }
+                        arg => arg.clone(),
+                    })
+                    .collect();
+                if !placed {
+                    args.insert(0, piped);
+                }
+                Expr::Call {
+                    callee: callee.clone(),
+                    args,
+                    location: location.clone(),
+                }
+            }
+            callee => Expr::Call {
+                callee: Box::new(callee.clone()),
+                args: vec![piped],
+                location: location.clone(),
+            },
+        };
+        self.eval_expr(&call, &mut call_env)
+    }
+
+    /// Modo `LANG_IO_RESULTS`: o resultado de um método de I/O vira `Ok(valor)` e a
+    /// falha `Err(mensagem)`, em vez de um `IoError` lançado.
+    fn io_result(
+        call: ControlFlow<Value>,
+        env: &mut Rc<RefCell<Environment>>,
+    ) -> ControlFlow<Value> {
+        let (variant, value) = match call {
+            ControlFlow::Return(Value::Error(error)) => {
+                return ControlFlow::Error(error.borrow().clone())
+            }
+            ControlFlow::Return(value) => ("Ok", value),
+            ControlFlow::None => ("Ok", Value::Void),
+            ControlFlow::Error(message) => ("Err", message),
+            other => return other,
+        };
+        let constructor = env
+            .borrow()
+            .get("Result")
+            .and_then(|result| result.to_class())
+            .and_then(|result| result.get_static_field(variant));
+        let Some(Value::Class(constructor)) = constructor else {
+            return ControlFlow::new_typed_error(
+                env,
+                ErrorKind::ReferenceError,
+                "Result is not defined".to_string(),
+            );
+        };
+        match Class::instantiate_variant(&constructor, vec![value]) {
+            Ok(result) => ControlFlow::Return(result),
+            Err(err) => ControlFlow::new_typed_error(env, ErrorKind::TypeError, err),
+        }
+    }
+
+    /// Resultado de um método `operator`: erros lançados por ele viram `ControlFlow::Error`.
+    fn operator_result(value: Value) -> ControlFlow<Value> {
+        match value {
+            Value::Error(error) => ControlFlow::Error(error.borrow().clone()),
+            value => ControlFlow::Return(value),
+        }
+    }
+
+    /// Verdadeiro se a comparação é resolvida por `operator eq`, `compare` ou `contains`.
+    fn is_overloaded_compare(op: &CompareOperator, a: &Value, b: &Value) -> bool {
+        match op {
+            CompareOperator::Eq | CompareOperator::Ne => {
+                a.has_operator("eq") || b.has_operator("eq")
+            }
+            CompareOperator::Gt
+            | CompareOperator::Ge
+            | CompareOperator::Lt
+            | CompareOperator::Le => a.has_operator("compare") || b.has_operator("compare"),
+            CompareOperator::In => b.has_operator("contains"),
+            _ => false,
+        }
+    }
+
+    /// `a == b` chama `eq`, `a < b` usa o sinal de `compare` e `a in b` chama
+    /// `b.contains(a)`. Se só o operando da direita declara o método, ele é
+    /// chamado com os operandos trocados e o resultado de `compare` é invertido.
+    fn overloaded_compare(
+        op: &CompareOperator,
+        a: &Value,
+        b: &Value,
+        env: &mut Rc<RefCell<Environment>>,
+    ) -> ControlFlow<Value> {
+        let (alias, reflected) = match op {
+            CompareOperator::Eq | CompareOperator::Ne => ("eq", !a.has_operator("eq")),
+            CompareOperator::In => ("contains", true),
+            _ => ("compare", !a.has_operator("compare")),
+        };
+        let result = match reflected {
+            true => b.call_operator(alias, vec![a.clone()]),
+            false => a.call_operator(alias, vec![b.clone()]),
+        };
+        let result = match result {
+            Some(Value::Error(error)) => return ControlFlow::Error(error.borrow().clone()),
+            Some(result) => result,
+            None => Value::Null,
+        };
+
+        let value = match op {
+            CompareOperator::Eq | CompareOperator::In => result.is_truthy(),
+            CompareOperator::Ne => !result.is_truthy(),
+            _ => {
+                let Value::Number(order) = &result else {
+                    let msg = format!(
+                        "operator compare must return a number, got {}",
+                        result.type_of()
+                    );
+                    return ControlFlow::new_typed_error(env, ErrorKind::TypeError, msg);
+                };
+                let order = match reflected {
+                    true => -order.get_value(),
+                    false => order.get_value(),
+                };
+                match op {
+                    CompareOperator::Gt => order > 0.0,
+                    CompareOperator::Ge => order >= 0.0,
+                    CompareOperator::Lt => order < 0.0,
+                    _ => order <= 0.0,
+                }
+            }
+        };
+        ControlFlow::Return(Value::Bool(value))
+    }
+
+    /// Aplica os decoradores de baixo para cima (`@a @b fn f` vira `a(b(f))`) e
+    /// registra cada um no valor retornado, para leitura com `decorators(alvo)`.
+    fn apply_decorators(
+        &mut self,
+        decorators: &[Decorator],
+        target: Value,
+        env: &mut Rc<RefCell<Environment>>,
+    ) -> ControlFlow<Value> {
+        let mut value = target;
+
+        for decorator in decorators.iter().rev() {
+            let (args, named_args) = match self.eval_call_args(&decorator.args, env) {
+                Ok(args) => args,
+                Err(err) => return err,
+            };
+
+            // Avalia `nome(alvo, ...args, nome: valor)` como uma chamada comum, com os
+            // valores em variáveis que o código do usuário não consegue nomear
+            let mut call_env = Environment::new_rc_enclosed(env);
+            call_env
+                .borrow_mut()
+                .define("%target".to_string(), value.clone());
+            call_env
+                .borrow_mut()
+                .define("%args".to_string(), Value::array(args.clone()));
+            let mut call_args = vec![
+                Expr::Identifier("%target".to_string()),
+                Expr::Spread(Box::new(Expr::Identifier("%args".to_string()))),
+            ];
+            for (name, arg) in named_args {
+                let hidden = format!("%{name}");
+                call_env.borrow_mut().define(hidden.clone(), arg);
+                call_args.push(Expr::NamedArg {
+                    name,
+                    value: Box::new(Expr::Identifier(hidden)),
+                });
+            }
+            let call = Expr::Call {
+                callee: Box::new(decorator.callee.clone()),
+                args: call_args,
+                location: decorator.location.clone(),
+            };
+            let result = self.eval_expr(&call, &mut call_env);
+            if result.is_error() {
+                return result;
+            }
+            let result = result.unwrap();
+
+            let mut decorations = vec![Decoration {
+                name: decorator.name.clone(),
+                args,
+            }];
+            decorations.extend(value.decorations());
+            result.set_decorations(decorations);
+            value = result;
+        }
+
+        ControlFlow::Return(value)
+    }
+
+    /// Métodos decorados continuam com o nome e os modificadores da declaração.
+    fn decorate_method(
+        &mut self,
+        decl: &MethodDecl,
+        method: Function,
+        env: &mut Rc<RefCell<Environment>>,
+    ) -> Result<Rc<Function>, ControlFlow<Value>> {
+        if decl.decorators.is_empty() {
+            return Ok(Rc::new(method));
+        }
+        let decorated =
+            self.apply_decorators(&decl.decorators, Value::Function(method.into()), env);
+        if decorated.is_error() {
+            return Err(decorated);
+        }
+        match decorated.unwrap() {
+            Value::Function(function) => {
+                let mut function = Function::from(function);
+                function.name = decl.name.clone();
+                function.modifiers = decl.modifiers.clone();
+                Ok(Rc::new(function))
+            }
+            other => {
+                let msg = format!(
+                    "Decorators of method '{}' must return a function, got {}",
+                    decl.name,
+                    other.type_of()
+                );
+                Err(ControlFlow::new_typed_error(env, ErrorKind::TypeError, msg))
+            }
+        }
+    }
+
+    /// Métodos de `required` que não estão em `methods`.
+    fn missing_methods(
+        required: &[RequiredMethod],
+        methods: &[Rc<Function>],
+    ) -> Vec<RequiredMethod> {
+        required
+            .iter()
+            .filter(|required| {
+                !methods.iter().any(|method| {
+                    method.name == required.name && (!required.operator || method.is_operator())
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// `'area', 'operator add'` para as mensagens de erro.
+    fn quote_methods(methods: &[RequiredMethod]) -> String {
+        methods
+            .iter()
+            .map(|method| format!("'{method}'"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Modo estrito: recusa atribuir a um campo anotado um valor de outro tipo.
+    fn check_field_type(
+        instance: &Instance,
+        key: &str,
+        value: &Value,
+        env: &mut Rc<RefCell<Environment>>,
+    ) -> Option<ControlFlow<Value>> {
+        if !typecheck::is_strict() {
+            return None;
+        }
+        let ty = instance.class.field_types.get(key)?;
+        if typecheck::value_matches(value, ty, env) {
+            return None;
+        }
+        Some(ControlFlow::new_typed_error(
+            env,
+            ErrorKind::TypeError,
+            format!(
+                "Field '{}.{key}' is declared as {ty}, got {}",
+                instance.class.name,
+                typecheck::describe(value)
+            ),
+        ))
+    }
+
+    /// Testa um padrão de `match`, definindo os bindings da variante em `arm_env`.
+    fn match_pattern(
+        &mut self,
+        pattern: &MatchPattern,
+        value: &Value,
+        arm_env: &mut Rc<RefCell<Environment>>,
+    ) -> Result<bool, ControlFlow<Value>> {
+        let (path, bindings) = match pattern {
+            MatchPattern::Wildcard => return Ok(true),
+            MatchPattern::Variant { path, bindings } => (path, Some(bindings)),
+            MatchPattern::Value(expr) => (expr, None),
+        };
+
+        let expected = self.eval_expr(path, arm_env);
+        if expected.is_error() {
+            return Err(expected);
+        }
+        let expected = expected.unwrap();
+
+        let Some(bindings) = bindings else {
+            if expected.is_class() {
+                return Ok(Class::is_instance_of(value, &expected));
+            }
+            return Ok(value.loose_equal(&expected));
+        };
+
+        let variant = match &expected {
+            Value::Class(class) if class.variant_fields().is_some() => class,
+            _ => {
+                return Err(ControlFlow::new_typed_error(
+                    arm_env,
+                    ErrorKind::TypeError,
+                    format!("'{}' is not an enum variant", expected.to_string()),
+                ))
+            }
+        };
+        let Value::Instance(instance) = value else {
+            return Ok(false);
+        };
+        let instance = instance.borrow();
+        if !Rc::ptr_eq(&instance.class, variant) {
+            return Ok(false);
+        }
+
+        let fields = variant.variant_fields().cloned().unwrap_or_default();
+        if bindings.len() != fields.len() {
+            return Err(ControlFlow::new_typed_error(
+                arm_env,
+                ErrorKind::TypeError,
+                format!(
+                    "{} has {} field(s), pattern binds {}",
+                    variant.qualified_name(),
+                    fields.len(),
+                    bindings.len()
+                ),
+            ));
+        }
+        for (binding, field) in bindings.iter().zip(fields) {
+            if binding == "_" {
+                continue;
+            }
+            let field_value = instance.this.borrow().get(&field).unwrap_or(Value::Null);
+            arm_env.borrow_mut().define(binding.clone(), field_value);
+        }
+        Ok(true)
+    }
+
+    fn execute_match_body(
+        &mut self,
+        stmts: &[Stmt],
+        arm_env: &mut Rc<RefCell<Environment>>,
+    ) -> ControlFlow<Value> {
+        for stmt in stmts {
+            match self.eval_stmt(stmt, arm_env) {
+                ControlFlow::None => {}
+                flow => return flow,
+            }
+        }
+        ControlFlow::None
+    }
+
+    /// Avisa (uma vez por local) quando um `match` sobre um enum não cobre todas as
+    /// variantes e não tem `_`.
+    fn check_match_exhaustive(&self, value: &Value, arms: &[MatchArm], location: &SourceLocation) {
+        let Value::Instance(instance) = value else {
+            return;
+        };
+        let Some(enum_name) = instance.borrow().class.enum_name() else {
+            return;
+        };
+        let Some(Value::Class(enum_class)) =
+            instance.borrow().class.closure.borrow().get(&enum_name)
+        else {
+            return;
+        };
+        let ClassKind::Enum(variants) = &enum_class.kind else {
+            return;
+        };
+
+        let mut covered = vec![];
+        for arm in arms {
+            let path = match &arm.pattern {
+                MatchPattern::Wildcard => return,
+                MatchPattern::Variant { path, .. } | MatchPattern::Value(path) => path,
+            };
+            match path {
+                Expr::GetProperty { property, .. } => match property.as_ref() {
+                    Expr::Identifier(name) => covered.push(name.clone()),
+                    _ => return,
+                },
+                // Padrões que não nomeiam uma variante podem cobrir qualquer valor
+                _ => return,
+            }
+        }
+
+        let missing = variants
+            .iter()
+            .filter(|variant| !covered.contains(variant))
+            .map(|variant| format!("{enum_name}.{variant}"))
+            .collect::<Vec<_>>();
+        if missing.is_empty() {
+            return;
+        }
+
+        let first = MATCH_WARNINGS.with(|seen| {
+            seen.borrow_mut()
+                .insert((location.file.clone(), location.line))
+        });
+        if first {
+            eprintln!(
+                "warning: non-exhaustive match at {location}: missing {}",
+                missing.join(", ")
+            );
+        }
+    }
+
+    /// Sai do escopo `env`: avalia os `defer` pendentes do mais recente ao mais antigo.
+    /// Um erro num `defer` substitui o resultado do bloco, a menos que ele já seja um erro.
+    pub fn exit_scope(
+        &mut self,
+        env: &mut Rc<RefCell<Environment>>,
+        flow: ControlFlow<Value>,
+    ) -> ControlFlow<Value> {
+        let deferred = std::mem::take(&mut env.borrow_mut().deferred);
+        let mut flow = flow;
+        for expr in deferred.iter().rev() {
+            self.pending_defers -= 1;
+            let result = self.eval_expr(expr, env);
+            if result.is_error() && !flow.is_error() {
+                flow = result;
+            }
+        }
+        flow
+    }
+
     pub fn execute_try_block(
         &mut self,
         stmts: &Vec<Stmt>,
@@ -2193,8 +3847,7 @@ impl Interpreter {
         }
 
         let source = std::fs::read_to_string(path).unwrap();
-        let tokens = self.tokenize(source, path.to_string());
-        let ast = Parser::new(tokens).parse();
+        let ast = self.parser_for(source, path.to_string()).parse();
 
         let mut module_env = Environment::new_rc();
         self.exported_symbols.clear();
@@ -2202,6 +3855,7 @@ impl Interpreter {
         for stmt in ast {
             self.eval_stmt(&stmt, &mut module_env);
         }
+        let _ = self.exit_scope(&mut module_env, ControlFlow::None);
 
         // println!("Env: {:?}",module_env.borrow_mut().get_vars_name_value());
         let export_only_env = Environment::new_rc();
@@ -2224,6 +3878,9 @@ impl Interpreter {
             Stmt::Let { name, .. } => Some(name.clone()),
             Stmt::FuncDecl(FunctionStmt { name, .. }) => Some(name.clone()),
             Stmt::ClassDecl { name, .. } => Some(name.clone()),
+            Stmt::EnumDecl { name, .. } => Some(name.clone()),
+            Stmt::InterfaceDecl { name, .. } => Some(name.clone()),
+            Stmt::Decorated { target, .. } => self.get_export_name(target),
             // adicione outras formas se precisar
             _ => None,
         }
@@ -1,4 +1,5 @@
 use logos::{Lexer, Logos};
+use num_bigint::BigInt;
 
 #[derive(Debug, Logos, PartialEq, Clone)]
 #[logos(skip r"[ \t\r\n\f]+")]
@@ -74,9 +75,23 @@ pub enum Token {
     Identifier(String),
 
     // Literais
-    #[regex(r"-?(?:0|[1-9](?:_?\d)*)(?:\.\d(?:_?\d)*)?(?:[eE][+-]?\d(?:_?\d)*)?", |lex| lex.slice().replace('_', "").parse::<f64>().unwrap())]
+    #[regex(r"-?(?:0|[1-9](?:_?\d)*)(?:\.\d(?:_?\d)*(?:[eE][+-]?\d(?:_?\d)*)?|[eE][+-]?\d(?:_?\d)*)", |lex| lex.slice().replace('_', "").parse::<f64>().unwrap())]
+    #[token("Infinity", |_| f64::INFINITY)]
+    #[token("NaN", |_| f64::NAN)]
     Number(f64),
 
+    #[regex(r"-?(?:0|[1-9](?:_?\d)*)", |lex| lex.slice().replace('_', "").parse::<i64>().ok())]
+    #[regex(r"-?0[xX][0-9a-fA-F](?:_?[0-9a-fA-F])*", |lex| parse_radix(lex.slice(), 16))]
+    #[regex(r"-?0[oO][0-7](?:_?[0-7])*", |lex| parse_radix(lex.slice(), 8))]
+    #[regex(r"-?0[bB][01](?:_?[01])*", |lex| parse_radix(lex.slice(), 2))]
+    Int(i64),
+
+    #[regex(r"-?(?:0|[1-9](?:_?\d)*)n", |lex| lex.slice().trim_end_matches('n').replace('_', ""))]
+    BigInt(String),
+
+    #[regex(r"-?(?:0|[1-9](?:_?\d)*)(?:\.\d(?:_?\d)*)?m", |lex| lex.slice().trim_end_matches('m').replace('_', ""))]
+    Decimal(String),
+
     #[token("false", |_| false)]
     #[token("true", |_| true)]
     Bool(bool),
@@ -118,6 +133,9 @@ pub enum Token {
     #[token("/")]
     Slash,
 
+    #[token("~/")]
+    TildeSlash,
+
     // Lang operators
     #[token("...")]
     Ellipsis,
@@ -176,6 +194,12 @@ pub enum Token {
     #[token("||")]
     Or,
 
+    #[token("|>")]
+    Pipe,
+
+    #[token("?")]
+    Question,
+
     #[token("!")]
     Not,
 
@@ -186,6 +210,12 @@ pub enum Token {
     #[token("!=")]
     NotEqual,
 
+    #[token("===")]
+    StrictEqual,
+
+    #[token("!==")]
+    StrictNotEqual,
+
     #[token("<")]
     Less,
 
@@ -216,6 +246,9 @@ impl Token {
         match self {
             Token::Identifier(id) => id.to_string(),
             Token::Number(n) => n.to_string(),
+            Token::Int(n) => n.to_string(),
+            Token::BigInt(n) => format!("{n}n"),
+            Token::Decimal(n) => format!("{n}m"),
             Token::Bool(b) => b.to_string(),
             Token::String(s) => s.to_string(),
             Token::Null => "null".to_string(),
@@ -230,6 +263,7 @@ impl Token {
             Token::Minus => "-".to_string(),
             Token::Asterisk => "*".to_string(),
             Token::Slash => "/".to_string(),
+            Token::TildeSlash => "~/".to_string(),
             Token::Ellipsis => "...".to_string(),
             Token::Arrow => "->".to_string(),
             Token::Dot => ".".to_string(),
@@ -248,9 +282,13 @@ impl Token {
             Token::Colon => ":".to_string(),
             Token::And => "&&".to_string(),
             Token::Or => "||".to_string(),
+            Token::Pipe => "|>".to_string(),
+            Token::Question => "?".to_string(),
             Token::Not => "!".to_string(),
             Token::Equal => "==".to_string(),
             Token::NotEqual => "!=".to_string(),
+            Token::StrictEqual => "===".to_string(),
+            Token::StrictNotEqual => "!==".to_string(),
             Token::Less => "<".to_string(),
             Token::LessEqual => "<=".to_string(),
             Token::Greater => ">".to_string(),
@@ -267,6 +305,34 @@ fn parse_error(lex: &mut Lexer<Token>) -> String {
     id
 }
 
+/// Converte literais como `0xFF`, `-0o755` e `0b1010` (o prefixo é ignorado).
+fn parse_radix(slice: &str, radix: u32) -> Option<i64> {
+    let (sign, digits) = match slice.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, slice),
+    };
+    let digits = digits[2..].replace('_', "");
+    i64::from_str_radix(&digits, radix).ok().map(|n| sign * n)
+}
+
+/// Texto decimal de um literal inteiro (decimal, hex, octal ou binário) que não
+/// cabe em i64; o lexer o promove para `Token::BigInt`, como no overflow aritmético.
+pub fn oversized_int_literal(slice: &str) -> Option<String> {
+    let (sign, digits) = match slice.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", slice),
+    };
+    let digits = digits.replace('_', "");
+    let (radix, digits) = match digits.get(..2) {
+        Some("0x" | "0X") => (16, &digits[2..]),
+        Some("0o" | "0O") => (8, &digits[2..]),
+        Some("0b" | "0B") => (2, &digits[2..]),
+        _ => (10, &digits[..]),
+    };
+    let value = BigInt::parse_bytes(digits.as_bytes(), radix)?;
+    Some(format!("{sign}{value}"))
+}
+
 fn parser_identifier(lex: &mut Lexer<Token>) -> String {
     let id = lex.slice().to_string();
     id
@@ -3,3 +3,4 @@ pub mod environment;
 pub mod interpreter;
 pub mod lexer;
 pub mod parsers;
+pub mod typecheck;
@@ -1,6 +1,62 @@
-use lang::interpreter::Interpreter;
+use std::{env, process, thread};
+
+use lang::{
+    environment::stdlib::option,
+    interpreter::{call_stack, Interpreter},
+    typecheck,
+};
+
+/// Pilha nativa da thread do interpretador. Cada chamada do script ocupa vários
+/// frames Rust (`eval_expr` -> `Function::call` -> `eval_stmt`), então o limite
+/// de `LANG_MAX_CALL_DEPTH` precisa ser atingido antes de um estouro real. Em
+/// debug os frames são bem maiores; aí `call_stack::set_native_stack` faz o
+/// `RangeError` surgir antes da profundidade configurada.
+const INTERPRETER_STACK_SIZE: usize = 1024 * 1024 * 1024;
 
 fn main() {
-    let mut interpreter = Interpreter::new_empty();
-    interpreter.interpret_bench();
+    let max_depth = env::var("LANG_MAX_CALL_DEPTH")
+        .ok()
+        .and_then(|depth| depth.parse().ok())
+        .unwrap_or(call_stack::DEFAULT_MAX_DEPTH);
+    let strict = env::var("LANG_STRICT").is_ok_and(|value| value == "1" || value == "true");
+    let io_results = env::var("LANG_IO_RESULTS").is_ok_and(|value| value == "1" || value == "true");
+
+    let interpreter = thread::Builder::new()
+        .name("interpreter".to_string())
+        .stack_size(INTERPRETER_STACK_SIZE)
+        .spawn(move || {
+            call_stack::set_native_stack(INTERPRETER_STACK_SIZE);
+            call_stack::set_max_depth(max_depth);
+            typecheck::set_strict(strict);
+            option::set_io_results(io_results);
+            let mut interpreter = Interpreter::new_empty();
+
+            // `lang check arquivo.x`: só verifica os tipos, sem executar
+            if env::args().nth(1).as_deref() == Some("check") {
+                let Some(filename) = env::args().nth(2) else {
+                    eprintln!("usage: lang check <file>");
+                    return 2;
+                };
+                let diagnostics = interpreter.check(&filename);
+                for diagnostic in &diagnostics {
+                    eprintln!("{diagnostic}");
+                }
+                if diagnostics.is_empty() {
+                    println!("No type errors found");
+                    return 0;
+                }
+                eprintln!("{} type error(s) found", diagnostics.len());
+                return 1;
+            }
+
+            interpreter.interpret_bench();
+            0
+        })
+        .expect("failed to spawn interpreter thread");
+
+    match interpreter.join() {
+        Ok(0) => {}
+        Ok(code) => process::exit(code),
+        Err(_) => process::exit(101),
+    }
 }
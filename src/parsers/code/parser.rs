@@ -2,21 +2,38 @@ use std::collections::HashMap;
 // use std::rc::Rc; // Troca para BOX para export e ExportAll
 
 use crate::ast::ast::{
-    AssignOperator, BinaryOperator, CompareOperator, Expr, FunctionStmt, Literal, LogicalOperator,
-    MethodDecl, Modifiers, ObjectEntry, Operator, Stmt, UnaryOperator,
+    AssignOperator, BinaryOperator, CatchBinding, CatchClause, CompareOperator,
+    ComprehensionClause, Decorator, EnumVariant, Expr, FunctionStmt, Literal, LogicalOperator,
+    MatchArm, MatchPattern, MethodDecl, Modifiers, ObjectEntry, Operator, Signature,
+    SourceLocation, Stmt, TypeAnnotation, UnaryOperator,
 };
 use crate::lexer::tokens::Token;
 
 #[derive(Debug)]
 pub struct Parser {
     tokens: Vec<Token>,
+    lines: Vec<usize>, // linha de cada token, em paralelo a `tokens`
+    file: String,
     pos: usize,
 }
 
 #[allow(unused)]
 impl Parser {
     pub fn new(tokens: Vec<Token>) -> Self {
-        Self { tokens, pos: 0 }
+        Self {
+            tokens,
+            lines: vec![],
+            file: String::new(),
+            pos: 0,
+        }
+    }
+    pub fn new_with_lines(tokens: Vec<Token>, lines: Vec<usize>, file: String) -> Self {
+        Self {
+            tokens,
+            lines,
+            file,
+            pos: 0,
+        }
     }
 
     pub fn parse(&mut self) -> Vec<Stmt> {
@@ -32,6 +49,7 @@ impl Parser {
     }
 
     fn parse_stmt(&mut self) -> Option<Stmt> {
+        let location = self.next_location();
         let stmt = match self.peek()? {
             Token::Identifier(s) if s == "let" => self.parse_var_decl(),
             Token::Identifier(s) if ["fn", "function"].contains(&s.as_str()) => {
@@ -47,9 +65,23 @@ impl Parser {
             Token::Identifier(s) if s == "if" => self.parse_if_stmt(),
             Token::Identifier(s) if s == "try" => self.parse_try_stmt(),
             Token::Identifier(s) if s == "throw" => self.parse_throw_stmt(),
-            Token::BraceOpen => Some(Stmt::ExprStmt(self.parse_brace()?)),
-            Token::Identifier(s) if s == "class" => self.parse_class_decl(),
-            _ => Some(Stmt::ExprStmt(self.parse_expr()?)),
+            Token::Identifier(s) if s == "defer" => self.parse_defer_stmt(),
+            Token::Identifier(s) if s == "using" && self.peek_next() == Some(&Token::ParenOpen) => {
+                self.parse_using_stmt()
+            }
+            Token::BraceOpen => Some(Stmt::ExprStmt(self.parse_brace()?, location)),
+            Token::Identifier(s) if s == "class" => self.parse_class_decl(vec![]),
+            Token::Identifier(s) if s == "abstract" && self.peek_next_is_keyword("class") => {
+                self.next(); // consume 'abstract'
+                self.parse_class_decl(vec![Modifiers::Abstract])
+            }
+            Token::Identifier(s) if s == "enum" => self.parse_enum_decl(),
+            Token::Identifier(s) if s == "interface" => self.parse_interface_decl(),
+            Token::Identifier(s) if Self::is_decorator(s) => self.parse_decorated_stmt(),
+            Token::Identifier(s) if s == "match" && self.peek_next() == Some(&Token::ParenOpen) => {
+                self.parse_match_stmt()
+            }
+            _ => Some(Stmt::ExprStmt(self.parse_expr()?, location)),
         };
         // Se houver um ponto e vírgula depois do statement, consome
         self.consume(&Token::Semicolon);
@@ -59,30 +91,44 @@ impl Parser {
 
     fn parse_throw_stmt(&mut self) -> Option<Stmt> {
         self.expect_keyword("throw");
+        let location = self.location();
         let expr = self.parse_expr()?;
         self.consume(&Token::Semicolon);
-        Some(Stmt::Throw(expr))
+        Some(Stmt::Throw(expr, location))
     }
 
-    fn parse_try_stmt(&mut self) -> Option<Stmt> {
-        self.expect_keyword("try");
-        let try_block = self.parse_block();
+    fn parse_defer_stmt(&mut self) -> Option<Stmt> {
+        self.expect_keyword("defer");
+        let expr = self.parse_expr()?;
+        self.consume(&Token::Semicolon);
+        Some(Stmt::Defer(expr))
+    }
 
-        let catch_block = if self.expect_keyword("catch") {
-            self.expect(&Token::ParenOpen);
+    // using (let f = abrir(caminho)) { ... }
+    fn parse_using_stmt(&mut self) -> Option<Stmt> {
+        self.expect_keyword("using");
+        self.expect(&Token::ParenOpen);
+        self.consume_keyword("let");
+        let name = match self.next() {
+            Some(Token::Identifier(name)) => name,
+            other => panic!("Error: Expected identifier in using, got {:?}", other),
+        };
+        self.expect(&Token::Assign);
+        let value = self.parse_expr()?;
+        self.expect(&Token::ParenClose);
+        let body = self.parse_block();
 
-            let identifier = match self.next()? {
-                Token::Identifier(name) => name,
-                _ => panic!("Expected identifier after '('"),
-            };
+        Some(Stmt::Using { name, value, body })
+    }
 
-            self.expect(&Token::ParenClose);
+    fn parse_try_stmt(&mut self) -> Option<Stmt> {
+        self.expect_keyword("try");
+        let try_block = self.parse_block();
 
-            let block = self.parse_block();
-            Some((identifier, block))
-        } else {
-            None
-        };
+        let mut catch_clauses = vec![];
+        while self.expect_keyword("catch") {
+            catch_clauses.push(self.parse_catch_clause());
+        }
 
         let finally_block = if self.expect_keyword("finally") {
             Some(self.parse_block())
@@ -92,10 +138,73 @@ impl Parser {
 
         Some(Stmt::TryCatchFinally {
             try_block,
-            catch_block,
+            catch_clauses,
             finally_block,
         })
     }
+    // catch { } | catch (e) | catch (e: Tipo) | catch ({ name, message: msg }) [if (cond)]
+    fn parse_catch_clause(&mut self) -> CatchClause {
+        let mut binding = None;
+        let mut error_type = None;
+
+        if self.consume(&Token::ParenOpen) {
+            binding = match self.next() {
+                Some(Token::Identifier(name)) => Some(CatchBinding::Identifier(name)),
+                Some(Token::BraceOpen) => Some(self.parse_catch_destructuring()),
+                other => panic!("Expected identifier or '{{' in catch, got {:?}", other),
+            };
+
+            if self.consume(&Token::Colon) {
+                error_type = self.parse_expr();
+            }
+
+            self.expect(&Token::ParenClose);
+        }
+
+        let guard = if self.expect_keyword("if") {
+            self.expect(&Token::ParenOpen);
+            let condition = self.parse_expr();
+            self.expect(&Token::ParenClose);
+            condition
+        } else {
+            None
+        };
+
+        let body = self.parse_block();
+        CatchClause {
+            binding,
+            error_type,
+            guard,
+            body,
+        }
+    }
+    fn parse_catch_destructuring(&mut self) -> CatchBinding {
+        let mut fields = vec![];
+        while !self.consume(&Token::BraceClose) {
+            let prop = match self.next() {
+                Some(Token::Identifier(name)) => name,
+                other => panic!("Expected property name in catch pattern, got {:?}", other),
+            };
+            let local = if self.consume(&Token::Colon) {
+                match self.next() {
+                    Some(Token::Identifier(name)) => name,
+                    other => panic!(
+                        "Expected identifier after ':' in catch pattern, got {:?}",
+                        other
+                    ),
+                }
+            } else {
+                prop.clone()
+            };
+            fields.push((prop, local));
+
+            if !self.consume(&Token::Comma) {
+                self.expect(&Token::BraceClose);
+                break;
+            }
+        }
+        CatchBinding::Object(fields)
+    }
     fn parse_export_stmt(&mut self) -> Option<Stmt> {
         if self.expect_keyword("export") {
             if self.expect_keyword("default") {
@@ -233,6 +342,10 @@ impl Parser {
     }
 
     fn parse_unary(&mut self, min_prec: u8) -> Option<Expr> {
+        if self.expect_keyword("delete") {
+            let target = self.parse_unary(min_prec)?;
+            return Some(Expr::Delete(Box::new(target)));
+        }
         while let Some(op) = self.peek().and_then(get_unary_op) {
             self.next();
             let expr = self.parse_unary(min_prec)?; // recursivo para múltiplos unários como `!!a`
@@ -245,7 +358,98 @@ impl Parser {
         self.parse_postfix_expr()
     }
 
-    fn parse_class_decl(&mut self) -> Option<Stmt> {
+    // enum Cor { Vermelho, Verde, Rgb(r, g, b) }
+    fn parse_enum_decl(&mut self) -> Option<Stmt> {
+        self.next(); // consume 'enum'
+
+        let name = match self.next()? {
+            Token::Identifier(name) => name,
+            other => panic!("Expected enum name, got {:?}", other),
+        };
+        self.expect(&Token::BraceOpen);
+
+        let mut variants = vec![];
+        while !self.consume(&Token::BraceClose) {
+            let variant = match self.next()? {
+                Token::Identifier(variant) => variant,
+                other => panic!("Expected variant name in enum {name}, got {:?}", other),
+            };
+
+            let mut fields = vec![];
+            if self.consume(&Token::ParenOpen) {
+                while !self.consume(&Token::ParenClose) {
+                    match self.next()? {
+                        Token::Identifier(field) => fields.push(field),
+                        other => panic!("Expected field name in {name}.{variant}, got {:?}", other),
+                    }
+                    self.consume(&Token::Comma);
+                }
+            }
+            variants.push(EnumVariant {
+                name: variant,
+                fields,
+            });
+
+            if !self.consume(&Token::Comma) {
+                self.expect(&Token::BraceClose);
+                break;
+            }
+        }
+
+        Some(Stmt::EnumDecl { name, variants })
+    }
+
+    // match (valor) { Cor.Rgb(r, g, b) => { ... } Cor.Vermelho => ...; _ => { ... } }
+    fn parse_match_stmt(&mut self) -> Option<Stmt> {
+        self.next(); // consume 'match'
+        let location = self.location();
+
+        self.expect(&Token::ParenOpen);
+        let subject = self.parse_expr()?;
+        self.expect(&Token::ParenClose);
+        self.expect(&Token::BraceOpen);
+
+        let mut arms = vec![];
+        while !self.consume(&Token::BraceClose) {
+            let pattern = match self.parse_expr()? {
+                Expr::Identifier(name) if name == "_" => MatchPattern::Wildcard,
+                Expr::Call { callee, args, .. } => {
+                    let bindings = args
+                        .into_iter()
+                        .map(|arg| match arg {
+                            Expr::Identifier(binding) => binding,
+                            other => {
+                                panic!("Expected identifier in match pattern, got {:?}", other)
+                            }
+                        })
+                        .collect();
+                    MatchPattern::Variant {
+                        path: *callee,
+                        bindings,
+                    }
+                }
+                expr => MatchPattern::Value(expr),
+            };
+            self.expect(&Token::FatArrow);
+
+            let body = if self.check(&Token::BraceOpen) {
+                self.parse_block()
+            } else {
+                vec![self.parse_stmt()?]
+            };
+            self.consume(&Token::Comma);
+
+            arms.push(MatchArm { pattern, body });
+        }
+
+        Some(Stmt::Match {
+            subject,
+            arms,
+            location,
+        })
+    }
+
+    fn parse_class_decl(&mut self, modifiers: Vec<Modifiers>) -> Option<Stmt> {
         self.next(); // consume 'class'
 
         let name = match self.next()? {
@@ -259,14 +463,23 @@ impl Parser {
         } else {
             None
         };
+        let interfaces = if self.consume_keyword("implements") {
+            self.parse_name_list()?
+        } else {
+            vec![]
+        };
 
         self.consume(&Token::BraceOpen);
 
         let mut methods = vec![];
         let mut static_fields = HashMap::new();
         let mut instance_fields = HashMap::new();
+        let mut field_types = HashMap::new();
 
         while self.peek() != Some(&Token::BraceClose) {
+            let decorators = self.parse_decorators()?;
+            let declared = methods.len();
+
             if self.check_identifier() && self.peek_next() == Some(&Token::ParenOpen) {
                 let method = self.parse_method(false, false)?;
                 methods.push(method);
@@ -279,8 +492,11 @@ impl Parser {
                         let method = self.parse_method(true, false)?;
                         methods.push(method);
                     }
-                    (Some(Token::Identifier(_)), Some(Token::Assign)) => {
-                        let (name, expr) = self.parse_field()?;
+                    (Some(Token::Identifier(_)), Some(Token::Assign | Token::Colon)) => {
+                        let (name, ty, expr) = self.parse_field()?;
+                        if let Some(ty) = ty {
+                            field_types.insert(name.clone(), ty);
+                        }
                         static_fields.insert(name, expr);
                     }
                     _ => {
@@ -290,12 +506,30 @@ impl Parser {
             } else if self.expect_keyword("operator") || self.expect_keyword("@Operator") {
                 let method = self.parse_method(false, true)?;
                 methods.push(method);
+            } else if self.expect_keyword("abstract") {
+                // `abstract nome(self);` — sem corpo, a subclasse implementa
+                let is_operator = self.expect_keyword("operator");
+                let method = self.parse_method(false, is_operator)?;
+                if !method.modifiers.contains(&Modifiers::Abstract) {
+                    return None;
+                }
+                methods.push(method);
             } else if self.check_identifier() {
-                let (name, expr) = self.parse_field()?;
+                let (name, ty, expr) = self.parse_field()?;
+                if let Some(ty) = ty {
+                    field_types.insert(name.clone(), ty);
+                }
                 instance_fields.insert(name, expr);
             } else {
                 return None; // erro de sintaxe
             }
+
+            if !decorators.is_empty() {
+                match methods[declared..].last_mut() {
+                    Some(method) => method.decorators = decorators,
+                    None => panic!("Error: Decorators can only be applied to methods"),
+                }
+            }
         }
 
         self.expect(&Token::BraceClose);
@@ -306,26 +540,103 @@ impl Parser {
             methods,
             static_fields,
             instance_fields,
+            field_types,
+            interfaces,
+            modifiers,
         })
     }
 
-    fn parse_field(&mut self) -> Option<(String, Expr)> {
+    /// `A, B.C, ...` depois de `implements` ou do `extends` de interfaces.
+    fn parse_name_list(&mut self) -> Option<Vec<Expr>> {
+        let mut names = vec![self.parse_primary()?];
+        while self.consume(&Token::Comma) {
+            names.push(self.parse_primary()?);
+        }
+        Some(names)
+    }
+
+    fn parse_interface_decl(&mut self) -> Option<Stmt> {
+        self.next(); // consume 'interface'
+
+        let name = match self.next()? {
+            Token::Identifier(name) => name,
+            _ => return None,
+        };
+        let extends = if self.consume_keyword("extends") {
+            self.parse_name_list()?
+        } else {
+            vec![]
+        };
+
+        self.expect(&Token::BraceOpen);
+        let mut methods = vec![];
+        while self.peek() != Some(&Token::BraceClose) {
+            let is_operator = self.expect_keyword("operator") || self.expect_keyword("@Operator");
+            methods.push(self.parse_method(false, is_operator)?);
+        }
+        self.expect(&Token::BraceClose);
+
+        Some(Stmt::InterfaceDecl {
+            name,
+            extends,
+            methods,
+        })
+    }
+
+    fn parse_field(&mut self) -> Option<(String, Option<TypeAnnotation>, Expr)> {
         let name = match self.next()? {
             Token::Identifier(name) => name,
             _ => {
                 return None;
             }
         };
+        let ty = self.parse_optional_type()?;
         if self.check(&Token::Assign) {
             self.consume(&Token::Assign);
             let expr = self.parse_expr()?;
             self.consume(&Token::Semicolon);
 
-            return Some((name, expr));
+            return Some((name, ty, expr));
         }
         let expr = Expr::Literal(Literal::Null);
         self.consume(&Token::Semicolon);
-        Some((name, expr))
+        Some((name, ty, expr))
+    }
+
+    /// `: Tipo` opcional depois de variáveis, parâmetros, campos e da lista de parâmetros.
+    /// Retorna `Some(None)` quando não há anotação e `None` em erro de sintaxe.
+    fn parse_optional_type(&mut self) -> Option<Option<TypeAnnotation>> {
+        if !self.consume(&Token::Colon) {
+            return Some(None);
+        }
+        self.parse_type().map(Some)
+    }
+
+    /// `nome` ou `Nome<Arg, ...>`, como `number`, `Foo` e `Array<Foo>`.
+    fn parse_type(&mut self) -> Option<TypeAnnotation> {
+        let name = match self.next()? {
+            Token::Identifier(name) => name,
+            Token::Null => "null".to_string(),
+            _ => return None,
+        };
+        let location = self.location();
+        let mut args = vec![];
+        if self.consume(&Token::Less) {
+            loop {
+                args.push(self.parse_type()?);
+                if !self.consume(&Token::Comma) {
+                    break;
+                }
+            }
+            if !self.consume(&Token::Greater) {
+                return None;
+            }
+        }
+        Some(TypeAnnotation {
+            name,
+            args,
+            location,
+        })
     }
 
     fn check_identifier(&self) -> bool {
@@ -339,6 +650,7 @@ impl Parser {
 
         self.expect(&Token::ParenOpen);
         let mut params = vec![];
+        let mut param_types = vec![];
         let mut vararg: Option<String> = None;
 
         loop {
@@ -375,6 +687,7 @@ impl Parser {
                 Token::Identifier(name) => {
                     params.push(name.clone());
                     self.next(); // consume identifier
+                    param_types.push(self.parse_optional_type()?);
 
                     if self.peek() == Some(&Token::Comma) {
                         self.next(); // consume comma
@@ -389,10 +702,21 @@ impl Parser {
         }
 
         self.expect(&Token::ParenClose);
-        let body = self.parse_block();
+        let signature = Signature {
+            params: param_types,
+            returns: self.parse_optional_type()?,
+        };
 
         let mut modifiers: Vec<Modifiers> = vec![];
 
+        // `nome(self);` declara o método sem corpo
+        let body = if self.consume(&Token::Semicolon) {
+            modifiers.push(Modifiers::Abstract);
+            vec![]
+        } else {
+            self.parse_block()
+        };
+
         if is_static {
             modifiers.push(Modifiers::Static);
         }
@@ -405,9 +729,70 @@ impl Parser {
             vararg,
             body,
             modifiers,
+            signature,
+            decorators: vec![],
         })
     }
 
+    /// `@nome`, exceto o `@Operator` embutido, abre um decorador.
+    fn is_decorator(name: &str) -> bool {
+        name.starts_with('@') && name.len() > 1 && name != "@Operator"
+    }
+
+    /// `@a @b(x) @mod.c` antes de uma declaração; vazio se não houver nenhum.
+    fn parse_decorators(&mut self) -> Option<Vec<Decorator>> {
+        let mut decorators = vec![];
+
+        while let Some(Token::Identifier(name)) = self.peek() {
+            if !Self::is_decorator(name) {
+                break;
+            }
+            let mut name = name[1..].to_string();
+            self.next(); // consume '@nome'
+            let location = self.location();
+
+            let mut callee = Expr::Identifier(name.clone());
+            while self.consume(&Token::Dot) {
+                let Some(Token::Identifier(property)) = self.next() else {
+                    return None;
+                };
+                name = format!("{name}.{property}");
+                callee = Expr::GetProperty {
+                    object: Box::new(callee),
+                    property: Box::new(Expr::Identifier(property)),
+                };
+            }
+
+            let args = if self.peek() == Some(&Token::ParenOpen) {
+                self.parse_arguments()
+            } else {
+                vec![]
+            };
+
+            decorators.push(Decorator {
+                name,
+                callee,
+                args,
+                location,
+            });
+        }
+
+        Some(decorators)
+    }
+
+    fn parse_decorated_stmt(&mut self) -> Option<Stmt> {
+        let decorators = self.parse_decorators()?;
+        let target = self.parse_stmt()?;
+
+        match target {
+            Stmt::FuncDecl(_) | Stmt::ClassDecl { .. } => Some(Stmt::Decorated {
+                decorators,
+                target: Box::new(target),
+            }),
+            _ => panic!("Error: Decorators can only be applied to classes, methods and functions"),
+        }
+    }
+
     fn parse_if_stmt(&mut self) -> Option<Stmt> {
         self.next(); // consume "if"
         self.expect(&Token::ParenOpen);
@@ -489,10 +874,13 @@ impl Parser {
             None
         } else if is_let {
             self.next(); // Consume Token::Assign;
+            let location = self.location();
             let value = self.parse_expr().unwrap_or(Expr::Literal(Literal::Null));
             Some(Stmt::Let {
                 name: self.extract_identifier(&pattern)?,
+                ty: None,
                 value: value,
+                location,
             })
         } else {
             Some(self.parse_stmt()?)
@@ -531,15 +919,19 @@ impl Parser {
 
     fn parse_var_decl(&mut self) -> Option<Stmt> {
         self.next(); // consume "let"
+        let location = self.location();
         let name = match self.next()? {
             Token::Identifier(name) => name,
             _ => return None,
         };
+        let ty = self.parse_optional_type()?;
         self.expect(&Token::Assign);
         let value = self.parse_expr();
         Some(Stmt::Let {
             name,
+            ty,
             value: value.unwrap_or(Expr::Literal(Literal::Null)),
+            location,
         })
     }
 
@@ -553,6 +945,7 @@ impl Parser {
 
         self.expect(&Token::ParenOpen);
         let mut params = vec![];
+        let mut param_types = vec![];
         let mut vararg: Option<String> = None;
 
         loop {
@@ -589,6 +982,7 @@ impl Parser {
                 Token::Identifier(name) => {
                     params.push(name.clone());
                     self.next(); // consume identifier
+                    param_types.push(self.parse_optional_type()?);
 
                     if self.peek() == Some(&Token::Comma) {
                         self.next(); // consume comma
@@ -603,6 +997,10 @@ impl Parser {
         }
 
         self.expect(&Token::ParenClose);
+        let signature = Signature {
+            params: param_types,
+            returns: self.parse_optional_type()?,
+        };
         let body = self.parse_block();
 
         Some(Stmt::FuncDecl(FunctionStmt {
@@ -610,10 +1008,12 @@ impl Parser {
             params,
             vararg,
             body,
+            signature,
         }))
     }
     fn parse_return_stmt(&mut self) -> Option<Stmt> {
         self.next(); // consume "return"
+        let location = self.location();
         let value = if let Some(Token::BraceClose) = self.peek() {
             None
         } else if let Some(Token::Semicolon) = self.peek() {
@@ -621,7 +1021,7 @@ impl Parser {
         } else {
             Some(self.parse_expr()?)
         };
-        Some(Stmt::Return(value))
+        Some(Stmt::Return(value, location))
     }
 
     fn parse_break_stmt(&mut self) -> Option<Stmt> {
@@ -663,12 +1063,9 @@ impl Parser {
         self.expect(&Token::ParenOpen);
 
         while self.peek() != Some(&Token::ParenClose) {
-            let arg = if self.peek() == Some(&Token::Ellipsis) {
-                let inner = self.parse_expr().expect("Expected expression after '...'");
-                Expr::Spread(Box::new(inner))
-            } else {
-                self.parse_expr().expect("Expected argument expression")
-            };
+            let arg = self
+                .parse_call_argument(&args)
+                .expect("Expected argument expression");
 
             args.push(arg);
 
@@ -684,20 +1081,64 @@ impl Parser {
         args
     }
 
+    /// Um argumento de chamada: `expr`, `...expr` ou `nome: expr`. Os nomeados vêm
+    /// depois de todos os posicionais (`previous` são os já lidos).
+    fn parse_call_argument(&mut self, previous: &[Expr]) -> Option<Expr> {
+        let is_named = matches!(
+            (self.peek(), self.peek_next()),
+            (Some(Token::Identifier(_)), Some(Token::Colon))
+        );
+        if is_named {
+            let Some(Token::Identifier(name)) = self.next() else {
+                return None;
+            };
+            self.next(); // consume ':'
+            if previous
+                .iter()
+                .any(|arg| matches!(arg, Expr::NamedArg { name: other, .. } if *other == name))
+            {
+                panic!("Error: Argument '{name}' given more than once");
+            }
+            let value = self.parse_expr()?;
+            return Some(Expr::NamedArg {
+                name,
+                value: Box::new(value),
+            });
+        }
+
+        if previous
+            .iter()
+            .any(|arg| matches!(arg, Expr::NamedArg { .. }))
+        {
+            panic!("Error: Positional argument cannot follow named arguments");
+        }
+        if self.peek() == Some(&Token::Ellipsis) {
+            self.next(); // consume '...'
+            let inner = self.parse_expr()?;
+            return Some(Expr::Spread(Box::new(inner)));
+        }
+        self.parse_expr()
+    }
+
     fn parse_primary(&mut self) -> Option<Expr> {
         match self.next()? {
             Token::Identifier(s) if s == "this" => Some(Expr::This),
             Token::Identifier(s) if s == "new" => self.parse_new_keyword(),
             Token::Number(n) => Some(Expr::Literal(Literal::Number(n))),
+            Token::Int(n) => Some(Expr::Literal(Literal::Int(n))),
+            Token::BigInt(n) => Some(Expr::Literal(Literal::BigInt(n))),
+            Token::Decimal(n) => Some(Expr::Literal(Literal::Decimal(n))),
             Token::String(s) => Some(Expr::Literal(Literal::String(s))),
             Token::Bool(b) => Some(Expr::Literal(Literal::Bool(b))),
             Token::Null => Some(Expr::Literal(Literal::Null)),
             Token::Identifier(name) => {
                 if let Some(Token::ParenOpen) = self.peek() {
+                    let location = self.location();
                     let args = self.parse_arguments();
                     Some(Expr::Call {
                         callee: Box::new(Expr::Identifier(name)),
                         args,
+                        location,
                     })
                 } else {
                     Some(Expr::Identifier(name))
@@ -751,15 +1192,10 @@ impl Parser {
                 }
                 Some(Token::ParenOpen) => {
                     self.next(); // consume '('
+                    let location = self.location();
                     let mut args = Vec::new();
                     while self.peek() != Some(&Token::ParenClose) {
-                        let arg = if self.peek() == Some(&Token::Ellipsis) {
-                            self.next(); // consume '...'
-                            let inner = self.parse_expr()?;
-                            Expr::Spread(Box::new(inner))
-                        } else {
-                            self.parse_expr()?
-                        };
+                        let arg = self.parse_call_argument(&args)?;
                         args.push(arg);
 
                         if !self.is(&Token::Comma) {
@@ -770,8 +1206,13 @@ impl Parser {
                     expr = Expr::Call {
                         callee: Box::new(expr),
                         args,
+                        location,
                     };
                 }
+                Some(Token::Question) => {
+                    self.next(); // consume '?'
+                    expr = Expr::Propagate(Box::new(expr));
+                }
                 Some(Token::Increment) => {
                     self.next();
                     expr = Expr::UnaryOp {
@@ -824,7 +1265,17 @@ impl Parser {
             if tok == &Token::BracketClose {
                 break;
             }
-            elements.push(self.parse_expr()?);
+            let element = self.parse_expr()?;
+            // [x * 2 for x of xs if x > 0]
+            if elements.is_empty() && self.peek_is_keyword("for") {
+                let clauses = self.parse_comprehension_clauses()?;
+                self.expect(&Token::BracketClose);
+                return Some(Expr::ArrayComprehension {
+                    element: Box::new(element),
+                    clauses,
+                });
+            }
+            elements.push(element);
             if !self.is(&Token::Comma) {
                 break;
             }
@@ -834,6 +1285,23 @@ impl Parser {
         Some(Expr::Literal(Literal::Array(elements)))
     }
 
+    /// `for alvo of iterável`, seguido de `if condição` e de outros `for` aninhados.
+    fn parse_comprehension_clauses(&mut self) -> Option<Vec<ComprehensionClause>> {
+        let mut clauses = vec![];
+        while self.consume_keyword("for") {
+            let target = self.parse_primary()?; // suporte a destructuring
+            if !self.consume_keyword("of") {
+                panic!("Error: Expected 'of' in comprehension");
+            }
+            let iterable = self.parse_expr()?;
+            clauses.push(ComprehensionClause::For { target, iterable });
+            while self.consume_keyword("if") {
+                clauses.push(ComprehensionClause::If(self.parse_expr()?));
+            }
+        }
+        Some(clauses)
+    }
+
     fn is_next_object(&self) -> bool {
         let mut i = self.pos;
         let token = self.tokens.get(i);
@@ -866,14 +1334,24 @@ impl Parser {
                 properties.push(ObjectEntry::Spread(expr));
             } else {
                 // ident or ident: expr
-                let key = match self.next()? {
-                    Token::Identifier(name) => name,
-                    Token::String(name) => name,
+                let (key, key_expr) = match self.next()? {
+                    Token::Identifier(name) => (name.clone(), Expr::Identifier(name)),
+                    Token::String(name) => (name.clone(), Expr::Literal(Literal::String(name))),
                     _ => return None,
                 };
 
                 if self.is(&Token::Colon) {
                     let value = self.parse_expr()?;
+                    // { k: v for [k, v] of entries }: a chave é uma expressão
+                    if properties.is_empty() && self.peek_is_keyword("for") {
+                        let clauses = self.parse_comprehension_clauses()?;
+                        self.expect(&Token::BraceClose);
+                        return Some(Expr::ObjectComprehension {
+                            key: Box::new(key_expr),
+                            value: Box::new(value),
+                            clauses,
+                        });
+                    }
                     properties.push(ObjectEntry::Property { key, value });
                 } else {
                     // shorthand: { a }  ->  { a: a }
@@ -894,7 +1372,21 @@ impl Parser {
     fn parse_binary_expr(&mut self, min_prec: u8) -> Option<Expr> {
         let mut left: Expr = self.parse_unary(7)?;
 
-        while let Some(op) = self.peek().and_then(get_bin_op) {
+        loop {
+            // `|>` tem a menor precedência entre os binários e associa à esquerda
+            if min_prec == 0 && self.consume(&Token::Pipe) {
+                let location = self.location();
+                let target = self.parse_binary_expr(1)?;
+                left = Expr::Pipe {
+                    value: Box::new(left),
+                    target: Box::new(target),
+                    location,
+                };
+                continue;
+            }
+            let Some(op) = self.peek().and_then(get_bin_op) else {
+                break;
+            };
             let prec = get_precedence(&op);
             if prec < min_prec {
                 break;
@@ -926,6 +1418,10 @@ impl Parser {
         self.tokens.get(self.pos + 1)
     }
 
+    fn peek_next_is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek_next(), Some(Token::Identifier(name)) if name == keyword)
+    }
+
     fn next(&mut self) -> Option<Token> {
         let tok = self.tokens.get(self.pos)?.clone();
         self.pos += 1;
@@ -969,6 +1465,30 @@ impl Parser {
 
     fn insert_next(&mut self, token: Token) {
         self.tokens.insert(self.pos, token);
+        if self.pos < self.lines.len() {
+            self.lines.insert(self.pos, self.lines[self.pos]);
+        }
+    }
+
+    /// Posição do próximo token, onde começa o statement que ainda vai ser lido.
+    fn next_location(&self) -> SourceLocation {
+        SourceLocation {
+            file: self.file.clone(),
+            line: self.lines.get(self.pos).copied().unwrap_or_default(),
+        }
+    }
+
+    /// Posição do último token consumido.
+    fn location(&self) -> SourceLocation {
+        let line = self
+            .lines
+            .get(self.pos.saturating_sub(1))
+            .copied()
+            .unwrap_or_default();
+        SourceLocation {
+            file: self.file.clone(),
+            line,
+        }
     }
     #[track_caller]
     fn expect_any(&mut self, expected: &[Token]) {
@@ -1054,11 +1574,14 @@ fn get_bin_op(token: &Token) -> Option<Operator> {
         Token::Minus => Some(Operator::Binary(BinaryOperator::Subtract)),
         Token::Asterisk => Some(Operator::Binary(BinaryOperator::Multiply)),
         Token::Slash => Some(Operator::Binary(BinaryOperator::Divide)),
+        Token::TildeSlash => Some(Operator::Binary(BinaryOperator::FloorDivide)),
         Token::Modulo => Some(Operator::Binary(BinaryOperator::Modulo)),
         Token::Exponentiation => Some(Operator::Binary(BinaryOperator::Exponentiate)),
 
         Token::Equal => Some(Operator::Compare(CompareOperator::Eq)),
         Token::NotEqual => Some(Operator::Compare(CompareOperator::Ne)),
+        Token::StrictEqual => Some(Operator::Compare(CompareOperator::StrictEq)),
+        Token::StrictNotEqual => Some(Operator::Compare(CompareOperator::StrictNe)),
         Token::Less => Some(Operator::Compare(CompareOperator::Lt)),
         Token::Greater => Some(Operator::Compare(CompareOperator::Gt)),
         Token::LessEqual => Some(Operator::Compare(CompareOperator::Le)),
@@ -1067,6 +1590,9 @@ fn get_bin_op(token: &Token) -> Option<Operator> {
             Some(Operator::Compare(CompareOperator::InstanceOf))
         }
         Token::Identifier(i) if i == "in" => Some(Operator::Compare(CompareOperator::In)),
+        Token::Identifier(i) if i == "implements" => {
+            Some(Operator::Compare(CompareOperator::Implements))
+        }
 
         Token::And => Some(Operator::Logical(LogicalOperator::And)),
         Token::Or => Some(Operator::Logical(LogicalOperator::Or)),
@@ -1086,6 +1612,7 @@ fn get_precedence(op: &Operator) -> u8 {
         Operator::Binary(BinaryOperator::Add) | Operator::Binary(BinaryOperator::Subtract) => 4,
         Operator::Binary(BinaryOperator::Multiply)
         | Operator::Binary(BinaryOperator::Divide)
+        | Operator::Binary(BinaryOperator::FloorDivide)
         | Operator::Binary(BinaryOperator::Modulo) => 5,
         Operator::Binary(BinaryOperator::Exponentiate) => 6,
         Operator::Unary(_) => 7,
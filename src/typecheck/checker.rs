@@ -0,0 +1,1094 @@
+//! Verificação estática das anotações de tipo. Percorre um arquivo e os módulos
+//! que ele importa sem executar nada; expressões cujo tipo não dá para inferir
+//! são tratadas como `any` e nunca geram erro.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::ast::ast::{
+    CatchBinding, ComprehensionClause, Decorator, Expr, FunctionStmt, Literal, MethodDecl,
+    Operator, Signature, SourceLocation, Stmt, TypeAnnotation, UnaryOperator,
+};
+
+use super::PRIMITIVES;
+
+/// Incompatibilidade encontrada pelo `check`.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub location: SourceLocation,
+    pub message: String,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
+}
+
+/// Parâmetros e tipos de uma função ou método declarado.
+#[derive(Debug, Clone)]
+struct Callable {
+    params: Vec<String>,
+    signature: Signature,
+}
+
+impl Callable {
+    fn from_function(func: &FunctionStmt) -> Self {
+        Callable {
+            params: func.params.clone(),
+            signature: func.signature.clone(),
+        }
+    }
+
+    fn from_method(method: &MethodDecl) -> Self {
+        Callable {
+            params: method.params.clone(),
+            signature: method.signature.clone(),
+        }
+    }
+
+    /// O `self` dos métodos é passado implicitamente.
+    fn receiver(&self) -> usize {
+        usize::from(self.params.first().is_some_and(|param| param == "self"))
+    }
+
+    /// Parâmetros que podem ser informados na chamada, por posição ou por nome.
+    fn explicit_params(&self) -> &[String] {
+        &self.params[self.receiver()..]
+    }
+
+    /// Tipos dos argumentos explícitos, na ordem de `explicit_params`.
+    fn arg_types(&self) -> Vec<Option<TypeAnnotation>> {
+        let mut types = self.signature.params.clone();
+        types.resize(self.params.len(), None);
+        types.into_iter().skip(self.receiver()).collect()
+    }
+}
+
+/// Variável em escopo: tipo anotado ou, sem anotação, o último valor atribuído.
+#[derive(Debug, Clone)]
+struct Binding {
+    ty: Option<TypeAnnotation>,
+    declared: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+struct ClassInfo {
+    superclass: Option<String>,
+    fields: HashMap<String, TypeAnnotation>,
+    methods: HashMap<String, Callable>,
+    static_methods: HashMap<String, Callable>,
+    interfaces: Vec<String>, // implements, ou extends de uma interface
+    is_enum: bool,
+    is_abstract: bool, // `abstract class`
+}
+
+pub struct Checker<F: FnMut(&str) -> Result<Vec<Stmt>, String>> {
+    load: F, // lê e analisa um módulo importado
+    classes: HashMap<String, ClassInfo>,
+    functions: HashMap<String, Callable>,
+    scopes: Vec<HashMap<String, Binding>>,
+    returns: Vec<(String, Option<TypeAnnotation>)>, // função atual e retorno declarado
+    current_class: Vec<String>,
+    visited: HashSet<String>,
+    file: String,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<F: FnMut(&str) -> Result<Vec<Stmt>, String>> Checker<F> {
+    pub fn new(load: F) -> Self {
+        Checker {
+            load,
+            classes: HashMap::new(),
+            functions: HashMap::new(),
+            scopes: vec![],
+            returns: vec![],
+            current_class: vec![],
+            visited: HashSet::new(),
+            file: String::new(),
+            diagnostics: vec![],
+        }
+    }
+
+    /// Registra classes e funções (da stdlib, por exemplo) sem verificar seus corpos.
+    pub fn declare(&mut self, stmts: &[Stmt]) {
+        for stmt in stmts {
+            match stmt {
+                Stmt::FuncDecl(func) => {
+                    self.functions
+                        .insert(func.name.clone(), Callable::from_function(func));
+                }
+                Stmt::ClassDecl {
+                    name,
+                    superclass,
+                    methods,
+                    field_types,
+                    interfaces,
+                    modifiers,
+                    ..
+                } => {
+                    let superclass = match superclass {
+                        Some(Expr::Identifier(parent)) => Some(parent.clone()),
+                        _ => None,
+                    };
+                    let (static_methods, methods): (Vec<_>, Vec<_>) =
+                        methods.iter().partition(|method| {
+                            method
+                                .modifiers
+                                .contains(&crate::ast::ast::Modifiers::Static)
+                        });
+                    let info = ClassInfo {
+                        superclass,
+                        fields: field_types.clone(),
+                        methods: methods
+                            .into_iter()
+                            .map(|method| (method.name.clone(), Callable::from_method(method)))
+                            .collect(),
+                        static_methods: static_methods
+                            .into_iter()
+                            .map(|method| (method.name.clone(), Callable::from_method(method)))
+                            .collect(),
+                        interfaces: Self::names(interfaces),
+                        is_enum: false,
+                        is_abstract: modifiers.contains(&crate::ast::ast::Modifiers::Abstract),
+                    };
+                    self.classes.insert(name.clone(), info);
+                }
+                Stmt::InterfaceDecl {
+                    name,
+                    extends,
+                    methods,
+                } => {
+                    let info = ClassInfo {
+                        methods: methods
+                            .iter()
+                            .map(|method| (method.name.clone(), Callable::from_method(method)))
+                            .collect(),
+                        interfaces: Self::names(extends),
+                        ..ClassInfo::default()
+                    };
+                    self.classes.insert(name.clone(), info);
+                }
+                Stmt::EnumDecl { name, .. } => {
+                    let info = ClassInfo {
+                        is_enum: true,
+                        ..ClassInfo::default()
+                    };
+                    self.classes.insert(name.clone(), info);
+                }
+                Stmt::Export(inner) | Stmt::ExportDefault(inner) => {
+                    self.declare(std::slice::from_ref(inner.as_ref()))
+                }
+                Stmt::Decorated { target, .. } => {
+                    self.declare(std::slice::from_ref(target.as_ref()))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Verifica `path` e, antes dele, cada módulo importado (uma vez só).
+    pub fn check_module(&mut self, path: &str) {
+        if !self.visited.insert(path.to_string()) {
+            return;
+        }
+        let stmts = match (self.load)(path) {
+            Ok(stmts) => stmts,
+            Err(err) => {
+                self.report_at(path, 0, format!("cannot load module: {err}"));
+                return;
+            }
+        };
+
+        self.declare(&stmts);
+        for stmt in &stmts {
+            if let Some(from) = Self::import_path(stmt) {
+                self.check_module(from);
+            }
+        }
+
+        let previous_file = std::mem::replace(&mut self.file, path.to_string());
+        let previous_scopes = std::mem::replace(&mut self.scopes, vec![HashMap::new()]);
+        for stmt in &stmts {
+            self.check_stmt(stmt);
+        }
+        self.scopes = previous_scopes;
+        self.file = previous_file;
+    }
+
+    pub fn into_diagnostics(self) -> Vec<Diagnostic> {
+        self.diagnostics
+    }
+
+    fn names(exprs: &[Expr]) -> Vec<String> {
+        exprs
+            .iter()
+            .filter_map(|expr| match expr {
+                Expr::Identifier(name) => Some(name.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn import_path(stmt: &Stmt) -> Option<&str> {
+        match stmt {
+            Stmt::ImportNamed { from, .. }
+            | Stmt::ImportDefault { from, .. }
+            | Stmt::ImportAll { from, .. }
+            | Stmt::ImportMixed { from, .. } => Some(from),
+            _ => None,
+        }
+    }
+
+    fn report(&mut self, location: &SourceLocation, message: String) {
+        let location = if location.file.is_empty() {
+            SourceLocation {
+                file: self.file.clone(),
+                line: location.line,
+            }
+        } else {
+            location.clone()
+        };
+        self.diagnostics.push(Diagnostic { location, message });
+    }
+
+    fn report_at(&mut self, file: &str, line: usize, message: String) {
+        let location = SourceLocation {
+            file: file.to_string(),
+            line,
+        };
+        self.diagnostics.push(Diagnostic { location, message });
+    }
+
+    fn push_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    fn pop_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    /// Define uma variável com tipo declarado (anotação ou parâmetro).
+    fn define(&mut self, name: &str, ty: Option<TypeAnnotation>) {
+        self.bind(name, ty, true);
+    }
+
+    fn bind(&mut self, name: &str, ty: Option<TypeAnnotation>, declared: bool) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.to_string(), Binding { ty, declared });
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Option<&Binding> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    fn lookup_mut(&mut self, name: &str) -> Option<&mut Binding> {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(name))
+    }
+
+    /// Reporta nomes de tipo que não são primitivos nem classes conhecidas.
+    fn validate(&mut self, ty: &TypeAnnotation) {
+        if !PRIMITIVES.contains(&ty.name.as_str()) && !self.classes.contains_key(&ty.name) {
+            self.report(&ty.location, format!("unknown type '{}'", ty.name));
+        }
+        for arg in &ty.args {
+            self.validate(arg);
+        }
+    }
+
+    fn validate_signature(&mut self, signature: &Signature) {
+        for ty in signature.params.iter().flatten() {
+            self.validate(ty);
+        }
+        if let Some(ty) = &signature.returns {
+            self.validate(ty);
+        }
+    }
+
+    /// Verdadeiro se `class` herda de `ancestor` ou implementa a interface `ancestor`.
+    fn is_subclass(&self, class: &str, ancestor: &str) -> bool {
+        if class == ancestor {
+            return true;
+        }
+        let Some(info) = self.classes.get(class) else {
+            return false;
+        };
+        info.interfaces
+            .iter()
+            .chain(&info.superclass)
+            .any(|parent| self.is_subclass(parent, ancestor))
+    }
+
+    /// Verdadeiro se um valor do tipo `actual` pode ocupar um lugar do tipo `expected`.
+    fn compatible(&self, expected: &TypeAnnotation, actual: &TypeAnnotation) -> bool {
+        const NUMBERS: [&str; 5] = ["number", "int", "float", "bigint", "decimal"];
+        let canonical = |name: &str| match name {
+            "boolean" => "bool".to_string(),
+            "Array" => "array".to_string(),
+            name => name.to_string(),
+        };
+        let expected_name = canonical(&expected.name);
+        let actual_name = canonical(&actual.name);
+
+        match (expected_name.as_str(), actual_name.as_str()) {
+            ("any", _) | (_, "any") => true,
+            (e, a) if e == a => expected
+                .args
+                .iter()
+                .zip(&actual.args)
+                .all(|(expected, actual)| self.compatible(expected, actual)),
+            ("number", actual) => NUMBERS.contains(&actual),
+            ("float", "int") => true,
+            ("void", "null") => true,
+            // Referências aceitam null; primitivos não
+            ("object" | "array" | "function", "null") => true,
+            (expected, "null") => self.classes.contains_key(expected),
+            ("object", actual) => self.classes.contains_key(actual),
+            (expected, actual) => {
+                self.classes.contains_key(expected) && self.is_subclass(actual, expected)
+            }
+        }
+    }
+
+    fn expect_type(&mut self, expected: &TypeAnnotation, actual: &Option<TypeAnnotation>) -> bool {
+        match actual {
+            Some(actual) => self.compatible(expected, actual),
+            None => true,
+        }
+    }
+
+    fn check_body(&mut self, body: &[Stmt]) {
+        self.push_scope();
+        for stmt in body {
+            self.check_stmt(stmt);
+        }
+        self.pop_scope();
+    }
+
+    fn check_function(
+        &mut self,
+        name: &str,
+        params: &[String],
+        vararg: &Option<String>,
+        signature: &Signature,
+        body: &[Stmt],
+    ) {
+        self.validate_signature(signature);
+        self.push_scope();
+        for (index, param) in params.iter().enumerate() {
+            let ty = signature.params.get(index).cloned().flatten();
+            let ty = match (param.as_str(), self.current_class.last()) {
+                ("self", Some(class)) if ty.is_none() => Some(TypeAnnotation::named(class)),
+                _ => ty,
+            };
+            self.define(param, ty);
+        }
+        if let Some(vararg) = vararg {
+            self.define(vararg, Some(TypeAnnotation::named("array")));
+        }
+        self.returns
+            .push((name.to_string(), signature.returns.clone()));
+        for stmt in body {
+            self.check_stmt(stmt);
+        }
+        self.returns.pop();
+        self.pop_scope();
+    }
+
+    /// Decoradores são chamadas comuns: confere os argumentos, o retorno não é tipado.
+    fn check_decorators(&mut self, decorators: &[Decorator]) {
+        for decorator in decorators {
+            self.infer(&decorator.callee);
+            for arg in &decorator.args {
+                self.infer(arg);
+            }
+        }
+    }
+
+    fn check_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Let {
+                name, ty, value, ..
+            } => {
+                let actual = self.infer(value);
+                if let Some(ty) = ty {
+                    self.validate(ty);
+                    if !self.expect_type(ty, &actual) {
+                        let found = actual.as_ref().map(|t| t.to_string()).unwrap_or_default();
+                        self.report(
+                            &ty.location,
+                            format!("'{name}' is declared as {ty} but initialized with {found}"),
+                        );
+                    }
+                }
+                match ty {
+                    Some(ty) => self.define(name, Some(ty.clone())),
+                    None => self.bind(name, actual, false),
+                }
+            }
+            Stmt::FuncDecl(func) => {
+                self.functions
+                    .insert(func.name.clone(), Callable::from_function(func));
+                self.check_function(
+                    &func.name,
+                    &func.params,
+                    &func.vararg,
+                    &func.signature,
+                    &func.body,
+                );
+            }
+            Stmt::Decorated { decorators, target } => {
+                self.check_decorators(decorators);
+                self.check_stmt(target);
+            }
+            Stmt::ClassDecl {
+                name,
+                methods,
+                static_fields,
+                instance_fields,
+                field_types,
+                ..
+            } => {
+                for (field, ty) in field_types {
+                    self.validate(ty);
+                    let initializer = static_fields.get(field).or(instance_fields.get(field));
+                    // Campos sem inicializador começam como null
+                    let Some(initializer) = initializer else {
+                        continue;
+                    };
+                    if matches!(initializer, Expr::Literal(Literal::Null)) {
+                        continue;
+                    }
+                    let actual = self.infer(initializer);
+                    if !self.expect_type(ty, &actual) {
+                        let found = actual.as_ref().map(|t| t.to_string()).unwrap_or_default();
+                        self.report(
+                            &ty.location,
+                            format!("field '{name}.{field}' is declared as {ty} but initialized with {found}"),
+                        );
+                    }
+                }
+                self.current_class.push(name.clone());
+                for method in methods {
+                    self.check_decorators(&method.decorators);
+                    self.check_function(
+                        &format!("{name}.{}", method.name),
+                        &method.params,
+                        &method.vararg,
+                        &method.signature,
+                        &method.body,
+                    );
+                }
+                self.current_class.pop();
+            }
+            Stmt::InterfaceDecl { name, methods, .. } => {
+                self.current_class.push(name.clone());
+                for method in methods {
+                    self.check_function(
+                        &format!("{name}.{}", method.name),
+                        &method.params,
+                        &method.vararg,
+                        &method.signature,
+                        &method.body,
+                    );
+                }
+                self.current_class.pop();
+            }
+            Stmt::Method(method) => {
+                self.check_function(
+                    &method.name,
+                    &method.params,
+                    &method.vararg,
+                    &method.signature,
+                    &method.body,
+                );
+            }
+            Stmt::Return(expr, _) => {
+                let actual = match expr {
+                    Some(expr) => self.infer(expr),
+                    None => Some(TypeAnnotation::named("void")),
+                };
+                let Some((name, Some(expected))) = self.returns.last().cloned() else {
+                    return;
+                };
+                if !self.expect_type(&expected, &actual) {
+                    let found = actual.as_ref().map(|t| t.to_string()).unwrap_or_default();
+                    self.report(
+                        &expected.location,
+                        format!("'{name}' is declared to return {expected} but returns {found}"),
+                    );
+                }
+            }
+            Stmt::If {
+                condition,
+                then_branch,
+                else_ifs,
+                else_branch,
+            } => {
+                self.infer(condition);
+                self.check_body(then_branch);
+                for (condition, body) in else_ifs {
+                    self.infer(condition);
+                    if let Some(body) = body {
+                        self.check_body(body);
+                    }
+                }
+                if let Some(body) = else_branch {
+                    self.check_body(body);
+                }
+            }
+            Stmt::While { condition, body } => {
+                self.infer(condition);
+                self.check_body(body);
+            }
+            Stmt::For {
+                init,
+                condition,
+                update,
+                body,
+            } => {
+                self.push_scope();
+                self.check_stmt(init);
+                if let Some(condition) = condition {
+                    self.infer(condition);
+                }
+                if let Some(update) = update {
+                    self.infer(update);
+                }
+                self.check_body(body);
+                self.pop_scope();
+            }
+            Stmt::ForIn {
+                target,
+                object,
+                body,
+            }
+            | Stmt::ForOf {
+                target,
+                iterable: object,
+                body,
+            } => {
+                self.infer(object);
+                self.push_scope();
+                if let Expr::Identifier(name) = target {
+                    self.define(name, None);
+                }
+                self.check_body(body);
+                self.pop_scope();
+            }
+            Stmt::TryCatchFinally {
+                try_block,
+                catch_clauses,
+                finally_block,
+            } => {
+                self.check_body(try_block);
+                for clause in catch_clauses {
+                    self.push_scope();
+                    match &clause.binding {
+                        Some(CatchBinding::Identifier(name)) => {
+                            let ty = match &clause.error_type {
+                                Some(Expr::Identifier(class)) => Some(TypeAnnotation::named(class)),
+                                _ => None,
+                            };
+                            self.define(name, ty);
+                        }
+                        Some(CatchBinding::Object(fields)) => {
+                            for (_, local) in fields {
+                                self.define(local, None);
+                            }
+                        }
+                        None => {}
+                    }
+                    if let Some(guard) = &clause.guard {
+                        self.infer(guard);
+                    }
+                    self.check_body(&clause.body);
+                    self.pop_scope();
+                }
+                if let Some(body) = finally_block {
+                    self.check_body(body);
+                }
+            }
+            Stmt::Match { subject, arms, .. } => {
+                self.infer(subject);
+                for arm in arms {
+                    self.push_scope();
+                    if let crate::ast::ast::MatchPattern::Variant { bindings, .. } = &arm.pattern {
+                        for binding in bindings {
+                            self.define(binding, None);
+                        }
+                    }
+                    self.check_body(&arm.body);
+                    self.pop_scope();
+                }
+            }
+            Stmt::ImportDefault { local_name, .. } | Stmt::ImportAll { local_name, .. } => {
+                self.define(local_name, None);
+            }
+            Stmt::ImportMixed { default, .. } => self.define(default, None),
+            Stmt::Export(inner) | Stmt::ExportDefault(inner) => self.check_stmt(inner),
+            Stmt::Using { name, value, body } => {
+                let ty = self.infer(value);
+                self.push_scope();
+                self.define(name, ty);
+                self.check_body(body);
+                self.pop_scope();
+            }
+            Stmt::Throw(expr, _) | Stmt::Defer(expr) | Stmt::ExprStmt(expr, _) => {
+                self.infer(expr);
+            }
+            Stmt::ImportNamed { .. } | Stmt::EnumDecl { .. } | Stmt::Break | Stmt::Continue => {}
+        }
+    }
+
+    /// Verifica os argumentos de uma chamada contra os parâmetros anotados.
+    fn check_args(
+        &mut self,
+        name: &str,
+        callable: &Callable,
+        args: &[Expr],
+        location: &SourceLocation,
+    ) {
+        let arg_types = callable.arg_types();
+        let mut after_spread = false;
+        for (index, arg) in args.iter().enumerate() {
+            if let Expr::NamedArg { name: param, value } = arg {
+                let actual = self.infer(value);
+                let Some(position) = callable.explicit_params().iter().position(|p| p == param)
+                else {
+                    self.report(location, format!("unknown argument '{param}' for '{name}'"));
+                    continue;
+                };
+                let Some(Some(expected)) = arg_types.get(position) else {
+                    continue;
+                };
+                if !self.expect_type(expected, &actual) {
+                    let found = actual.as_ref().map(|t| t.to_string()).unwrap_or_default();
+                    self.report(
+                        location,
+                        format!("argument '{param}' of '{name}' expects {expected}, found {found}"),
+                    );
+                }
+                continue;
+            }
+            if after_spread || matches!(arg, Expr::Spread(_)) {
+                // Posições depois de um spread são desconhecidas
+                after_spread = true;
+                self.infer(arg);
+                continue;
+            }
+            let actual = self.infer(arg);
+            let Some(Some(expected)) = arg_types.get(index) else {
+                continue;
+            };
+            if !self.expect_type(expected, &actual) {
+                let found = actual.as_ref().map(|t| t.to_string()).unwrap_or_default();
+                self.report(
+                    location,
+                    format!(
+                        "argument {} of '{name}' expects {expected}, found {found}",
+                        index + 1
+                    ),
+                );
+            }
+        }
+    }
+
+    fn find_method(&self, class: &str, method: &str, is_static: bool) -> Option<Callable> {
+        let info = self.classes.get(class)?;
+        let methods = if is_static {
+            &info.static_methods
+        } else {
+            &info.methods
+        };
+        if let Some(callable) = methods.get(method) {
+            return Some(callable.clone());
+        }
+        // Superclasse primeiro; métodos padrão de interfaces por último
+        info.superclass
+            .iter()
+            .chain(&info.interfaces)
+            .find_map(|parent| self.find_method(parent, method, is_static))
+    }
+
+    fn find_field(&self, class: &str, field: &str) -> Option<TypeAnnotation> {
+        let mut current = Some(class.to_string());
+        while let Some(name) = current {
+            let info = self.classes.get(&name)?;
+            if let Some(ty) = info.fields.get(field) {
+                return Some(ty.clone());
+            }
+            current = info.superclass.clone();
+        }
+        None
+    }
+
+    /// Classe nomeada diretamente por um identificador (acesso estático).
+    fn class_named(&self, expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Identifier(name)
+                if self.lookup(name).is_none() && self.classes.contains_key(name) =>
+            {
+                Some(name.clone())
+            }
+            _ => None,
+        }
+    }
+
+    fn literal_type(&mut self, literal: &Literal) -> Option<TypeAnnotation> {
+        let name = match literal {
+            Literal::Void => "void",
+            Literal::Null => "null",
+            Literal::Bool(_) => "bool",
+            Literal::Number(_) => "float",
+            Literal::Int(_) => "int",
+            Literal::BigInt(_) => "bigint",
+            Literal::Decimal(_) => "decimal",
+            Literal::String(_) => "string",
+            Literal::Object(entries) => {
+                for entry in entries {
+                    match entry {
+                        crate::ast::ast::ObjectEntry::Property { value, .. } => {
+                            self.infer(value);
+                        }
+                        crate::ast::ast::ObjectEntry::Spread(value) => {
+                            self.infer(value);
+                        }
+                        crate::ast::ast::ObjectEntry::Shorthand(_) => {}
+                    }
+                }
+                "object"
+            }
+            Literal::Array(items) => {
+                let types = items
+                    .iter()
+                    .map(|item| self.infer(item))
+                    .collect::<Vec<_>>();
+                let mut ty = TypeAnnotation::named("Array");
+                // Array<T> só quando todos os elementos têm o mesmo tipo conhecido
+                if let Some(Some(first)) = types.first() {
+                    let first = first.clone();
+                    if types.iter().all(|item| item.as_ref() == Some(&first)) {
+                        ty.args.push(first);
+                    }
+                }
+                return Some(ty);
+            }
+        };
+        Some(TypeAnnotation::named(name))
+    }
+
+    fn numeric_result(left: &TypeAnnotation, right: &TypeAnnotation) -> Option<TypeAnnotation> {
+        const NUMBERS: [&str; 5] = ["number", "int", "float", "bigint", "decimal"];
+        if !NUMBERS.contains(&left.name.as_str()) || !NUMBERS.contains(&right.name.as_str()) {
+            return None;
+        }
+        let name = match (left.name.as_str(), right.name.as_str()) {
+            (a, b) if a == b => a,
+            ("int", "float") | ("float", "int") => "float",
+            _ => "number",
+        };
+        Some(TypeAnnotation::named(name))
+    }
+
+    /// Tipo de `expr`, ou `None` quando não dá para saber estaticamente.
+    fn infer(&mut self, expr: &Expr) -> Option<TypeAnnotation> {
+        match expr {
+            Expr::Literal(literal) => self.literal_type(literal),
+            Expr::Identifier(name) => match self.lookup(name) {
+                Some(binding) => binding.ty.clone(),
+                None if self.functions.contains_key(name) => {
+                    Some(TypeAnnotation::named("function"))
+                }
+                None => None,
+            },
+            Expr::This => self
+                .current_class
+                .last()
+                .map(|class| TypeAnnotation::named(class)),
+            Expr::BinaryOp { op, left, right } => {
+                let left = self.infer(left);
+                let right = self.infer(right);
+                match op {
+                    Operator::Compare(_) => Some(TypeAnnotation::named("bool")),
+                    Operator::Binary(crate::ast::ast::BinaryOperator::Add) => {
+                        let (left, right) = (left?, right?);
+                        if left.name == "string" || right.name == "string" {
+                            return Some(TypeAnnotation::named("string"));
+                        }
+                        Self::numeric_result(&left, &right)
+                    }
+                    Operator::Binary(_) => Self::numeric_result(&left?, &right?),
+                    Operator::Logical(_) | Operator::Unary(_) => None,
+                }
+            }
+            Expr::UnaryOp { op, expr, .. } => {
+                let operand = self.infer(expr);
+                match op {
+                    UnaryOperator::Not => Some(TypeAnnotation::named("bool")),
+                    UnaryOperator::Typeof => Some(TypeAnnotation::named("string")),
+                    _ => operand,
+                }
+            }
+            Expr::Assign { target, value, .. } => {
+                let actual = self.infer(value);
+                // Só atribuições simples têm o tipo do valor; `+=` etc. dependem do operador
+                let is_plain = matches!(
+                    expr,
+                    Expr::Assign {
+                        op: crate::ast::ast::AssignOperator::Assign,
+                        ..
+                    }
+                );
+                let (name, expected) = match target.as_ref() {
+                    Expr::Identifier(name) => match self.lookup_mut(name) {
+                        Some(binding) if binding.declared => (name.clone(), binding.ty.clone()),
+                        Some(binding) => {
+                            // Variável sem anotação passa a ter o tipo do novo valor
+                            binding.ty = if is_plain { actual.clone() } else { None };
+                            return actual;
+                        }
+                        None => return actual,
+                    },
+                    Expr::GetProperty { object, property } => {
+                        let class = self.infer(object);
+                        let field = match property.as_ref() {
+                            Expr::Identifier(field) => field.clone(),
+                            _ => return actual,
+                        };
+                        let expected = class.and_then(|class| self.find_field(&class.name, &field));
+                        (field, expected)
+                    }
+                    _ => return actual,
+                };
+                if let (true, Some(expected)) = (is_plain, expected) {
+                    if !self.expect_type(&expected, &actual) {
+                        let found = actual.as_ref().map(|t| t.to_string()).unwrap_or_default();
+                        self.report(
+                            &expected.location,
+                            format!("'{name}' is declared as {expected} but assigned {found}"),
+                        );
+                    }
+                }
+                actual
+            }
+            Expr::GetProperty { object, property } => {
+                let Expr::Identifier(property) = property.as_ref() else {
+                    self.infer(object);
+                    return None;
+                };
+                if let Some(class) = self.class_named(object) {
+                    let info = &self.classes[&class];
+                    // Color.Red é um valor do enum Color
+                    return info.is_enum.then(|| TypeAnnotation::named(&class));
+                }
+                let class = self.infer(object)?;
+                self.find_field(&class.name, property)
+            }
+            Expr::SetProperty {
+                object,
+                property,
+                value,
+            } => {
+                self.infer(object);
+                self.infer(property);
+                self.infer(value)
+            }
+            Expr::BracketAccess { object, property } => {
+                let object = self.infer(object);
+                self.infer(property);
+                match object {
+                    Some(ty) if ty.name == "Array" || ty.name == "array" => {
+                        ty.args.first().cloned()
+                    }
+                    _ => None,
+                }
+            }
+            Expr::Call {
+                callee,
+                args,
+                location,
+            } => self.infer_call(callee, args, location),
+            Expr::New { class_expr } => match class_expr.as_ref() {
+                Expr::Call {
+                    callee,
+                    args,
+                    location,
+                } => {
+                    let class = self.class_named(callee);
+                    if let Some(class) = class.as_ref().filter(|c| self.classes[*c].is_abstract) {
+                        let msg = format!("cannot instantiate abstract class '{class}'");
+                        self.report(location, msg);
+                    }
+                    match &class {
+                        Some(class) => {
+                            if let Some(constructor) = self.find_method(class, "constructor", false)
+                            {
+                                let name = format!("{class}.constructor");
+                                self.check_args(&name, &constructor, args, location);
+                            } else {
+                                for arg in args {
+                                    self.infer(arg);
+                                }
+                            }
+                        }
+                        None => {
+                            for arg in args {
+                                self.infer(arg);
+                            }
+                        }
+                    }
+                    class.map(|class| TypeAnnotation::named(&class))
+                }
+                other => self
+                    .class_named(other)
+                    .map(|class| TypeAnnotation::named(&class)),
+            },
+            Expr::Block(stmts) => {
+                self.check_body(stmts);
+                None
+            }
+            Expr::Spread(inner) | Expr::Delete(inner) => {
+                self.infer(inner);
+                None
+            }
+            Expr::NamedArg { value, .. } => self.infer(value),
+            Expr::Propagate(inner) => {
+                self.infer(inner);
+                None
+            }
+            Expr::ArrayComprehension { element, clauses } => {
+                self.push_scope();
+                self.check_clauses(clauses);
+                let element = self.infer(element);
+                self.pop_scope();
+                let mut ty = TypeAnnotation::named("Array");
+                ty.args.extend(element);
+                Some(ty)
+            }
+            Expr::ObjectComprehension {
+                key,
+                value,
+                clauses,
+            } => {
+                self.push_scope();
+                self.check_clauses(clauses);
+                self.infer(key);
+                self.infer(value);
+                self.pop_scope();
+                Some(TypeAnnotation::named("object"))
+            }
+            Expr::Pipe { value, target, .. } => {
+                // O valor entra como argumento extra; só as subexpressões são verificadas
+                self.infer(value);
+                match target.as_ref() {
+                    Expr::Call { callee, args, .. } => {
+                        self.infer(callee);
+                        for arg in args {
+                            self.infer(arg);
+                        }
+                    }
+                    other => {
+                        self.infer(other);
+                    }
+                }
+                None
+            }
+        }
+    }
+
+    /// Cláusulas de uma compreensão, já no escopo dela.
+    fn check_clauses(&mut self, clauses: &[ComprehensionClause]) {
+        for clause in clauses {
+            match clause {
+                ComprehensionClause::For { target, iterable } => {
+                    self.infer(iterable);
+                    self.define_pattern(target);
+                }
+                ComprehensionClause::If(condition) => {
+                    self.infer(condition);
+                }
+            }
+        }
+    }
+
+    /// Variáveis introduzidas por um alvo com destructuring (`[k, v]`, `{ a, b }`).
+    fn define_pattern(&mut self, pattern: &Expr) {
+        match pattern {
+            Expr::Identifier(name) => self.define(name, None),
+            Expr::Literal(Literal::Array(items)) => {
+                for item in items {
+                    self.define_pattern(item);
+                }
+            }
+            Expr::Literal(Literal::Object(entries)) => {
+                for entry in entries {
+                    match entry {
+                        crate::ast::ast::ObjectEntry::Property { value, .. } => {
+                            self.define_pattern(value)
+                        }
+                        crate::ast::ast::ObjectEntry::Shorthand(name) => self.define(name, None),
+                        crate::ast::ast::ObjectEntry::Spread(value) => self.define_pattern(value),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn infer_call(
+        &mut self,
+        callee: &Expr,
+        args: &[Expr],
+        location: &SourceLocation,
+    ) -> Option<TypeAnnotation> {
+        let target = match callee {
+            Expr::Identifier(name) if self.lookup(name).is_none() => self
+                .functions
+                .get(name)
+                .cloned()
+                .map(|callable| (name.clone(), callable)),
+            Expr::GetProperty { object, property } => match property.as_ref() {
+                Expr::Identifier(method) => {
+                    if let Some(class) = self.class_named(object) {
+                        if self.classes[&class].is_enum {
+                            // Construtor de variante: Color.Rgb(1, 2, 3)
+                            for arg in args {
+                                self.infer(arg);
+                            }
+                            return Some(TypeAnnotation::named(&class));
+                        }
+                        self.find_method(&class, method, true)
+                            .map(|callable| (format!("{class}.{method}"), callable))
+                    } else {
+                        self.infer(object).and_then(|class| {
+                            self.find_method(&class.name, method, false)
+                                .map(|callable| (format!("{}.{method}", class.name), callable))
+                        })
+                    }
+                }
+                _ => None,
+            },
+            other => {
+                self.infer(other);
+                None
+            }
+        };
+
+        match target {
+            Some((name, callable)) => {
+                self.check_args(&name, &callable, args, location);
+                callable.signature.returns
+            }
+            None => {
+                for arg in args {
+                    self.infer(arg);
+                }
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Anotações de tipo opcionais (`let x: number`, `fn f(a: string): bool`).
+//! O comando `check` (ver `checker`) as verifica sem executar o código; em
+//! modo estrito o interpretador também confere os valores em tempo de execução.
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use crate::{
+    ast::ast::TypeAnnotation,
+    environment::{
+        values::{Class, Value},
+        Environment,
+    },
+};
+
+pub mod checker;
+
+/// Tipos embutidos; qualquer outro nome deve ser uma classe ou enum.
+pub const PRIMITIVES: [&str; 15] = [
+    "any", "number", "int", "float", "bigint", "decimal", "string", "bool", "boolean", "null",
+    "void", "object", "array", "Array", "function",
+];
+
+thread_local! {
+    static STRICT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Liga as verificações de `let`, parâmetros, retornos e campos em tempo de execução.
+pub fn set_strict(strict: bool) {
+    STRICT.with(|flag| flag.set(strict));
+}
+
+pub fn is_strict() -> bool {
+    STRICT.with(|flag| flag.get())
+}
+
+/// Nome usado nas mensagens: a classe para instâncias, `type_of` para o resto.
+pub fn describe(value: &Value) -> String {
+    match value {
+        Value::Instance(instance) => instance.borrow().class.name.clone(),
+        other => other.type_of(),
+    }
+}
+
+/// Verdadeiro se `value` satisfaz `ty`. Classes são resolvidas em `env`; nomes
+/// desconhecidos são aceitos, já que só o `check` os reporta.
+pub fn value_matches(value: &Value, ty: &TypeAnnotation, env: &Rc<RefCell<Environment>>) -> bool {
+    let number = |kinds: &[&str]| match value {
+        Value::Number(n) => kinds.contains(&n.type_name()),
+        _ => false,
+    };
+    match ty.name.as_str() {
+        "any" => true,
+        "number" => matches!(value, Value::Number(_)),
+        "int" => number(&["int"]),
+        "float" => number(&["int", "float"]),
+        "bigint" => number(&["bigint"]),
+        "decimal" => number(&["decimal"]),
+        "string" => matches!(value, Value::String(_)),
+        "bool" | "boolean" => matches!(value, Value::Bool(_)),
+        "null" => value.is_null(),
+        "void" => matches!(value, Value::Void | Value::Null),
+        "object" => matches!(value, Value::Object(_) | Value::Instance(_) | Value::Null),
+        "function" => matches!(
+            value,
+            Value::Function(_) | Value::Builtin(_) | Value::InternalFunction(_) | Value::Null
+        ),
+        "array" | "Array" => match value {
+            Value::Array(items) => match ty.args.first() {
+                Some(item_ty) => items
+                    .get_value()
+                    .borrow()
+                    .iter()
+                    .all(|item| value_matches(item, item_ty, env)),
+                None => true,
+            },
+            Value::Null => true,
+            _ => false,
+        },
+        name => match env.borrow().get(name) {
+            Some(class) if class.is_class() => {
+                value.is_null() || Class::is_instance_of(value, &class)
+            }
+            _ => true,
+        },
+    }
+}
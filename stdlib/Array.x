@@ -1,4 +1,4 @@
-class Collection {
+abstract class Collection {
     value = [];
     length = 0;
     constructor(self, ...args) {
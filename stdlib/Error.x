@@ -1,6 +1,7 @@
 class Error {
     name = "Error";
     message = "Default error message";
+    stack = ""; // frames "at funcao (arquivo:linha)", preenchido na criação
     constructor(self, name, message) {
         if (name != null) {
             self.name = name;
@@ -33,6 +34,10 @@ class Error {
         return self.message;
     }
 
+    getStack(self) {
+        return self.stack;
+    }
+
     getName(self) {
         return self.name;
     }
@@ -0,0 +1,233 @@
+//! Roda cada script de `tests/scripts` com o binário `lang` e compara a saída
+//! com o arquivo `.out` de mesmo nome.
+use std::{fs, process::Command};
+
+use regex::Regex;
+
+struct Run {
+    success: bool,
+    stdout: String,
+    stderr: String,
+}
+
+fn run(args: &[&str], name: &str, envs: &[(&str, &str)]) -> Run {
+    let output = Command::new(env!("CARGO_BIN_EXE_lang"))
+        .args(args)
+        .arg(format!("tests/scripts/{name}.x"))
+        .env("RUST_BACKTRACE", "0")
+        .envs(envs.iter().copied())
+        .output()
+        .expect("failed to run lang");
+
+    // Sem as cores do `Io.println` e a linha de tempo do `interpret_bench`
+    let colors = Regex::new(r"\x1b\[[0-9;]*m").unwrap();
+    let clean = |bytes: &[u8]| {
+        colors
+            .replace_all(&String::from_utf8_lossy(bytes), "")
+            .into_owned()
+    };
+    let stdout = clean(&output.stdout)
+        .lines()
+        .filter(|line| !line.starts_with("Elapsed: "))
+        .map(|line| format!("{line}\n"))
+        .collect();
+
+    Run {
+        success: output.status.success(),
+        stdout,
+        stderr: clean(&output.stderr),
+    }
+}
+
+fn expected(name: &str) -> String {
+    fs::read_to_string(format!("tests/scripts/{name}.out"))
+        .unwrap_or_else(|err| panic!("tests/scripts/{name}.out: {err}"))
+}
+
+/// O script termina sem erro e imprime exatamente o `.out`.
+fn check(name: &str) {
+    check_with_env(name, &[]);
+}
+
+fn check_with_env(name: &str, envs: &[(&str, &str)]) {
+    let run = run(&[], name, envs);
+    assert!(run.success, "{name} failed:\n{}", run.stderr);
+    assert_eq!(run.stdout, expected(name), "unexpected output from {name}");
+}
+
+/// O script termina com um erro não capturado contendo `message`, depois de
+/// imprimir o `.out`.
+fn check_error(name: &str, message: &str) {
+    let run = run(&[], name, &[]);
+    assert!(!run.success, "{name} should have failed");
+    assert!(
+        run.stderr.contains(message),
+        "{name}: expected {message:?} in:\n{}",
+        run.stderr
+    );
+    assert_eq!(run.stdout, expected(name), "unexpected output from {name}");
+}
+
+/// `lang check` encontra erros de tipo e os diagnósticos são exatamente o `.out`.
+fn check_types(name: &str) {
+    let run = run(&["check"], name, &[]);
+    assert!(!run.success, "check should fail for {name}");
+    assert_eq!(
+        run.stderr,
+        expected(name),
+        "unexpected diagnostics for {name}"
+    );
+}
+
+#[test]
+fn int_and_float() {
+    check("int_float");
+}
+
+#[test]
+fn bigint_and_decimal() {
+    check("bigint_decimal");
+}
+
+#[test]
+fn numeric_literals() {
+    check("numeric_literals");
+}
+
+#[test]
+fn strict_and_deep_equality() {
+    check("equality");
+}
+
+#[test]
+fn delete_and_object_helpers() {
+    check("delete_object");
+}
+
+#[test]
+fn native_error_hierarchy() {
+    check("native_errors");
+}
+
+#[test]
+fn typed_and_guarded_catch() {
+    check("catch_clauses");
+}
+
+#[test]
+fn stack_trace_lines() {
+    check_error(
+        "stack_trace",
+        concat!(
+            "RangeError: saldo insuficiente\n",
+            "    at Conta.sacar (tests/scripts/stack_trace.x:85)\n",
+            "    at pagar (tests/scripts/stack_trace.x:90)\n",
+            "    at <main> (tests/scripts/stack_trace.x:94)",
+        ),
+    );
+}
+
+#[test]
+fn call_depth_raises_range_error() {
+    check("call_depth");
+}
+
+#[test]
+fn configurable_call_depth() {
+    check_with_env("call_depth_limit", &[("LANG_MAX_CALL_DEPTH", "500")]);
+}
+
+#[test]
+fn tail_calls_run_in_constant_stack() {
+    check("tail_calls");
+}
+
+#[test]
+fn enums_and_match() {
+    check("enum_match");
+}
+
+#[test]
+fn type_check_reports_mismatches() {
+    check_types("type_check");
+}
+
+#[test]
+fn strict_mode_asserts_annotations() {
+    check_with_env("strict_types", &[("LANG_STRICT", "1")]);
+}
+
+#[test]
+fn interfaces_and_implements() {
+    check("interfaces");
+}
+
+#[test]
+fn abstract_classes_and_methods() {
+    check("abstract_classes");
+}
+
+#[test]
+fn decorators_on_functions_methods_and_classes() {
+    check("decorators");
+}
+
+#[test]
+fn operator_overloading() {
+    check("operators");
+}
+
+#[test]
+fn function_values_and_method_references() {
+    check("function_values");
+}
+
+#[test]
+fn named_arguments() {
+    check("named_args");
+}
+
+#[test]
+fn pipe_operator_and_composition() {
+    check("pipe");
+}
+
+#[test]
+fn array_and_object_comprehensions() {
+    check("comprehensions");
+}
+
+#[test]
+fn option_result_and_propagation() {
+    check("option_result");
+}
+
+#[test]
+fn io_results_mode() {
+    check_with_env("io_results", &[("LANG_IO_RESULTS", "1")]);
+}
+
+#[test]
+fn defer_and_using_cleanup() {
+    check("defer_using");
+}
+
+#[test]
+fn drop_runs_once_when_last_reference_goes() {
+    check("destructors");
+}
+
+#[test]
+fn gc_frees_dropped_cycles() {
+    check("gc_cycles");
+}
+
+#[test]
+fn gc_keeps_live_cycles_during_a_call() {
+    check("gc_mid_method");
+}
+
+#[test]
+fn weak_refs_and_ephemeron_weak_map() {
+    check("weak_refs");
+}
@@ -0,0 +1,50 @@
+// Classes abstratas não são instanciáveis e exigem os métodos abstratos
+abstract class Forma {
+    nome = "forma";
+    abstract area(self);
+    descrever(self) {
+        return self.nome + ": " + self.area();
+    }
+}
+
+class Quadrado extends Forma {
+    lado = 0;
+    constructor(self, lado) {
+        self.lado = lado;
+        self.nome = "quadrado";
+    }
+    area(self) {
+        return self.lado * self.lado;
+    }
+}
+
+let q = new Quadrado(3);
+Io.println(q.descrever());
+
+try {
+    new Forma();
+} catch (e: TypeError) {
+    Io.println(e.message);
+}
+
+try {
+    class Circulo extends Forma {
+        raio = 1;
+    }
+} catch (e: TypeError) {
+    Io.println(e.message);
+}
+
+// Subclasse abstrata pode adiar a implementação
+abstract class Poligono extends Forma {
+    lados(self) {
+        return 0;
+    }
+}
+class Triangulo extends Poligono {
+    area(self) {
+        return 1;
+    }
+}
+let t = new Triangulo();
+Io.println(t.descrever());
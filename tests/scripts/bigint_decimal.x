@@ -0,0 +1,23 @@
+// BigInt (sufixo `n`) e Decimal (sufixo `m`) são exatos
+Io.println(typeof(10n), typeof(0.1m));
+Io.println(2n ** 100n, 123456789012345678901234567890n * 10n);
+Io.println(0.1m + 0.2m, 0.1m + 0.2m == 0.3m, 19.99m * 3m);
+Io.println(10n > 9n, 1.50m < 1.51m);
+
+// `~/` e `%` usam piso, como em Int
+let pares = [[7n, 2n], [-7n, 2n], [7n, -2n], [-7n, -2n]];
+for (let par of pares) {
+    let a = par[0];
+    let b = par[1];
+    Io.println(a ~/ b, a % b, (a ~/ b) * b + a % b == a);
+}
+let decimais = [[7.5m, 2m], [-7.5m, 2m], [7.5m, -2m]];
+for (let par of decimais) {
+    let a = par[0];
+    let b = par[1];
+    Io.println(a ~/ b, a % b, (a ~/ b) * b + a % b == a);
+}
+Io.println(JSON.stringify({ total: 12345678901234567890.12m, id: 98765432109876543210n }));
+let lido = JSON.parse('{"total": 12345678901234567890.12, "id": 98765432109876543210, "taxa": 0.1}');
+Io.println(lido.total, typeof(lido.total), lido.id, typeof(lido.id), lido.taxa + 0.2m);
+Io.println(JSON.stringify(lido));
@@ -0,0 +1,56 @@
+// Recursão sem chamada de cauda até o limite: vira RangeError capturável, sem
+// estourar a pilha nativa (inclusive em builds de debug)
+fn deep(n) {
+    let r = 1 + deep(n + 1);
+    return r;
+}
+try {
+    deep(0);
+} catch (e: RangeError) {
+    Io.println(e.name, e.message);
+}
+
+class No {
+    filho = null;
+    constructor(self, n) {
+        self.filho = new No(n + 1);
+    }
+}
+try {
+    new No(0);
+} catch (e: RangeError) {
+    Io.println("new", e.message);
+}
+
+// Chamadas feitas pelo Rust (`.call`, operadores) respeitam o mesmo limite
+fn viaCall(n) {
+    return viaCall.call(null, n + 1);
+}
+try {
+    viaCall(0);
+} catch (e: RangeError) {
+    Io.println(".call", e.message);
+}
+
+class Valor {
+    @Operator
+    add(self, outro) {
+        return self + outro;
+    }
+}
+let v = new Valor();
+try {
+    let soma = v + 1;
+} catch (e: RangeError) {
+    Io.println("operator", e.message);
+}
+
+// Depois do erro a pilha volta ao normal
+fn soma(n) {
+    if (n == 0) {
+        return 0;
+    }
+    let r = n + soma(n - 1);
+    return r;
+}
+Io.println(soma(100));
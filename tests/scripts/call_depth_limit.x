@@ -0,0 +1,12 @@
+// Com LANG_MAX_CALL_DEPTH=500 a chamada 501 falha
+let maximo = 0;
+fn deep(n) {
+    maximo = n;
+    let r = 1 + deep(n + 1);
+    return r;
+}
+try {
+    deep(1);
+} catch (e) {
+    Io.println(e.name, maximo);
+}
@@ -0,0 +1,46 @@
+// Cláusulas catch testadas em ordem: tipo, guarda, destructuring e sem binding
+class HttpError extends Error {
+    code = 0;
+    constructor(self, code) {
+        self.super("HttpError", "http " + code);
+        self.code = code;
+    }
+}
+
+fn tratar(erro) {
+    try {
+        throw erro;
+    } catch (e: TypeError) {
+        return "type: " + e.message;
+    } catch (e: HttpError) if (e.code == 404) {
+        return "not found";
+    } catch ({ name, message: msg }: HttpError) {
+        return name + " -> " + msg;
+    } catch (e: RangeError) {
+        return "range";
+    }
+}
+
+Io.println(tratar(new TypeError("x")));
+Io.println(tratar(new HttpError(404)));
+Io.println(tratar(new HttpError(500)));
+Io.println(tratar(new RangeError("y")));
+
+try {
+    throw new Error(null, "qualquer");
+} catch {
+    Io.println("sem binding");
+}
+
+// Erros sem cláusula compatível propagam depois do finally
+try {
+    try {
+        throw new ReferenceError("fora");
+    } catch (e: TypeError) {
+        Io.println("nao deveria");
+    } finally {
+        Io.println("finally");
+    }
+} catch (e) {
+    Io.println("propagou", e.name, e.message);
+}
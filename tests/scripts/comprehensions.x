@@ -0,0 +1,17 @@
+// Compreensões de arrays e objetos
+let xs = [3, -1, 4, -1, 5];
+Io.println([x * 2 for x of xs if x > 0]);
+Io.println([[a, b] for a of [1, 2] for b of ["x", "y"] if a != 2 || b != "x"]);
+
+let pares = [["um", 1], ["dois", 2], ["tres", 3]];
+let mapa = { k: v * 10 for [k, v] of pares if v != 2 };
+Io.println(mapa);
+Io.println({ nome: [i for i of range(0, n)] for [nome, n] of [["a", 1], ["b", 3]] });
+
+// A variável do laço fica no escopo da compreensão
+let x = "fora";
+let ys = [x for x of [1, 2]];
+Io.println(ys, x);
+
+let s = "abc";
+Io.println(s.enumerate());
@@ -0,0 +1,54 @@
+// Decoradores escritos na própria linguagem
+let chamadas = 0;
+
+fn memoize(f) {
+    let cache = {};
+    fn memo(n) {
+        let chave = "" + n;
+        if (!Object.hasOwn(cache, chave)) {
+            cache[chave] = f(n);
+        }
+        return cache[chave];
+    }
+    return memo;
+}
+
+fn logCalls(f, prefixo) {
+    fn logado(...args) {
+        Io.println(prefixo, f.name, args);
+        return f.apply(null, args);
+    }
+    return logado;
+}
+
+@memoize
+fn quadrado(n) {
+    chamadas++;
+    return n * n;
+}
+Io.println(quadrado(4), quadrado(4), quadrado(5), chamadas);
+
+@logCalls("->")
+fn soma(a, b) {
+    return a + b;
+}
+Io.println(soma(1, 2));
+
+let registro = [];
+fn registrar(classe, rotulo) {
+    registro.push(rotulo);
+    return classe;
+}
+
+@registrar("entidade")
+class Usuario {
+    nome = "";
+    @logCalls("metodo")
+    ola(self, outro) {
+        return "ola " + outro;
+    }
+}
+let u = new Usuario();
+Io.println(u.ola("Bia"), registro);
+
+Io.println(decorators(quadrado), decorators(soma), decorators(Usuario));
@@ -0,0 +1,58 @@
+// `defer` roda ao sair do bloco, em ordem inversa, mesmo com erro
+let log = [];
+fn registrar(msg) {
+    log.push(msg);
+}
+
+fn trabalho(falhar) {
+    defer registrar("primeiro");
+    defer registrar("segundo");
+    if (falhar) {
+        throw new Error(null, "falhou");
+    }
+    registrar("corpo");
+    return "ok";
+}
+Io.println(trabalho(false), log);
+log = [];
+try {
+    trabalho(true);
+} catch (e) {
+    Io.println(e.message, log);
+}
+
+log = [];
+for (let i = 0; i < 2; i++) {
+    defer registrar("fim " + i);
+    registrar("volta " + i);
+}
+Io.println(log);
+
+// `using` chama close() ou dispose() no fim do bloco
+class Arquivo {
+    nome = "";
+    constructor(self, nome) {
+        self.nome = nome;
+    }
+    close(self) {
+        registrar("close " + self.nome);
+    }
+}
+class Timer {
+    dispose(self) {
+        registrar("dispose");
+    }
+}
+
+log = [];
+using (let f = new Arquivo("a.txt")) {
+    registrar("usando " + f.nome);
+}
+try {
+    using (let t = new Timer()) {
+        throw new Error(null, "no meio");
+    }
+} catch (e) {
+    registrar(e.message);
+}
+Io.println(log);
@@ -0,0 +1,31 @@
+// `delete` remove chaves de objetos e campos de instâncias
+let obj = { a: 1, b: 2, c: 3 };
+Io.println(delete obj.a, delete obj["b"], delete obj.nada);
+Io.println(Object.keys(obj), Object.hasOwn(obj, "a"));
+
+class Pessoa {
+    nome = "";
+    idade = 0;
+    constructor(self, nome, idade) {
+        self.nome = nome;
+        self.idade = idade;
+    }
+    saudacao(self) {
+        return "oi " + self.nome;
+    }
+}
+let p = new Pessoa("Ana", 30);
+// Campos de instância não têm ordem garantida
+let campos = Object.keys(p);
+campos.sort();
+Io.println(campos, len(Object.values(p)), Object.hasOwn(p, "saudacao"));
+Io.println(delete p.idade, Object.hasOwn(p, "idade"), delete p.saudacao, p.saudacao());
+p.idade = 31;
+Io.println(p.idade, len(Object.entries(p)));
+
+let alvo = Object.assign({ x: 1 }, { y: 2 }, p);
+let chaves = Object.keys(alvo);
+chaves.sort();
+Io.println(chaves, alvo.nome);
+let pares = Object.fromEntries([["k", 1], ["v", 2]]);
+Io.println(pares.k + pares.v, Object.hasOwn(pares, "k"), Object.hasOwn(pares, "z"));
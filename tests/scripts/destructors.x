@@ -0,0 +1,50 @@
+// `drop(self)` roda uma única vez quando a última referência some
+let log = [];
+class Recurso {
+    nome = "";
+    constructor(self, nome) {
+        self.nome = nome;
+    }
+    drop(self) {
+        log.push("drop " + self.nome);
+    }
+}
+
+let a = new Recurso("a");
+let b = a;
+a = null;
+log.push("ainda vivo");
+b = null;
+Io.println(log);
+
+log = [];
+fn escopo() {
+    let local = new Recurso("local");
+    log.push("dentro");
+}
+escopo();
+Io.println(log);
+
+// Erros dentro do drop viram avisos e não interrompem o script
+class Falho {
+    drop(self) {
+        throw new Error(null, "erro no drop");
+    }
+}
+let f = new Falho();
+f = null;
+Io.println("continua");
+
+// Ressuscitar a instância no drop não faz o drop rodar de novo
+let salvo = null;
+class Fenix {
+    drop(self) {
+        log.push("fenix");
+        salvo = self;
+    }
+}
+log = [];
+let x = new Fenix();
+x = null;
+salvo = null;
+Io.println(log);
@@ -0,0 +1,53 @@
+// Enums com variantes e match
+enum Shape {
+    Empty,
+    Circle(r),
+    Rect(w, h)
+}
+
+fn area(s) {
+    match (s) {
+        Shape.Circle(r) => {
+            if (r > 10) {
+                return 999;
+            }
+            return r * r;
+        }
+        Shape.Rect(w, h) => {
+            let a = w * h;
+            while (true) {
+                return a;
+            }
+        }
+        _ => {
+            for (let i = 0; i < 3; i++) {
+                if (i == 1) {
+                    return i;
+                }
+            }
+            return -1;
+        }
+    }
+    return -2;
+}
+Io.println(area(Shape.Circle(20)), area(Shape.Circle(3)), area(Shape.Rect(2, 5)), area(Shape.Empty));
+
+// Um braço sem `return` segue para depois do match
+fn descrever(s) {
+    let texto = "?";
+    match (s) {
+        Shape.Empty => {
+            texto = "vazio";
+        }
+        _ => {
+            texto = "forma";
+        }
+    }
+    return texto;
+}
+Io.println(descrever(Shape.Empty), descrever(Shape.Circle(1)));
+
+let c = Shape.Circle(2);
+Io.println(c instanceof Shape, c instanceof Shape.Circle, c instanceof Shape.Rect);
+Io.println(c == Shape.Circle(2), c == Shape.Circle(3), Shape.Empty == Shape.Empty);
+Io.println(JSON.stringify(Shape.Rect(1, 2)), JSON.stringify(Shape.Empty));
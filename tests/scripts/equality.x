@@ -0,0 +1,54 @@
+// `===` não converte; `==` segue a tabela de `Value::loose_equal`
+Io.println(1 === 1, 1 === 1.0, 1 == 1.0, 1 !== 1.0, "a" === "a");
+Io.println("10" == 10, true == 1, false == 0, null == null, null == 0, "a" == 1);
+
+let a = [1, 2];
+let b = [1, 2];
+Io.println(a === a, a === b, a !== b);
+
+class Ponto {
+    x = 0;
+    y = 0;
+    constructor(self, x, y) {
+        self.x = x;
+        self.y = y;
+    }
+}
+let p = new Ponto(1, 2);
+Io.println(p === p, p === new Ponto(1, 2));
+
+// deepEqual percorre arrays, objetos (sem ordem de chaves) e instâncias
+Io.println(deepEqual(a, b), deepEqual([1, [2, 3]], [1, [2, 4]]));
+Io.println(deepEqual({ x: 1, y: { z: [1] } }, { y: { z: [1] }, x: 1 }), deepEqual({ x: 1 }, { x: 1, y: 2 }));
+Io.println(deepEqual(p, new Ponto(1, 2)), deepEqual(p, new Ponto(2, 1)));
+
+let ciclo = { nome: "a" };
+ciclo.eu = ciclo;
+let outro = { nome: "a" };
+outro.eu = outro;
+Io.println(deepEqual(ciclo, outro));
+
+// `@Operator eq` tem a palavra final
+class Aproximado {
+    valor = 0;
+    constructor(self, valor) {
+        self.valor = valor;
+    }
+    @Operator
+    eq(self, other) {
+        return self.valor ~/ 10 == other.valor ~/ 10;
+    }
+}
+Io.println(deepEqual(new Aproximado(11), new Aproximado(19)), deepEqual(new Aproximado(11), new Aproximado(21)));
+
+// Aridade errada é TypeError
+try {
+    deepEqual(1);
+} catch (e: TypeError) {
+    Io.println(e.name, e.message);
+}
+try {
+    deepEqual(1, 1, 1);
+} catch (e: TypeError) {
+    Io.println(e.name, e.message);
+}
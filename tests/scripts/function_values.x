@@ -0,0 +1,26 @@
+// Funções como valores: name, arity, bind, call, apply, toString e métodos destacados
+fn soma(a, b, c) {
+    return a + b + c;
+}
+Io.println(soma.name, soma.arity, soma.call(null, 1, 2, 3), soma.apply(null, [4, 5, 6]));
+
+let soma10 = soma.bind(null, 10);
+let soma15 = soma10.bind(null, 5);
+Io.println(soma10(1, 2), soma15(1), soma10.arity, soma15.arity);
+Io.println(soma.toString());
+
+class Contador {
+    total = 0;
+    incrementar(self, n) {
+        self.total = self.total + n;
+        return self.total;
+    }
+}
+let c = new Contador();
+let inc = c.incrementar;
+inc(2);
+inc(3);
+Io.println(c.total, inc.name, inc.arity);
+
+let outro = new Contador();
+Io.println(c.incrementar.call(outro, 7), outro.total, c.total);
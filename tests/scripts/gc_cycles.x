@@ -0,0 +1,60 @@
+// Ciclos que só o `gc()` libera: lista duplamente encadeada, objeto que aponta
+// para si mesmo e closure que captura o próprio dono
+class No {
+    valor = 0;
+    proximo = null;
+    anterior = null;
+    constructor(self, valor) {
+        self.valor = valor;
+    }
+}
+
+fn lista(n) {
+    let primeiro = new No(0);
+    let atual = primeiro;
+    for (let i = 1; i < n; i++) {
+        let no = new No(i);
+        atual.proximo = no;
+        no.anterior = atual;
+        atual = no;
+    }
+    atual.proximo = primeiro;
+    primeiro.anterior = atual;
+    return primeiro;
+}
+
+let base = gcStats().tracked;
+
+let l = lista(100);
+l = null;
+Io.println("lista antes: " + (gcStats().tracked - base));
+Io.println("lista liberados: " + gc());
+Io.println("lista depois: " + (gcStats().tracked - base));
+
+let o = { nome: "o" };
+o.eu = o;
+o = null;
+Io.println("objeto liberados: " + gc());
+Io.println("objeto depois: " + (gcStats().tracked - base));
+
+class Caixa {
+    callback = null;
+}
+fn criar() {
+    let caixa = new Caixa();
+    fn dono() {
+        return caixa;
+    }
+    caixa.callback = dono;
+}
+criar();
+Io.println("closure liberados: " + gc());
+Io.println("closure depois: " + (gcStats().tracked - base));
+
+let vivo = lista(3);
+Io.println("vivos liberados: " + gc());
+Io.println("vivos depois: " + (gcStats().tracked - base));
+Io.println("vivo: " + vivo.proximo.valor + " " + vivo.anterior.valor);
+
+let stats = gcStats();
+Io.println("coletas: " + stats.collections + " total: " + stats.collected);
@@ -0,0 +1,30 @@
+// Um `gc()` no meio de um método não libera ciclos que ainda estão em uso: nem
+// os alcançáveis só pela pilha da chamada, nem o próprio `self`
+class No {
+    valor = 0;
+    outro = null;
+    constructor(self, valor) {
+        self.valor = valor;
+    }
+
+    coletar(self) {
+        let a = new No(1);
+        let b = new No(2);
+        a.outro = b;
+        b.outro = a;
+        self.outro = self;
+        let liberados = gc();
+        return [liberados, a.outro.valor, b.outro.valor, self.outro.valor];
+    }
+}
+
+fn usar() {
+    let n = new No(7);
+    return n.coletar();
+}
+
+Io.println(usar());
+
+// Só depois que a chamada termina os ciclos viram lixo: `n` com o próprio
+// ciclo e o par `a`/`b`
+Io.println("depois: " + gc());
@@ -0,0 +1,17 @@
+// Int e Float são tipos distintos; `~/` e `%` usam divisão com piso
+Io.println(typeof(10), typeof(10.0), typeof(1e3));
+Io.println(10_000_000_000 * 3, 7 / 2, 7 ~/ 2);
+
+let pares = [[7, 2], [-7, 2], [7, -2], [-7, -2]];
+for (let par of pares) {
+    let a = par[0];
+    let b = par[1];
+    Io.println(a ~/ b, a % b, (a ~/ b) * b + a % b == a);
+}
+Io.println(-7.5 ~/ 2.0, -7.5 % 2.0, 7.5 % -2.0);
+
+// Overflow e literais acima de i64 são promovidos para BigInt
+let max = 9223372036854775807;
+Io.println(typeof(max), max + 1, typeof(max + 1));
+Io.println(9223372036854775808, typeof(-9223372036854775809));
+Io.println(0xFFFFFFFFFFFFFFFFFF);
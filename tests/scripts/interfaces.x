@@ -0,0 +1,55 @@
+// Interfaces com métodos exigidos e padrão
+interface Nomeado {
+    nome(self);
+    saudacao(self) {
+        return "Olá, " + self.nome();
+    }
+}
+
+interface Contavel extends Nomeado {
+    contar(self);
+}
+
+class Pessoa implements Nomeado {
+    n = "";
+    constructor(self, n) {
+        self.n = n;
+    }
+    nome(self) {
+        return self.n;
+    }
+}
+
+class Grupo implements Contavel {
+    nome(self) {
+        return "grupo";
+    }
+    contar(self) {
+        return 3;
+    }
+    saudacao(self) {
+        return "Oi, " + self.nome();
+    }
+}
+
+let p = new Pessoa("Ana");
+let g = new Grupo();
+Io.println(p.saudacao(), g.saudacao(), g.contar());
+Io.println(p implements Nomeado, p implements Contavel, g implements Nomeado);
+
+// Métodos faltando são um TypeError na declaração
+try {
+    class Incompleto implements Contavel {
+        nome(self) {
+            return "x";
+        }
+    }
+} catch (e: TypeError) {
+    Io.println(e.message);
+}
+
+try {
+    new Nomeado();
+} catch (e: TypeError) {
+    Io.println(e.message);
+}
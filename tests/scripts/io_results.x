@@ -0,0 +1,10 @@
+// Com LANG_IO_RESULTS=1 o Fs retorna Result em vez de lançar
+let lido = Fs.readFile("tests/scripts/nao_existe.txt");
+Io.println(lido instanceof Err, lido.unwrapOr("padrao"));
+
+fn comeco(caminho) {
+    let texto = Fs.readFile(caminho)?;
+    return Ok(len(texto) > 0);
+}
+Io.println(comeco("tests/scripts/io_results.x").unwrap());
+Io.println(comeco("tests/scripts/nao_existe.txt").unwrapOr("sem arquivo"));
@@ -0,0 +1,42 @@
+// Argumentos nomeados depois dos posicionais
+fn conectar(host, porta, tentativas) {
+    return host + ":" + porta + " x" + tentativas;
+}
+Io.println(conectar("a", tentativas: 3, porta: 80));
+Io.println(conectar(host: "b", porta: 1, tentativas: 2));
+let resto = [8080, 5];
+Io.println(conectar("c", ...resto));
+
+class Servidor {
+    host = "";
+    porta = 0;
+    constructor(self, host, porta) {
+        self.host = host;
+        self.porta = porta;
+    }
+    url(self, caminho, seguro) {
+        let esquema = "http";
+        if (seguro) {
+            esquema = "https";
+        }
+        return esquema + "://" + self.host + ":" + self.porta + caminho;
+    }
+}
+let s = new Servidor(porta: 443, host: "x.com");
+Io.println(s.url(seguro: true, caminho: "/api"));
+
+try {
+    conectar("a", porta: 1, outra: 2);
+} catch (e: TypeError) {
+    Io.println(e.message);
+}
+try {
+    conectar("a", host: "b");
+} catch (e: TypeError) {
+    Io.println(e.message);
+}
+try {
+    Io.println(texto: "x");
+} catch (e: TypeError) {
+    Io.println(e.message);
+}
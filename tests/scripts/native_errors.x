@@ -0,0 +1,33 @@
+// O interpretador lança a subclasse de Error de cada tipo de falha
+fn tipo(acao) {
+    try {
+        acao();
+    } catch (e) {
+        return e.name + " " + (e instanceof Error);
+    }
+    return "ok";
+}
+
+fn referencia() { return naoExiste; }
+fn divisao() { return 1 ~/ 0; }
+fn chamada() { let x = 1; return x(); }
+Io.println(tipo(referencia));
+Io.println(tipo(divisao));
+Io.println(tipo(chamada));
+
+let e = new RangeError("fora");
+Io.println(e instanceof RangeError, e instanceof TypeError, e instanceof Error, e.message);
+
+// Scripts podem estender as classes nativas
+class ValidationError extends TypeError {
+    campo = "";
+    constructor(self, campo) {
+        self.super("invalid " + campo);
+        self.campo = campo;
+    }
+}
+try {
+    throw new ValidationError("email");
+} catch (err) {
+    Io.println(err instanceof ValidationError, err instanceof TypeError, err.campo, err.message);
+}
@@ -0,0 +1,11 @@
+// Literais em outras bases e valores especiais
+Io.println(0xFF, 0o755, 0b1010, 0xdead_beef, -0x10);
+Io.println(Infinity, -Infinity, NaN, NaN == NaN, 1.5e3);
+Io.println(Number.parseInt("ff", 16), Number.parseInt("0x1A"), Number.parseInt("  42px"), Number.parseInt("z"));
+Io.println(Number.parseInt("-101", 2), Number.parseFloat("3.25abc"), Number.parseFloat("-1e2"));
+Io.println((255).toString(16), (255).toString(2), (-8).toString(8), (35).toString(36));
+try {
+    (10).toString(1);
+} catch (e) {
+    Io.println(e.message);
+}
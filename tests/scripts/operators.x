@@ -0,0 +1,74 @@
+// Sobrecarga com @Operator: aritméticos, refletidos, comparação, [], unários, in e chamada
+class Vetor {
+    x = 0;
+    y = 0;
+    constructor(self, x, y) {
+        self.x = x;
+        self.y = y;
+    }
+    @Operator add(self, other) {
+        return new Vetor(self.x + other.x, self.y + other.y);
+    }
+    @Operator
+    mul(self, k) {
+        return new Vetor(self.x * k, self.y * k);
+    }
+    @Operator
+    rmul(self, k) {
+        return new Vetor(self.x * k * 10, self.y * k * 10);
+    }
+    @Operator
+    rsub(self, k) {
+        return new Vetor(k - self.x, k - self.y);
+    }
+    @Operator
+    eq(self, other) {
+        return self.x == other.x && self.y == other.y;
+    }
+    @Operator
+    compare(self, other) {
+        return (self.x + self.y) - (other.x + other.y);
+    }
+    @Operator
+    neg(self) {
+        return new Vetor(-self.x, -self.y);
+    }
+    @Operator
+    not(self) {
+        return self.x == 0 && self.y == 0;
+    }
+    @Operator
+    get(self, i) {
+        if (i == 0) {
+            return self.x;
+        }
+        return self.y;
+    }
+    @Operator
+    set(self, i, valor) {
+        if (i == 0) {
+            self.x = valor;
+        } else {
+            self.y = valor;
+        }
+    }
+    @Operator
+    contains(self, valor) {
+        return self.x == valor || self.y == valor;
+    }
+    @Operator
+    call(self, k) {
+        return self.x * k + self.y;
+    }
+    toString(self) {
+        return "(" + self.x + ", " + self.y + ")";
+    }
+}
+
+let a = new Vetor(1, 2);
+let b = new Vetor(3, 4);
+Io.println((a + b).toString(), (a * 2).toString(), (2 * a).toString(), (10 - a).toString());
+Io.println(a == new Vetor(1, 2), a != b, a < b, a >= b, b > a);
+Io.println((-a).toString(), !a, !new Vetor(0, 0));
+a[1] = 7;
+Io.println(a[0], a[1], 7 in a, 5 in a, a(10));
@@ -0,0 +1,51 @@
+// Option e Result com combinadores e `?`
+fn dividir(a, b) {
+    if (b == 0) {
+        return Err("divisao por zero");
+    }
+    return Ok(a / b);
+}
+fn buscar(lista, i) {
+    if (i < len(lista)) {
+        return Some(lista[i]);
+    }
+    return None;
+}
+fn dobro(x) {
+    return x * 2;
+}
+fn metade(x) {
+    return dividir(x, 2);
+}
+
+Io.println(dividir(8, 2).map(dobro).unwrap(), dividir(1, 0).unwrapOr(-1));
+Io.println(dividir(1, 0).mapErr(dobro).unwrapOr("?"), dividir(8, 2).andThen(metade).unwrap());
+Io.println(buscar([1, 2], 1).map(dobro).unwrapOr(0), buscar([1, 2], 5).map(dobro).unwrapOr(0));
+Io.println(Some(1) == Some(1), None == None, Ok(1) == Err(1));
+
+// `?` devolve o None/Err da função atual
+fn calcular(a, b, c) {
+    let x = dividir(a, b)?;
+    let y = dividir(x, c)?;
+    return Ok(x + y);
+}
+Io.println(calcular(8, 2, 2).unwrap(), calcular(8, 0, 2).unwrapOr("falhou"), calcular(8, 2, 0).unwrapOr("falhou"));
+
+fn primeiroDobro(lista) {
+    let v = buscar(lista, 0)?;
+    return Some(v * 2);
+}
+Io.println(primeiroDobro([21]).unwrap(), primeiroDobro([]) == None);
+
+try {
+    None.unwrap();
+} catch (e) {
+    Io.println(e.name);
+}
+
+// Sem LANG_IO_RESULTS o Fs lança
+try {
+    Fs.readFile("tests/scripts/nao_existe.txt");
+} catch (e: IoError) {
+    Io.println("IoError");
+}
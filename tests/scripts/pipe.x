@@ -0,0 +1,31 @@
+// `|>` passa o valor como primeiro argumento ou no lugar de `_`
+fn dobro(x) {
+    return x * 2;
+}
+fn sub(a, b) {
+    return a - b;
+}
+fn somar(a, b) {
+    return a + b;
+}
+Io.println(5 |> dobro, 5 |> dobro |> somar(1), 5 |> sub(100, _), 3 + 4 |> dobro);
+
+class Texto {
+    valor = "";
+    constructor(self, valor) {
+        self.valor = valor;
+    }
+    repetir(self, s, n) {
+        let r = "";
+        for (let i = 0; i < n; i++) {
+            r = r + s;
+        }
+        return r;
+    }
+}
+let t = new Texto("x");
+Io.println("ab" |> t.repetir(3));
+
+let f = compose(dobro, somar.bind(null, 1));
+let g = pipe(dobro, somar.bind(null, 1));
+Io.println(f(10), g(10), 10 |> f |> g);
@@ -0,0 +1,94 @@
+// Cada frame mostra a própria posição: onde o erro surgiu e as chamadas pendentes
+fn f() {
+    let x = 1;
+    throw new Error(null, "boom");
+}
+fn g() {
+    f();
+    return 1;
+}
+fn h() {
+    let r = g();
+    return r;
+}
+
+try {
+    h();
+} catch (e) {
+    Io.println(e.getStack());
+}
+
+// Construtores têm frame próprio
+fn validar(itens) {
+    if (len(itens) == 0) {
+        throw new TypeError("pedido vazio");
+    }
+}
+class Pedido {
+    itens = [];
+    constructor(self, itens) {
+        validar(itens);
+        self.itens = itens;
+    }
+}
+try {
+    let pedido = new Pedido([]);
+} catch (e) {
+    Io.println(e.getStack());
+}
+
+// Erros que não vêm de uma chamada usam a linha do próprio statement
+fn total(precos) {
+    let soma = 0;
+    let frete = 5;
+    return soma + frete + desconto;
+}
+fn fechar() {
+    let precos = [1, 2];
+    total(precos);
+}
+try {
+    fechar();
+} catch (e) {
+    Io.println(e.message);
+    Io.println(e.getStack());
+}
+
+// Uma chamada de cauda reaproveita o frame; o trace registra quem foi substituído
+fn interna() {
+    throw new Error(null, "cauda");
+}
+fn externa() {
+    return interna();
+}
+fn contar(n) {
+    if (n == 0) {
+        return interna();
+    }
+    return contar(n - 1);
+}
+try {
+    externa();
+} catch (e) {
+    Io.println(e.getStack());
+}
+try {
+    contar(3);
+} catch (e) {
+    Io.println(e.getStack());
+}
+
+class Conta {
+    saldo = 0;
+    sacar(self, valor) {
+        if (valor > self.saldo) {
+            throw new RangeError("saldo insuficiente");
+        }
+    }
+}
+fn pagar(conta) {
+    conta.sacar(10);
+    return true;
+}
+let conta = new Conta();
+pagar(conta);
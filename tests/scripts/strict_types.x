@@ -0,0 +1,33 @@
+// Com LANG_STRICT=1 as anotações viram TypeError em tempo de execução
+fn dobro(n: number): number {
+    return n * 2;
+}
+fn texto(): string {
+    return 1;
+}
+class Caixa {
+    valor: int = 0;
+}
+
+Io.println(dobro(2));
+try {
+    dobro("2");
+} catch (e: TypeError) {
+    Io.println(e.message);
+}
+try {
+    texto();
+} catch (e: TypeError) {
+    Io.println(e.message);
+}
+try {
+    let x: string = 1;
+} catch (e: TypeError) {
+    Io.println(e.message);
+}
+let caixa = new Caixa();
+try {
+    caixa.valor = 1.5;
+} catch (e: TypeError) {
+    Io.println(e.message);
+}
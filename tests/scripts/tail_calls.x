@@ -0,0 +1,34 @@
+// `return f(args)` reaproveita o frame: recursão própria ou mútua passa do
+// limite de 10000 chamadas sem aprofundar a pilha
+fn somaAte(n, acc) {
+    if (n == 0) {
+        return acc;
+    }
+    return somaAte(n - 1, acc + n);
+}
+Io.println(somaAte(20000, 0));
+
+fn par(n) {
+    if (n == 0) {
+        return true;
+    }
+    return impar(n - 1);
+}
+fn impar(n) {
+    if (n == 0) {
+        return false;
+    }
+    return par(n - 1);
+}
+Io.println(par(20001), impar(20001));
+
+// Processamento de lista com 100 mil elementos
+let itens = range(0, 100000);
+fn contar(lista, i, total) {
+    if (i == len(lista)) {
+        return total;
+    }
+    return contar(lista, i + 1, total + lista[i]);
+}
+Io.println(contar(itens, 0, 0));
+
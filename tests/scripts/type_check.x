@@ -0,0 +1,24 @@
+// Anotações de tipo: `lang check` aponta os erros sem executar
+class Produto {
+    nome: string = "";
+    preco: number = 0;
+    tags: Array<string> = [];
+}
+
+fn total(itens: Array<Produto>, desconto: number): number {
+    return 0;
+}
+
+fn nome(p: Produto): string {
+    return p.nome;
+}
+
+let ok: number = total([], 0.1);
+let errado: string = 10;
+let p = new Produto();
+nome(p);
+nome("produto");
+total([], "dez");
+fn booleano(): bool {
+    return "sim";
+}
@@ -0,0 +1,68 @@
+// WeakRef e WeakMap não mantêm as chaves vivas; os valores do WeakMap são
+// efêmeros: só valem enquanto a chave está viva, mesmo que apontem para ela
+class Usuario {
+    nome = "";
+    constructor(self, nome) {
+        self.nome = nome;
+    }
+}
+
+let perfis = WeakMap();
+let ana = new Usuario("Ana");
+let ref = WeakRef(ana);
+perfis.set(ana, { acessos: 3 });
+Io.println(ref.deref().nome);
+Io.println(perfis.has(ana) + " " + perfis.get(ana).acessos);
+perfis.set(ana, { acessos: 4 });
+Io.println(perfis.get(ana).acessos);
+Io.println(perfis.delete(ana) + " " + perfis.delete(ana) + " " + perfis.has(ana));
+Io.println(perfis.get(ana));
+
+perfis.set(ana, 1);
+ana = null;
+Io.println(ref.deref());
+
+// Um ciclo só morre com o coletor
+let bob = new Usuario("Bob");
+bob.nome = bob;
+let refBob = WeakRef(bob);
+bob = null;
+Io.println(refBob.deref() == null);
+Io.println("ciclo: " + gc());
+Io.println(refBob.deref());
+
+// Valor que aponta para a própria chave
+let m = WeakMap();
+let kk = { nome: "kk" };
+let refKk = WeakRef(kk);
+m.set(kk, [kk]);
+kk = null;
+Io.println("efêmero: " + gc());
+Io.println(refKk.deref());
+
+// Chave viva mantém o valor vivo, e o que ele alcança
+let viva = new Usuario("Carla");
+let dado = { dono: viva };
+let refDado = WeakRef(dado);
+m.set(viva, dado);
+dado = null;
+Io.println("chave viva: " + gc());
+Io.println(refDado.deref().dono.nome + " " + m.get(viva).dono.nome);
+
+// Chave alcançável só pelo valor de outra entrada viva
+let a = new Usuario("a");
+let b = new Usuario("b");
+m.set(a, b);
+m.set(b, [b]);
+let refB = WeakRef(b);
+b = null;
+Io.println("encadeado: " + gc() + " " + refB.deref().nome + " " + m.has(refB.deref()));
+a = null;
+Io.println("encadeado morto: " + gc());
+Io.println(refB.deref());
+
+try {
+    m.set(1, 2);
+} catch (e) {
+    Io.println(e.message);
+}